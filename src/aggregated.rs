@@ -0,0 +1,521 @@
+use std::collections::VecDeque;
+use std::ops::{Add, Range, RangeBounds};
+
+use crate::chunk_sums::ChunkSums;
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// An associative way of combining the elements of an [`AggregatedArrayList`],
+/// such as a sum, a minimum or a maximum.
+pub trait Monoid<T> {
+    /// The aggregate of a run of elements.
+    type Value: Clone;
+
+    /// Returns the aggregate of no elements, which leaves any other one unchanged when combined.
+    fn identity(&self) -> Self::Value;
+
+    /// Returns the aggregate of a single element.
+    fn lift(&self, value: &T) -> Self::Value;
+
+    /// Combines the aggregates of two adjacent runs, `a` being the one before `b`.
+    fn combine(&self, a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+/// Aggregates elements by adding them up, starting from their default value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sum;
+
+impl<T> Monoid<T> for Sum
+where
+    T: Clone + Default + Add<Output = T>,
+{
+    type Value = T;
+
+    fn identity(&self) -> T {
+        T::default()
+    }
+
+    fn lift(&self, value: &T) -> T {
+        value.clone()
+    }
+
+    fn combine(&self, a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+}
+
+/// Aggregates elements into the smallest one, `None` for no elements.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Min;
+
+impl<T: Clone + Ord> Monoid<T> for Min {
+    type Value = Option<T>;
+
+    fn identity(&self) -> Option<T> {
+        None
+    }
+
+    fn lift(&self, value: &T) -> Option<T> {
+        Some(value.clone())
+    }
+
+    fn combine(&self, a: &Option<T>, b: &Option<T>) -> Option<T> {
+        a.iter().chain(b).min().cloned()
+    }
+}
+
+/// Aggregates elements into the largest one, `None` for no elements.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Max;
+
+impl<T: Clone + Ord> Monoid<T> for Max {
+    type Value = Option<T>;
+
+    fn identity(&self) -> Option<T> {
+        None
+    }
+
+    fn lift(&self, value: &T) -> Option<T> {
+        Some(value.clone())
+    }
+
+    fn combine(&self, a: &Option<T>, b: &Option<T>) -> Option<T> {
+        a.iter().chain(b).max().cloned()
+    }
+}
+
+const LEN: usize = 0;
+
+fn len_of<T>(chunk: &VecDeque<T>) -> [usize; 1] {
+    [chunk.len()]
+}
+
+/// An ArrayList whose chunks keep the aggregate of their elements under a [`Monoid`].
+///
+/// The aggregates of the chunks are combined in a segment tree, so that
+/// [`range_aggregate`](Self::range_aggregate) only folds the elements of the chunks at the ends
+/// of the range, plus O(log chunks) nodes for the ones in between. The lengths of the chunks
+/// are kept in a Fenwick tree to find them in O(log chunks) as well. Elements can't be borrowed
+/// mutably, use [`update`](Self::update) to modify one and have its chunk aggregated again.
+///
+/// Edits that change the number of chunks anywhere but at the back rebuild the segment tree.
+///
+/// # Examples
+/// ```
+/// use array_list::{AggregatedArrayList, Max, Sum};
+///
+/// let mut sums = AggregatedArrayList::<_, _, 2>::from_list((1..=6).collect(), Sum);
+/// assert_eq!(sums.range_aggregate(1..5), 2 + 3 + 4 + 5);
+///
+/// sums.update(2, |value| *value = 10);
+/// assert_eq!(sums.total(), 1 + 2 + 10 + 4 + 5 + 6);
+///
+/// let peaks = AggregatedArrayList::<_, _, 2>::from_list([3, 9, 1, 7].into(), Max);
+/// assert_eq!(peaks.range_aggregate(2..), Some(7));
+/// ```
+pub struct AggregatedArrayList<T, M, const N: usize>
+where
+    M: Monoid<T>,
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    monoid: M,
+    lens: ChunkSums<1>,
+    /// The leaves take the second half, padded with identities up to a power of two,
+    /// and every other node combines its two children.
+    tree: Vec<M::Value>,
+}
+
+impl<T, M, const N: usize> AggregatedArrayList<T, M, N>
+where
+    M: Monoid<T>,
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a new, empty `AggregatedArrayList`.
+    pub fn new(monoid: M) -> Self {
+        Self::from_list(ArrayList::new(), monoid)
+    }
+
+    /// Aggregates the chunks of `list` as they are.
+    pub fn from_list(list: ArrayList<T, N>, monoid: M) -> Self {
+        let mut this = Self {
+            lens: ChunkSums::from_chunks(&list.chunks, len_of),
+            list,
+            monoid,
+            tree: Vec::new(),
+        };
+        this.rebuild();
+        this
+    }
+
+    /// Returns the underlying list.
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the aggregate of all the elements.
+    pub fn total(&self) -> M::Value {
+        self.tree[1].clone()
+    }
+
+    /// Returns the aggregate of the elements in `range`.
+    ///
+    /// # Panics
+    /// - Panics if the range is out of bounds or its start is greater than its end.
+    #[track_caller]
+    pub fn range_aggregate(&self, range: impl RangeBounds<usize>) -> M::Value {
+        let Range { start, end } = self.list.resolve_range(range);
+        if start == end {
+            return self.monoid.identity();
+        }
+
+        let (first, start) = self.lens.search(LEN, start);
+        let (last, end) = self.lens.search(LEN, end - 1);
+        if first == last {
+            return self.fold(self.list.chunks[first].range(start..=end));
+        }
+
+        let head = self.fold(self.list.chunks[first].range(start..));
+        let middle = self.fold_chunks(first + 1..last);
+        let tail = self.fold(self.list.chunks[last].range(..=end));
+        let head = self.monoid.combine(&head, &middle);
+        self.monoid.combine(&head, &tail)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk_index, target_index) = self.lens.search(LEN, index);
+        self.list.chunks.get(chunk_index)?.get(target_index)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.edit(0, 1, |list| list.push_front(value));
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let last = self.list.chunks.len().saturating_sub(1);
+        self.edit(last, 1, |list| list.push_back(value));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.edit(0, 1, ArrayList::pop_front)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self.list.chunks.len().saturating_sub(1);
+        self.edit(last, 1, ArrayList::pop_back)
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        let chunk_index = self.lens.search(LEN, index).0;
+        let chunk_index = chunk_index.min(self.list.chunks.len().saturating_sub(1));
+
+        // the element may spill over into the next chunk
+        self.edit(chunk_index, 2, |list| list.insert(index, value));
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let chunk_index = self.lens.search(LEN, index).0;
+        self.edit(chunk_index, 1, |list| list.remove(index))
+    }
+
+    /// Calls `f` on the element at `index`, then aggregates its chunk again.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn update<R>(&mut self, index: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let (chunk_index, target_index) = self.lens.search(LEN, index);
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(self.edit(chunk_index, 1, |list| {
+            f(&mut list.chunks[chunk_index][target_index])
+        }))
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.lens = ChunkSums::new();
+        self.rebuild();
+    }
+
+    /// Applies `f` to the list, then aggregates again the chunks that were in
+    /// `start..start + count` before the edit, which must be the only ones `f` touches.
+    fn edit<R>(
+        &mut self,
+        start: usize,
+        count: usize,
+        f: impl FnOnce(&mut ArrayList<T, N>) -> R,
+    ) -> R {
+        let before = self.list.chunks.len();
+        let out = f(&mut self.list);
+        self.lens.refresh(&self.list.chunks, start, count, len_of);
+
+        let after = self.list.chunks.len();
+        let end = before.min(start + count);
+        let new_end = end + after - before;
+        if new_end != end && (end != before || after > self.tree.len() / 2) {
+            // the chunks past the edited ones moved, or outgrew the tree
+            self.rebuild();
+            return out;
+        }
+
+        for chunk_index in start..new_end {
+            let value = self.fold(&self.list.chunks[chunk_index]);
+            self.set_leaf(chunk_index, value);
+        }
+
+        // chunks dropped at the back leave their leaves empty
+        for chunk_index in after..before {
+            self.set_leaf(chunk_index, self.monoid.identity());
+        }
+
+        out
+    }
+
+    fn fold<'a>(&self, values: impl IntoIterator<Item = &'a T>) -> M::Value
+    where
+        T: 'a,
+    {
+        values
+            .into_iter()
+            .fold(self.monoid.identity(), |aggregate, value| {
+                self.monoid.combine(&aggregate, &self.monoid.lift(value))
+            })
+    }
+
+    /// Combines the leaves of the chunks in `range`, in order.
+    fn fold_chunks(&self, range: Range<usize>) -> M::Value {
+        let leaves = self.tree.len() / 2;
+        let (mut lo, mut hi) = (range.start + leaves, range.end + leaves);
+        let mut left = self.monoid.identity();
+        let mut right = self.monoid.identity();
+
+        while lo < hi {
+            if lo % 2 == 1 {
+                left = self.monoid.combine(&left, &self.tree[lo]);
+                lo += 1;
+            }
+
+            if hi % 2 == 1 {
+                hi -= 1;
+                right = self.monoid.combine(&self.tree[hi], &right);
+            }
+
+            lo /= 2;
+            hi /= 2;
+        }
+
+        self.monoid.combine(&left, &right)
+    }
+
+    fn set_leaf(&mut self, chunk_index: usize, value: M::Value) {
+        let mut node = chunk_index + self.tree.len() / 2;
+        self.tree[node] = value;
+
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self
+                .monoid
+                .combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let leaves = self.list.chunks.len().next_power_of_two();
+        let mut tree = vec![self.monoid.identity(); 2 * leaves];
+        for (leaf, chunk) in tree[leaves..].iter_mut().zip(&self.list.chunks) {
+            *leaf = self.fold(chunk);
+        }
+
+        for node in (1..leaves).rev() {
+            tree[node] = self.monoid.combine(&tree[2 * node], &tree[2 * node + 1]);
+        }
+
+        self.tree = tree;
+    }
+}
+
+impl<T, M, const N: usize> Default for AggregatedArrayList<T, M, N>
+where
+    M: Monoid<T> + Default,
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::new(M::default())
+    }
+}
+
+impl<T, M, const N: usize> Clone for AggregatedArrayList<T, M, N>
+where
+    T: Clone,
+    M: Monoid<T> + Clone,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self::from_list(self.list.clone(), self.monoid.clone())
+    }
+}
+
+impl<T, M, const N: usize> FromIterator<T> for AggregatedArrayList<T, M, N>
+where
+    M: Monoid<T> + Default,
+    Usize<N>: ChunkCapacity,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_list(ArrayList::from_iter(iter), M::default())
+    }
+}
+
+impl<T, M, const N: usize> core::fmt::Debug for AggregatedArrayList<T, M, N>
+where
+    T: core::fmt::Debug,
+    M: Monoid<T>,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ChunkCapacity, Usize};
+
+    use super::{AggregatedArrayList, Min, Monoid};
+
+    /// Concatenates strings, an operation that isn't commutative.
+    #[derive(Default)]
+    struct Concat;
+
+    impl Monoid<u8> for Concat {
+        type Value = String;
+
+        fn identity(&self) -> String {
+            String::new()
+        }
+
+        fn lift(&self, value: &u8) -> String {
+            value.to_string()
+        }
+
+        fn combine(&self, a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+    }
+
+    #[quickcheck]
+    fn test_aggregated_matches_vec(seed: Vec<u8>, operations: Vec<(u8, usize, u8)>) {
+        fn _test<const N: usize>(seed: &[u8], operations: &[(u8, usize, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.to_vec();
+            let mut actual = AggregatedArrayList::<_, Concat, N>::from_iter(seed.iter().copied());
+            let mut min = AggregatedArrayList::<_, Min, N>::from_iter(seed.iter().copied());
+
+            for &(operation, index, value) in operations {
+                let index = index % (expected.len() + 1);
+
+                match operation % 7 {
+                    0 => {
+                        expected.insert(0, value);
+                        actual.push_front(value);
+                        min.push_front(value);
+                    }
+                    1 => {
+                        expected.push(value);
+                        actual.push_back(value);
+                        min.push_back(value);
+                    }
+                    2 => {
+                        let value = (!expected.is_empty()).then(|| expected.remove(0));
+                        assert_eq!(actual.pop_front(), value);
+                        assert_eq!(min.pop_front(), value);
+                    }
+                    3 => {
+                        assert_eq!(actual.pop_back(), expected.last().copied());
+                        assert_eq!(min.pop_back(), expected.pop());
+                    }
+                    4 => {
+                        expected.insert(index, value);
+                        actual.insert(index, value);
+                        min.insert(index, value);
+                    }
+                    5 => {
+                        let value = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), value);
+                        assert_eq!(min.remove(index), value);
+                    }
+                    _ => {
+                        let updated = actual.update(index, |v| *v = value);
+                        min.update(index, |v| *v = value);
+                        if let Some(v) = expected.get_mut(index) {
+                            *v = value;
+                            assert_eq!(updated, Some(()));
+                        } else {
+                            assert_eq!(updated, None);
+                        }
+                    }
+                }
+
+                assert_eq!(*actual.as_list(), expected.as_slice());
+                assert_eq!(actual.get(index), expected.get(index));
+
+                let concat = |values: &[u8]| values.iter().map(u8::to_string).collect::<String>();
+                assert_eq!(actual.total(), concat(&expected));
+                for start in 0..=expected.len() {
+                    for end in start..=expected.len() {
+                        let values = &expected[start..end];
+                        assert_eq!(actual.range_aggregate(start..end), concat(values));
+                        assert_eq!(
+                            min.range_aggregate(start..end),
+                            values.iter().min().copied()
+                        );
+                    }
+                }
+            }
+        }
+
+        // keeps the quadratic check of every range short
+        let seed = &seed[..seed.len().min(24)];
+        let operations = &operations[..operations.len().min(24)];
+        _test::<1>(seed, operations);
+        _test::<2>(seed, operations);
+        _test::<3>(seed, operations);
+        _test::<4>(seed, operations);
+        _test::<5>(seed, operations);
+        _test::<8>(seed, operations);
+        _test::<16>(seed, operations);
+        _test::<32>(seed, operations);
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An alignment that [`Aligned`] can raise its value to.
+///
+/// This trait is sealed, it's implemented by [`Align16`], [`Align32`] and [`Align64`] only.
+pub trait Alignment: sealed::Sealed + Copy + Default {}
+
+macro_rules! alignment {
+    ($name:ident, $align:literal) => {
+        #[doc = concat!("Aligns an [`Aligned`] value to ", stringify!($align), " bytes.")]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(align($align))]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+        impl Alignment for $name {}
+    };
+}
+
+alignment!(Align16, 16);
+alignment!(Align32, 32);
+alignment!(Align64, 64);
+
+/// A value aligned to at least `A`, to be stored in an ArrayList whose chunks must be aligned.
+///
+/// Chunks allocate their storage with the alignment of the elements they hold,
+/// so in an `ArrayList<Aligned<T, A>, N>` every chunk, and every run yielded by
+/// [`Iter::as_chunks`](crate::Iter::as_chunks), starts at an address that is a multiple of `A`,
+/// and so does every element after it, as the size of `Aligned<T, A>` is rounded up to `A` too.
+/// Wrapping a whole SIMD vector, like `Aligned<[f32; 8], Align32>`, lets each element
+/// be fed to an aligned load as it is, without padding between lanes.
+///
+/// ```
+/// use array_list::{Align32, Aligned, ArrayList};
+///
+/// let mut list: ArrayList<Aligned<[f32; 8], Align32>, 4> = ArrayList::new();
+/// list.extend((0..10).map(|i| Aligned::new([i as f32; 8])));
+///
+/// assert!(list.iter().as_chunks().all(|run| run.as_ptr().addr() % 32 == 0));
+/// assert_eq!(list.get(3).map(|lanes| lanes[0]), Some(3.0));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Aligned<T, A: Alignment> {
+    align: [A; 0],
+    value: T,
+}
+
+impl<T, A: Alignment> Aligned<T, A> {
+    pub const fn new(value: T) -> Self {
+        Self { align: [], value }
+    }
+
+    /// Consumes `self`, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, A: Alignment> From<T> for Aligned<T, A> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, A: Alignment> Deref for Aligned<T, A> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, A: Alignment> DerefMut for Aligned<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: Debug, A: Alignment> Debug for Aligned<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use super::*;
+    use crate::ArrayList;
+
+    fn assert_aligned<A: Alignment, const N: usize>()
+    where
+        crate::Usize<N>: crate::ChunkCapacity,
+    {
+        let align = align_of::<A>();
+        let mut list: ArrayList<Aligned<u8, A>, N> = ArrayList::new();
+
+        for i in 0..100 {
+            list.push_back(Aligned::new(i));
+            list.push_front(Aligned::new(i));
+        }
+
+        for i in 0..30 {
+            list.insert(i * 3, Aligned::new(i as u8));
+            list.remove(i * 2);
+            list.pop_front();
+        }
+
+        let aligned = |ptr: *const Aligned<u8, A>| ptr.addr() % align == 0;
+        assert!(list.iter().as_chunks().all(|run| aligned(run.as_ptr())));
+        assert!(list.iter().all(|value| aligned(value)));
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(align_of::<Aligned<u8, Align16>>(), 16);
+        assert_eq!(size_of::<Aligned<u8, Align16>>(), 16);
+        assert_eq!(align_of::<Aligned<[f32; 8], Align32>>(), 32);
+        assert_eq!(size_of::<Aligned<[f32; 8], Align32>>(), 32);
+        assert_eq!(align_of::<Aligned<[u8; 65], Align64>>(), 64);
+        assert_eq!(size_of::<Aligned<[u8; 65], Align64>>(), 128);
+    }
+
+    #[test]
+    fn test_chunks_are_aligned() {
+        assert_aligned::<Align16, 1>();
+        assert_aligned::<Align16, 3>();
+        assert_aligned::<Align32, 4>();
+        assert_aligned::<Align32, 5>();
+        assert_aligned::<Align64, 8>();
+        assert_aligned::<Align64, 16>();
+    }
+
+    #[test]
+    fn test_deref() {
+        let mut value: Aligned<Vec<u8>, Align64> = vec![1, 2].into();
+        value.push(3);
+
+        assert_eq!(value.len(), 3);
+        assert_eq!(format!("{value:?}"), "[1, 2, 3]");
+        assert_eq!(value.into_inner(), vec![1, 2, 3]);
+    }
+}
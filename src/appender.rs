@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A cloneable handle to append to an ArrayList from several threads at once.
+///
+/// Each handle fills a private chunk without any synchronization, and only takes a short lock
+/// to publish it at the back of the list once it's full, when [`flush`](Self::flush) is called,
+/// or when the handle is dropped. Elements pushed through the same handle keep their order,
+/// while the ones pushed through different handles are interleaved a chunk at a time.
+///
+/// This struct is created by ArrayList::appender().
+pub struct Appender<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: Arc<Mutex<&'a mut ArrayList<T, N>>>,
+    chunk: VecDeque<T>,
+}
+
+impl<'a, T, const N: usize> Appender<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn new(list: &'a mut ArrayList<T, N>) -> Self {
+        Self {
+            list: Arc::new(Mutex::new(list)),
+            chunk: VecDeque::with_capacity(N),
+        }
+    }
+
+    /// Appends an element to the private chunk of this handle, publishing the chunk once it's full.
+    pub fn push(&mut self, value: T) {
+        self.chunk.push_back(value);
+        if self.chunk.len() >= N {
+            self.flush();
+        }
+    }
+
+    /// Publishes the elements pushed through this handle so far, even if its chunk is not full.
+    pub fn flush(&mut self) {
+        if self.chunk.is_empty() {
+            return;
+        }
+
+        let chunk = core::mem::replace(&mut self.chunk, VecDeque::with_capacity(N));
+        self.publish(chunk);
+    }
+
+    /// Takes the published elements out of the list, in the order they were published,
+    /// along with the ones the list held before the appender was created.
+    ///
+    /// Elements still sitting in the private chunks of the handles are not taken.
+    pub fn take_published(&self) -> ArrayList<T, N> {
+        // the counters stay with the list, which keeps being appended to
+        let mut list = self.lock();
+        ArrayList {
+            chunks: core::mem::take(&mut list.chunks),
+            len: core::mem::take(&mut list.len),
+            ..ArrayList::new()
+        }
+    }
+
+    fn publish(&self, chunk: VecDeque<T>) {
+        let mut list = self.lock();
+        list.len += chunk.len();
+        list.chunks.push_back(chunk);
+        list.counters.allocation();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, &'a mut ArrayList<T, N>> {
+        // publishing never leaves the list in a broken state, so a poisoned lock is fine to use
+        self.list.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T, const N: usize> Clone for Appender<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Returns a new handle to the same list, with an empty private chunk.
+    fn clone(&self) -> Self {
+        Self {
+            list: Arc::clone(&self.list),
+            chunk: VecDeque::with_capacity(N),
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Appender<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl<T, const N: usize> Drop for Appender<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn drop(&mut self) {
+        // unlike flushing, there's no need to make room for the next elements
+        let chunk = core::mem::take(&mut self.chunk);
+        if !chunk.is_empty() {
+            self.publish(chunk);
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for Appender<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Appender")
+            .field("pending", &self.chunk.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::ArrayList;
+
+    #[test]
+    fn test_appender_publishes_on_flush_and_drop() {
+        let mut list = ArrayList::<usize, 4>::from([0]);
+
+        let mut appender = list.appender();
+        appender.extend(1..4);
+        assert_eq!(appender.take_published(), [0]);
+
+        appender.flush();
+        appender.extend(4..10);
+        assert_eq!(appender.take_published(), [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut other = appender.clone();
+        other.push(10);
+        drop(other);
+        drop(appender);
+
+        assert_eq!(list, [10, 8, 9]);
+        assert!(
+            list.chunks
+                .iter()
+                .all(|chunk| (1..=4).contains(&chunk.len()))
+        );
+    }
+
+    #[test]
+    fn test_appender_keeps_each_producer_in_order() {
+        const PRODUCERS: usize = 4;
+        const VALUES: usize = 1000;
+
+        let mut list = ArrayList::<(usize, usize), 16>::new();
+        let mut taken = Vec::new();
+
+        thread::scope(|scope| {
+            let appender = list.appender();
+
+            for producer in 0..PRODUCERS {
+                let mut appender = appender.clone();
+                scope.spawn(move || appender.extend((0..VALUES).map(|value| (producer, value))));
+            }
+
+            for _ in 0..10 {
+                taken.extend(appender.take_published());
+                thread::yield_now();
+            }
+        });
+
+        taken.extend(list);
+        assert_eq!(taken.len(), PRODUCERS * VALUES);
+
+        for producer in 0..PRODUCERS {
+            let values = taken
+                .iter()
+                .filter(|(p, _)| *p == producer)
+                .map(|(_, v)| *v);
+            assert!(values.eq(0..VALUES));
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+
+use arrow_array::{ArrowPrimitiveType, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, MutableBuffer, ScalarBuffer};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+impl<T: ArrowNativeType, const N: usize> ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a list from the values of an Arrow array, copying them a chunk at a time.
+    ///
+    /// Null slots are read as the value the array stores under them, usually zero:
+    /// collect [`PrimitiveArray::iter`] into an `ArrayList<Option<T>, N>` to keep them apart.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    /// use arrow_array::Int64Array;
+    ///
+    /// let array = Int64Array::from(vec![3, 1, 4, 1, 5]);
+    /// let list: ArrayList<i64, 2> = ArrayList::from_arrow(&array);
+    ///
+    /// assert_eq!(list, [3, 1, 4, 1, 5]);
+    /// ```
+    pub fn from_arrow<A>(array: &PrimitiveArray<A>) -> Self
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        let mut list = Self::new();
+        for values in array.values().chunks(N) {
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.extend(values.iter().copied());
+            list.chunks.push_back(chunk);
+            list.counters.allocation();
+        }
+
+        list.len = array.len();
+        list
+    }
+
+    /// Builds an Arrow array with no nulls out of the elements,
+    /// copying them into a single buffer a contiguous run at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    /// use arrow_array::Float32Array;
+    /// use arrow_array::types::Float32Type;
+    ///
+    /// let list: ArrayList<f32, 2> = ArrayList::from([0.5, 1.5, 2.5]);
+    /// let array = list.to_arrow::<Float32Type>();
+    ///
+    /// assert_eq!(array, Float32Array::from(vec![0.5, 1.5, 2.5]));
+    /// ```
+    pub fn to_arrow<A>(&self) -> PrimitiveArray<A>
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        let mut buffer = MutableBuffer::new(self.len() * size_of::<T>());
+        for chunk in &self.chunks {
+            let (front, back) = chunk.as_slices();
+            buffer.extend_from_slice(front);
+            buffer.extend_from_slice(back);
+        }
+
+        PrimitiveArray::new(ScalarBuffer::new(buffer.into(), 0, self.len()), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Int32Array;
+    use arrow_array::types::Int32Type;
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_from_arrow_reads_values_under_nulls() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+
+        let list = ArrayList::<_, 2>::from_arrow(&array);
+        assert_eq!(list, [1, 0, 3]);
+
+        let list: ArrayList<_, 2> = array.iter().collect();
+        assert_eq!(list, [Some(1), None, Some(3)]);
+    }
+
+    #[quickcheck]
+    fn test_arrow_round_trip(seed: Vec<i32>, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let array = list.to_arrow::<Int32Type>();
+            assert_eq!(array, Int32Array::from(seed.to_vec()));
+
+            let actual = ArrayList::<_, N>::from_arrow(&array);
+            assert_eq!(actual, seed);
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
+}
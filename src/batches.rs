@@ -0,0 +1,183 @@
+use core::iter::FusedIterator;
+
+use crate::{ArrayList, ChunkCapacity, Iter, SearchTarget, Usize};
+
+/// An iterator over the elements of an ArrayList in batches of exactly `k` elements,
+/// whatever the size of the chunks.
+///
+/// Each batch is an [`Iter`] over its elements, which can be viewed as contiguous slices
+/// with [`Iter::as_chunks`] or copied out with [`Iter::copy_to_slice`]. When the length
+/// of the list isn't a multiple of `k`, the last elements are left out of the batches
+/// and are available through [`remainder`](Self::remainder).
+///
+/// This struct is created by ArrayList::batches().
+pub struct Batches<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a ArrayList<T, N>,
+    k: usize,
+    /// The position of the first element of the next batch, as a chunk index and an index within it.
+    position: (usize, usize),
+    /// The number of elements left, including the remainder.
+    len: usize,
+}
+
+impl<'a, T, const N: usize> Batches<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    #[track_caller]
+    pub(crate) fn new(list: &'a ArrayList<T, N>, k: usize) -> Self {
+        assert!(k != 0, "batch size must be non-zero");
+
+        Self {
+            list,
+            k,
+            position: (0, 0),
+            len: list.len(),
+        }
+    }
+
+    /// Returns the last elements of the list, fewer than `k`, which don't make up a batch.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 4> = ArrayList::from_iter(0..10);
+    /// let batches = list.batches(3);
+    ///
+    /// assert_eq!(batches.len(), 3);
+    /// assert!(batches.remainder().eq(&[9]));
+    /// ```
+    pub fn remainder(&self) -> Iter<'a, T, N> {
+        let len = self.len % self.k;
+        let start = match self.list.search_target(self.list.len() - len) {
+            Some(SearchTarget {
+                chunk_index,
+                target_index,
+            }) => (chunk_index, target_index),
+            None => (self.list.chunks.len(), 0),
+        };
+
+        Iter::from_range(self.list, start, (self.list.chunks.len(), 0), len)
+    }
+}
+
+impl<T, const N: usize> Clone for Batches<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Batches<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = Iter<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len < self.k {
+            return None;
+        }
+
+        let start = self.position;
+        let (mut chunk_index, mut inner_index) = start;
+        let mut n = self.k;
+
+        // walks a chunk at a time, stopping past the last element of the batch
+        while n > 0 {
+            let available = self.list.chunks[chunk_index].len() - inner_index;
+            if n < available {
+                inner_index += n;
+                break;
+            }
+
+            n -= available;
+            chunk_index += 1;
+            inner_index = 0;
+        }
+
+        self.position = (chunk_index, inner_index);
+        self.len -= self.k;
+        Some(Iter::from_range(self.list, start, self.position, self.k))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len / self.k;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Batches<'_, T, N> where Usize<N>: ChunkCapacity {}
+
+impl<T, const N: usize> FusedIterator for Batches<'_, T, N> where Usize<N>: ChunkCapacity {}
+
+impl<T, const N: usize> core::fmt::Debug for Batches<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Batches")
+            .field("k", &self.k)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_batches_match_chunks_exact(seed: Vec<i32>, k: u8, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], k: usize, front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let mut batches = list.batches(k);
+            let mut expected = seed.chunks_exact(k);
+            assert_eq!(batches.len(), expected.len());
+            assert!(batches.remainder().eq(expected.remainder()));
+
+            for (i, expected) in expected.by_ref().enumerate() {
+                let batch = batches.next().unwrap();
+                assert!(batch.clone().eq(expected));
+
+                let mut buffer = vec![0; k];
+                batch.copy_to_slice(&mut buffer);
+                assert_eq!(buffer, expected);
+
+                assert_eq!(batches.len(), seed.len() / k - i - 1);
+            }
+
+            assert!(batches.next().is_none());
+            assert!(batches.remainder().eq(expected.remainder()));
+        }
+
+        let k = usize::from(k % 16) + 1;
+        _test::<1>(&seed, k, front);
+        _test::<2>(&seed, k, front);
+        _test::<3>(&seed, k, front);
+        _test::<4>(&seed, k, front);
+        _test::<5>(&seed, k, front);
+        _test::<8>(&seed, k, front);
+        _test::<16>(&seed, k, front);
+        _test::<32>(&seed, k, front);
+    }
+}
@@ -0,0 +1,126 @@
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// Encodes like a `Vec<T>`: a `u64` length prefix followed by the elements, front to back.
+impl<T, const N: usize> Encode for ArrayList<T, N>
+where
+    T: Encode,
+    Usize<N>: ChunkCapacity,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        (self.len() as u64).encode(encoder)?;
+        self.iter().try_for_each(|value| value.encode(encoder))
+    }
+}
+
+impl<Context, T, const N: usize> Decode<Context> for ArrayList<T, N>
+where
+    T: Decode<Context>,
+    Usize<N>: ChunkCapacity,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len = decode_len::<T, _>(decoder)?;
+
+        (0..len)
+            .map(|_| {
+                // the whole list was claimed upfront, the element claims its own bytes again
+                decoder.unclaim_bytes_read(size_of::<T>());
+                T::decode(decoder)
+            })
+            .collect()
+    }
+}
+
+impl<'de, Context, T, const N: usize> BorrowDecode<'de, Context> for ArrayList<T, N>
+where
+    T: BorrowDecode<'de, Context>,
+    Usize<N>: ChunkCapacity,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let len = decode_len::<T, _>(decoder)?;
+
+        (0..len)
+            .map(|_| {
+                decoder.unclaim_bytes_read(size_of::<T>());
+                T::borrow_decode(decoder)
+            })
+            .collect()
+    }
+}
+
+/// Decodes the length prefix, claiming the bytes of as many `T` against the decoding limit
+/// before anything gets allocated.
+fn decode_len<T, D: Decoder>(decoder: &mut D) -> Result<usize, DecodeError> {
+    let len = u64::decode(decoder)?;
+    let len = usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))?;
+    decoder.claim_container_read::<T>(len)?;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode::config;
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_decode_respects_limit() {
+        let list = ArrayList::<u32, 2>::from_iter(0..64);
+        let bytes = bincode::encode_to_vec(&list, config::standard()).unwrap();
+
+        let limited = config::standard().with_limit::<16>();
+        let decoded = bincode::decode_from_slice::<ArrayList<u32, 2>, _>(&bytes, limited);
+        assert!(decoded.is_err());
+    }
+
+    #[quickcheck]
+    fn test_encoding_matches_vec(seed: Vec<String>, front: u8) {
+        fn _test<const N: usize>(seed: &[String], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().cloned());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|value| list.push_front(value.clone()));
+
+            let bytes = bincode::encode_to_vec(&list, config::standard()).unwrap();
+            assert_eq!(
+                bytes,
+                bincode::encode_to_vec(seed, config::standard()).unwrap()
+            );
+
+            let (decoded, read) =
+                bincode::decode_from_slice::<ArrayList<String, N>, _>(&bytes, config::standard())
+                    .unwrap();
+            assert_eq!(read, bytes.len());
+            assert_eq!(decoded, seed);
+
+            let (borrowed, _) = bincode::borrow_decode_from_slice::<ArrayList<&str, N>, _>(
+                &bytes,
+                config::standard(),
+            )
+            .unwrap();
+            assert!(borrowed.iter().eq(seed.iter()));
+        }
+
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
+}
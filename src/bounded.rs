@@ -0,0 +1,381 @@
+use std::collections::VecDeque;
+use std::ops::Deref;
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// What a [`BoundedArrayList`] does with a value pushed while it's full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// The front element is evicted to make room, like in a ring buffer.
+    EvictFront,
+    /// The pushed value is handed back and the list is left untouched.
+    Reject,
+}
+
+/// An ArrayList that never holds more than a given number of elements.
+///
+/// The list is read through `Deref`, while pushing goes through
+/// [`push_back`](Self::push_back), which applies the [`OverflowPolicy`] once the list is full,
+/// or through [`try_push_back`](Self::try_push_back) and the like, which always reject.
+/// When evicting, the chunks freed at the front are reused at the back rather than reallocated,
+/// so a full list doesn't allocate anymore.
+///
+/// This struct is created by ArrayList::bounded().
+pub struct BoundedArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    max_len: usize,
+    policy: OverflowPolicy,
+    /// The last chunk freed by an eviction, kept around for the next chunk pushed at the back.
+    spare: VecDeque<T>,
+}
+
+impl<T, const N: usize> BoundedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) const fn new(max_len: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            list: ArrayList::new(),
+            max_len,
+            policy,
+            spare: VecDeque::new(),
+        }
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    pub const fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.list.len() >= self.max_len
+    }
+
+    /// Adds an element to the back of the list, or applies the overflow policy if it's full.
+    ///
+    /// Returns the value that didn't fit: the evicted front element with
+    /// [`OverflowPolicy::EvictFront`], or `value` itself with [`OverflowPolicy::Reject`].
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::{ArrayList, OverflowPolicy};
+    ///
+    /// let mut history = ArrayList::<_, 2>::bounded(3, OverflowPolicy::EvictFront);
+    /// assert_eq!(history.push_back(0), None);
+    /// assert_eq!(history.push_back(1), None);
+    /// assert_eq!(history.push_back(2), None);
+    /// assert_eq!(history.push_back(3), Some(0));
+    /// assert_eq!(*history, [1, 2, 3]);
+    ///
+    /// let mut queue = ArrayList::<_, 2>::bounded(1, OverflowPolicy::Reject);
+    /// assert_eq!(queue.push_back(0), None);
+    /// assert_eq!(queue.push_back(1), Some(1));
+    /// assert_eq!(*queue, [0]);
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Option<T> {
+        if !self.is_full() {
+            self.list.push_back(value);
+            return None;
+        }
+
+        if self.policy == OverflowPolicy::Reject || self.max_len == 0 {
+            return Some(value);
+        }
+
+        let front = self.list.chunks.front_mut()?;
+        let evicted = front.pop_front();
+        if front.is_empty() {
+            self.spare = self.list.chunks.pop_front().unwrap_or_default();
+        }
+
+        match self.list.chunks.back_mut() {
+            Some(chunk) if chunk.len() < N => chunk.push_back(value),
+            _ => {
+                let mut chunk = match self.spare.capacity() {
+                    0 => {
+                        self.list.counters.allocation();
+                        VecDeque::with_capacity(N)
+                    }
+                    _ => core::mem::take(&mut self.spare),
+                };
+                chunk.push_back(value);
+                self.list.chunks.push_back(chunk);
+            }
+        }
+
+        evicted
+    }
+
+    /// Adds an element to the back of the list, or hands `value` back if it's full,
+    /// whatever the overflow policy.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<_, 2>::with_max_len(2);
+    /// assert_eq!(list.try_push_back(1), Ok(()));
+    /// assert_eq!(list.try_push_front(0), Ok(()));
+    /// assert_eq!(list.try_push_back(2), Err(2));
+    /// assert_eq!(list.try_insert(1, 2), Err(2));
+    /// assert_eq!(*list, [0, 1]);
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.list.push_back(value);
+        Ok(())
+    }
+
+    /// Adds an element to the front of the list, or hands `value` back if it's full,
+    /// whatever the overflow policy.
+    pub fn try_push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.list.push_front(value);
+        Ok(())
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right,
+    /// or hands `value` back if the list is full, whatever the overflow policy.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+        let len = self.list.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.list.insert(index, value);
+        Ok(())
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.list.remove(index)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl<T, const N: usize> Clone for BoundedArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            max_len: self.max_len,
+            policy: self.policy,
+            spare: VecDeque::new(),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize> Extend<T> for BoundedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Pushes every element, dropping the ones that didn't fit.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| {
+            self.push_back(value);
+        });
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for BoundedArrayList<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::OverflowPolicy;
+
+    #[test]
+    fn test_bounded_reuses_evicted_chunks() {
+        let mut sut = ArrayList::<usize, 4>::bounded(8, OverflowPolicy::EvictFront);
+        sut.extend(0..8);
+        assert_eq!(sut.spare.capacity(), 0);
+
+        assert_eq!(sut.push_back(8), Some(0));
+        assert_eq!(sut.chunks.len(), 3);
+
+        sut.extend(9..12);
+        assert_eq!(sut.spare.capacity(), 4);
+        assert_eq!(sut.chunks.len(), 2);
+
+        assert_eq!(sut.push_back(12), Some(4));
+        assert_eq!(sut.spare.capacity(), 0);
+        assert_eq!(sut.chunks.len(), 3);
+
+        assert_eq!(*sut, [5, 6, 7, 8, 9, 10, 11, 12]);
+    }
+
+    #[quickcheck]
+    fn test_bounded_matches_vec_deque(max_len: u8, evict: bool, operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(max_len: usize, evict: bool, operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let policy = if evict {
+                OverflowPolicy::EvictFront
+            } else {
+                OverflowPolicy::Reject
+            };
+
+            let mut actual = ArrayList::<_, N>::bounded(max_len, policy);
+            let mut expected = VecDeque::new();
+
+            for &(operation, value) in operations {
+                match operation % 4 {
+                    0 => assert_eq!(actual.pop_front(), expected.pop_front()),
+                    1 => assert_eq!(actual.pop_back(), expected.pop_back()),
+                    _ => {
+                        let overflow = if expected.len() < max_len || evict {
+                            expected.push_back(value);
+                            expected
+                                .drain(..expected.len().saturating_sub(max_len))
+                                .next()
+                        } else {
+                            Some(value)
+                        };
+
+                        assert_eq!(actual.push_back(value), overflow);
+                    }
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.len() <= max_len);
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        let max_len = usize::from(max_len % 64);
+        _test::<1>(max_len, evict, &operations);
+        _test::<2>(max_len, evict, &operations);
+        _test::<3>(max_len, evict, &operations);
+        _test::<4>(max_len, evict, &operations);
+        _test::<5>(max_len, evict, &operations);
+        _test::<8>(max_len, evict, &operations);
+        _test::<16>(max_len, evict, &operations);
+        _test::<32>(max_len, evict, &operations);
+    }
+
+    #[quickcheck]
+    fn test_with_max_len_matches_vec(max_len: u8, operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(max_len: usize, operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::with_max_len(max_len);
+            let mut expected = Vec::new();
+
+            for &(operation, value) in operations {
+                let index = value.unsigned_abs() as usize % (expected.len() + 1);
+                let full = expected.len() >= max_len;
+                let pushed = match operation % 5 {
+                    0 => actual
+                        .try_push_front(value)
+                        .map(|()| expected.insert(0, value)),
+                    1 => actual.try_push_back(value).map(|()| expected.push(value)),
+                    2 | 3 => actual
+                        .try_insert(index, value)
+                        .map(|()| expected.insert(index, value)),
+                    _ => {
+                        let removed = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), removed);
+                        continue;
+                    }
+                };
+
+                assert_eq!(pushed, if full { Err(value) } else { Ok(()) });
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.len() <= max_len);
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        let max_len = usize::from(max_len % 64);
+        _test::<1>(max_len, &operations);
+        _test::<2>(max_len, &operations);
+        _test::<3>(max_len, &operations);
+        _test::<4>(max_len, &operations);
+        _test::<5>(max_len, &operations);
+        _test::<8>(max_len, &operations);
+        _test::<16>(max_len, &operations);
+        _test::<32>(max_len, &operations);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+use std::mem::size_of;
+
+use bytemuck::Pod;
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+impl<T: Pod, const N: usize> ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a list from the raw bytes of its elements, in native byte order.
+    ///
+    /// The bytes don't need to be aligned for `T`, they're copied a chunk at a time.
+    ///
+    /// # Panics
+    /// - Panics if the length of `bytes` is not a multiple of the size of `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let bytes: Vec<u8> = [1.5f32, -2.0, 4.25].iter().flat_map(|v| v.to_ne_bytes()).collect();
+    /// let list: ArrayList<f32, 2> = ArrayList::from_pod_bytes(&bytes);
+    ///
+    /// assert_eq!(list, [1.5, -2.0, 4.25]);
+    /// ```
+    #[track_caller]
+    pub fn from_pod_bytes(bytes: &[u8]) -> Self {
+        let mut list = Self::new();
+        list.extend_from_pod_bytes(bytes);
+        list
+    }
+
+    /// Appends the elements read from their raw bytes, in native byte order,
+    /// filling up the last chunk first.
+    ///
+    /// # Panics
+    /// - Panics if the length of `bytes` is not a multiple of the size of `T`.
+    #[track_caller]
+    pub fn extend_from_pod_bytes(&mut self, bytes: &[u8]) {
+        let size = size_of::<T>();
+        assert!(
+            bytes.len() % size == 0,
+            "byte length (is {}) should be a multiple of the element size (is {size})",
+            bytes.len()
+        );
+
+        let mut rest = bytes;
+        if let Some(chunk) = self.chunks.back_mut() {
+            let n = (N - chunk.len()).min(rest.len() / size);
+            let (head, tail) = rest.split_at(n * size);
+            chunk.extend(
+                head.chunks_exact(size)
+                    .map(bytemuck::pod_read_unaligned::<T>),
+            );
+            rest = tail;
+        }
+
+        for values in rest.chunks(N * size) {
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.resize(values.len() / size, T::zeroed());
+            bytemuck::cast_slice_mut(chunk.as_mut_slices().0).copy_from_slice(values);
+            self.chunks.push_back(chunk);
+            self.counters.allocation();
+        }
+
+        self.len += bytes.len() / size;
+    }
+
+    /// Returns the raw bytes of the elements, one contiguous run of them at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<u16, 2> = ArrayList::from([1, 2, 3]);
+    /// let bytes: Vec<u8> = list.chunks_as_bytes().flatten().copied().collect();
+    ///
+    /// assert_eq!(ArrayList::<u16, 4>::from_pod_bytes(&bytes), [1, 2, 3]);
+    /// ```
+    pub fn chunks_as_bytes(&self) -> impl DoubleEndedIterator<Item = &[u8]> {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| {
+                let (front, back) = chunk.as_slices();
+                [front, back]
+            })
+            .filter(|run| !run.is_empty())
+            .map(bytemuck::cast_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_pod_bytes_round_trip(seed: Vec<u32>, batches: Vec<Vec<u32>>, front: u8) {
+        fn _test<const N: usize>(seed: &[u32], batches: &[Vec<u32>], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let bytes = |values: &[u32]| -> Vec<u8> {
+                values
+                    .iter()
+                    .flat_map(|value| value.to_ne_bytes())
+                    .collect()
+            };
+
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut actual = ArrayList::<u32, N>::from_pod_bytes(&bytes(&seed[front..]));
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| actual.push_front(value));
+            let mut expected = seed.to_vec();
+
+            for batch in batches {
+                // reading from an odd offset makes the bytes misaligned for u32
+                let mut buffer = vec![0];
+                buffer.extend(bytes(batch));
+                actual.extend_from_pod_bytes(&buffer[1..]);
+                expected.extend(batch);
+            }
+
+            assert_eq!(actual.len(), expected.len());
+            assert!(actual.iter().eq(expected.iter()));
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+
+            let written: Vec<u8> = actual.chunks_as_bytes().flatten().copied().collect();
+            assert_eq!(written, bytes(&expected));
+        }
+
+        _test::<1>(&seed, &batches, front);
+        _test::<2>(&seed, &batches, front);
+        _test::<3>(&seed, &batches, front);
+        _test::<4>(&seed, &batches, front);
+        _test::<5>(&seed, &batches, front);
+        _test::<8>(&seed, &batches, front);
+        _test::<16>(&seed, &batches, front);
+        _test::<32>(&seed, &batches, front);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte length (is 5) should be a multiple of the element size (is 2)")]
+    fn test_from_pod_bytes_rejects_partial_elements() {
+        ArrayList::<u16, 2>::from_pod_bytes(&[0; 5]);
+    }
+}
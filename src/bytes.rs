@@ -0,0 +1,225 @@
+use std::collections::VecDeque;
+use std::io::IoSlice;
+use std::mem::MaybeUninit;
+
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// Advancing consumes bytes from the front of the list.
+impl<const N: usize> Buf for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.chunks.front().map_or(&[], |chunk| chunk.as_slices().0)
+    }
+
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let slices = self
+            .chunks
+            .iter()
+            .flat_map(|chunk| {
+                let (front, back) = chunk.as_slices();
+                [front, back]
+            })
+            .filter(|slice| !slice.is_empty());
+
+        dst.iter_mut()
+            .zip(slices)
+            .map(|(dst, slice)| *dst = IoSlice::new(slice))
+            .count()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.len(),
+            "cannot advance past `remaining`: {cnt} should be <= {}",
+            self.len()
+        );
+
+        self.consume_bytes(cnt);
+    }
+}
+
+/// Writing appends bytes to the back of the list.
+///
+/// The slice returned by [`BufMut::chunk_mut`] is the room left in the back chunk, after pushing
+/// a new one if it's full, and [`BufMut::advance_mut`] adds the bytes written to it to the list.
+/// Until then a new back chunk is held empty, so every call to `chunk_mut` should be followed
+/// by one to `advance_mut`, even with a count of 0, which drops the chunk if it's still empty.
+unsafe impl<const N: usize> BufMut for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.len()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let room = self.chunks.back().map_or(0, |chunk| N - chunk.len());
+        assert!(
+            cnt <= room,
+            "cannot advance past `chunk_mut`: {cnt} should be <= {room}"
+        );
+
+        let Some(chunk) = self.chunks.back_mut() else {
+            return;
+        };
+
+        if cnt > 0 {
+            // never moves the bytes around, `chunk_mut` already put them at the start of the buffer
+            let mut bytes = Vec::from(core::mem::take(chunk));
+            // SAFETY: the caller guarantees that the first `cnt` bytes past the end of
+            // the back chunk, where `chunk_mut` pointed to, are initialized.
+            unsafe { bytes.set_len(bytes.len() + cnt) };
+            *chunk = VecDeque::from(bytes);
+        } else if chunk.is_empty() {
+            self.chunks.pop_back();
+        }
+
+        self.len += cnt;
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.chunks.back().is_none_or(|chunk| chunk.len() >= N) {
+            self.chunks.push_back(VecDeque::with_capacity(N));
+            self.counters.allocation();
+        }
+
+        let chunk = self.chunks.back_mut().unwrap();
+        // lays the bytes out at the start of the buffer, followed by the room left
+        let mut bytes = Vec::from(core::mem::take(chunk));
+        bytes.reserve_exact(N - bytes.len());
+
+        let len = N - bytes.len();
+        let spare = &mut bytes.spare_capacity_mut()[..len] as *mut [MaybeUninit<u8>];
+        // neither conversion reallocates, the buffer is just handed back to the chunk
+        *chunk = VecDeque::from(bytes);
+
+        // SAFETY: the spare capacity is owned by the back chunk, which stays in the list
+        // as long as the slice borrowing the list is alive.
+        UninitSlice::uninit(unsafe { &mut *spare })
+    }
+
+    fn put_slice(&mut self, src: &[u8]) {
+        self.append_bytes(src);
+    }
+
+    fn put_bytes(&mut self, val: u8, cnt: usize) {
+        let mut rest = cnt;
+        while rest > 0 {
+            let n = rest.min(N);
+            self.append_bytes(&[val; N][..n]);
+            rest -= n;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::IoSlice;
+
+    use bytes::{Buf, BufMut};
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_buf(seed: Vec<u8>, cnt: usize) {
+        fn _test<const N: usize>(seed: &[u8], cnt: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::from_iter(seed.iter().copied());
+            let cnt = cnt % (seed.len() + 1);
+
+            let mut dst = vec![IoSlice::new(&[]); seed.len()];
+            let n = sut.chunks_vectored(&mut dst);
+            let actual: Vec<u8> = dst[..n]
+                .iter()
+                .flat_map(|slice| slice.iter().copied())
+                .collect();
+            assert_eq!(actual, seed);
+
+            sut.advance(cnt);
+            assert_eq!(sut.remaining(), seed.len() - cnt);
+            assert_eq!(sut, seed[cnt..]);
+            assert!(seed[cnt..].starts_with(sut.chunk()));
+
+            let actual = sut.copy_to_bytes(sut.remaining());
+            assert_eq!(actual, seed[cnt..]);
+            assert!(!sut.has_remaining());
+            assert!(sut.chunk().is_empty());
+        }
+
+        _test::<1>(&seed, cnt);
+        _test::<2>(&seed, cnt);
+        _test::<3>(&seed, cnt);
+        _test::<4>(&seed, cnt);
+        _test::<5>(&seed, cnt);
+        _test::<8>(&seed, cnt);
+        _test::<16>(&seed, cnt);
+        _test::<32>(&seed, cnt);
+    }
+
+    #[quickcheck]
+    fn test_buf_mut(seed: Vec<Vec<u8>>) {
+        fn _test<const N: usize>(seed: &[Vec<u8>])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::new();
+            let mut expected = Vec::new();
+
+            for (i, bytes) in seed.iter().enumerate() {
+                match i % 3 {
+                    0 => sut.put_slice(bytes),
+                    1 => sut.put(bytes.as_slice()),
+                    _ => {
+                        // a chunk left unwritten is dropped rather than kept empty
+                        sut.chunk_mut();
+                        unsafe { sut.advance_mut(0) };
+
+                        let mut values = bytes.as_slice();
+                        while !values.is_empty() {
+                            let chunk = sut.chunk_mut();
+                            assert!((1..=N).contains(&chunk.len()));
+
+                            let n = values.len().min(chunk.len());
+                            chunk[..n].copy_from_slice(&values[..n]);
+                            unsafe { sut.advance_mut(n) };
+                            values = &values[n..];
+                        }
+                    }
+                }
+
+                sut.put_bytes(i as u8, i);
+                expected.extend(bytes);
+                expected.extend(std::iter::repeat_n(i as u8, i));
+            }
+
+            assert_eq!(sut.len(), expected.len());
+            assert_eq!(sut, expected.as_slice());
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+
+/// Per-chunk totals of `D` quantities, kept in Fenwick trees so that prefix sums
+/// and searches over the chunks take O(log chunks).
+#[derive(Clone, Debug)]
+pub(crate) struct ChunkSums<const D: usize> {
+    values: Vec<[usize; D]>,
+    trees: [Fenwick; D],
+}
+
+impl<const D: usize> ChunkSums<D> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            trees: [const { Fenwick::new() }; D],
+        }
+    }
+
+    pub(crate) fn from_chunks<T>(
+        chunks: &VecDeque<VecDeque<T>>,
+        measure: impl FnMut(&VecDeque<T>) -> [usize; D],
+    ) -> Self {
+        let values: Vec<_> = chunks.iter().map(measure).collect();
+        let trees = core::array::from_fn(|d| Fenwick::from_values(values.iter().map(|v| v[d])));
+        Self { values, trees }
+    }
+
+    /// Returns the total of the `d`-th quantity over all the chunks.
+    pub(crate) fn total(&self, d: usize) -> usize {
+        self.trees[d].prefix(self.values.len())
+    }
+
+    /// Returns the total of the `d`-th quantity over the first `n` chunks.
+    pub(crate) fn prefix(&self, d: usize, n: usize) -> usize {
+        self.trees[d].prefix(n)
+    }
+
+    /// Returns the number of leading chunks whose `d`-th totals sum to at most `target`,
+    /// along with what's left of `target` after subtracting that sum.
+    pub(crate) fn search(&self, d: usize, target: usize) -> (usize, usize) {
+        self.trees[d].search(target)
+    }
+
+    /// Measures again the chunks that were in `start..start + count` before an edit,
+    /// which may have replaced them with any number of chunks but left the others untouched.
+    pub(crate) fn refresh<T>(
+        &mut self,
+        chunks: &VecDeque<VecDeque<T>>,
+        start: usize,
+        count: usize,
+        measure: impl FnMut(&VecDeque<T>) -> [usize; D],
+    ) {
+        let end = self.values.len().min(start + count);
+        let new_end = end + chunks.len() - self.values.len();
+        let measured = chunks.range(start..new_end).map(measure);
+
+        if new_end == end {
+            for (index, value) in (start..).zip(measured) {
+                let old = core::mem::replace(&mut self.values[index], value);
+                for (d, tree) in self.trees.iter_mut().enumerate() {
+                    tree.add(index, value[d].wrapping_sub(old[d]));
+                }
+            }
+        } else if end == self.values.len() {
+            // the edit happened at the back, so the trees only grow or shrink at their end
+            let measured: Vec<_> = measured.collect();
+            self.values.truncate(start);
+            self.trees.iter_mut().for_each(|tree| tree.truncate(start));
+
+            for value in measured {
+                self.values.push(value);
+                for (d, tree) in self.trees.iter_mut().enumerate() {
+                    tree.push(value[d]);
+                }
+            }
+        } else {
+            let measured: Vec<_> = measured.collect();
+            self.values.splice(start..end, measured);
+            self.trees =
+                core::array::from_fn(|d| Fenwick::from_values(self.values.iter().map(|v| v[d])));
+        }
+    }
+}
+
+/// A Fenwick tree over per-chunk totals.
+///
+/// Values are added with wrapping arithmetic so that a decrease can be applied as the
+/// two's complement of the difference, while every prefix sum stays exact.
+#[derive(Clone, Debug)]
+struct Fenwick {
+    tree: Vec<usize>,
+}
+
+impl Fenwick {
+    const fn new() -> Self {
+        Self { tree: Vec::new() }
+    }
+
+    fn from_values(values: impl IntoIterator<Item = usize>) -> Self {
+        let mut tree: Vec<usize> = values.into_iter().collect();
+
+        for index in 1..=tree.len() {
+            let parent = index + lowest_bit(index);
+            if parent <= tree.len() {
+                tree[parent - 1] = tree[parent - 1].wrapping_add(tree[index - 1]);
+            }
+        }
+
+        Self { tree }
+    }
+
+    /// Returns the sum of the first `n` values.
+    fn prefix(&self, mut n: usize) -> usize {
+        let mut sum = 0usize;
+        while n > 0 {
+            sum = sum.wrapping_add(self.tree[n - 1]);
+            n &= n - 1;
+        }
+        sum
+    }
+
+    fn add(&mut self, index: usize, delta: usize) {
+        let mut n = index + 1;
+        while n <= self.tree.len() {
+            self.tree[n - 1] = self.tree[n - 1].wrapping_add(delta);
+            n += lowest_bit(n);
+        }
+    }
+
+    fn push(&mut self, value: usize) {
+        let n = self.tree.len() + 1;
+        let covered = self
+            .prefix(n - 1)
+            .wrapping_sub(self.prefix(n - lowest_bit(n)));
+        self.tree.push(value.wrapping_add(covered));
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.tree.truncate(len);
+    }
+
+    /// Returns the number of leading values whose sum doesn't exceed `target`,
+    /// along with what's left of `target` after subtracting that sum.
+    fn search(&self, mut target: usize) -> (usize, usize) {
+        let mut n = 0;
+        let mut step = self.tree.len().checked_ilog2().map_or(0, |log| 1 << log);
+
+        while step > 0 {
+            if n + step <= self.tree.len() && self.tree[n + step - 1] <= target {
+                n += step;
+                target -= self.tree[n - 1];
+            }
+            step >>= 1;
+        }
+
+        (n, target)
+    }
+}
+
+fn lowest_bit(n: usize) -> usize {
+    n & n.wrapping_neg()
+}
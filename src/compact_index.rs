@@ -0,0 +1,56 @@
+/// A position stored by the cursors, which takes 32 bits instead of a whole `usize`
+/// with the `compact_index` feature.
+///
+/// The length of `ArrayList` and the bookkeeping of its chunks are left out on purpose:
+/// they live in std's `VecDeque`s, whose fields are words, so a 32-bit length would only
+/// turn into padding and `size_of::<ArrayList>()` would stay the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct CompactIndex(Repr);
+
+#[cfg(feature = "compact_index")]
+type Repr = u32;
+
+#[cfg(not(feature = "compact_index"))]
+type Repr = usize;
+
+impl CompactIndex {
+    pub(crate) const ZERO: Self = Self(0);
+
+    /// # Panics
+    /// - Panics if `index` doesn't fit in 32 bits with the `compact_index` feature.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn new(index: usize) -> Self {
+        #[cfg(feature = "compact_index")]
+        let index = u32::try_from(index).unwrap_or_else(|_| {
+            panic!("index (is {index}) should fit in 32 bits with compact_index")
+        });
+
+        Self(index)
+    }
+
+    #[inline]
+    pub(crate) const fn get(self) -> usize {
+        #[cfg(feature = "compact_index")]
+        return self.0 as usize;
+
+        #[cfg(not(feature = "compact_index"))]
+        self.0
+    }
+}
+
+impl core::ops::AddAssign<usize> for CompactIndex {
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: usize) {
+        *self = Self::new(self.get() + rhs);
+    }
+}
+
+impl core::ops::SubAssign<usize> for CompactIndex {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = Self::new(self.get() - rhs);
+    }
+}
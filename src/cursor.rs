@@ -1,8 +1,49 @@
 use std::collections::VecDeque;
 
-use crate::{ArrayList, ChunkCapacity, Usize};
+use crate::compact_index::CompactIndex;
+use crate::{ArrayList, ChunkCapacity, Iter, SearchTarget, Usize};
 
-/// A cursor over a ArrayList.
+/// The position of a cursor, kept without borrowing the list.
+///
+/// This struct is created by Cursor::position() and CursorMut::position(),
+/// and turned back into a cursor by ArrayList::cursor_from_position().
+///
+/// Only the index of the current element is recorded, so a position taken before the list
+/// was edited is restored by index rather than by element:
+/// - a position at an element goes to the element now at that index,
+///   or to the “ghost” non-element if the list got too short;
+/// - a position at the “ghost” non-element stays there, whatever the length of the list.
+///
+/// # Examples
+/// ```
+/// use array_list::ArrayList;
+///
+/// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3]);
+///
+/// let mut cursor = list.cursor_front();
+/// cursor.seek_to(2);
+/// let bookmark = cursor.position();
+///
+/// list.push_front(-1);
+/// assert_eq!(list.cursor_from_position(bookmark).current(), Some(&1));
+///
+/// list.pop_back_n(3);
+/// assert_eq!(list.cursor_from_position(bookmark).current(), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CursorPosition {
+    pub(crate) index: Option<usize>,
+}
+
+impl CursorPosition {
+    /// Returns the index of the element the cursor was pointing at,
+    /// or `None` for the “ghost” non-element.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+/// A cursor over an ArrayList.
 ///
 /// A Cursor is like an iterator, except that it can freely seek back-and-forth.  
 /// Cursors always rest between two elements in the list, and index in a logically circular way.  
@@ -15,12 +56,17 @@ where
     Usize<N>: ChunkCapacity,
 {
     pub(crate) list: &'a ArrayList<T, N>,
-    pub(crate) index: usize,
-    pub(crate) chunk_index: usize,
-    pub(crate) inner_index: usize,
+    pub(crate) index: CompactIndex,
+    pub(crate) chunk_index: CompactIndex,
+    pub(crate) inner_index: CompactIndex,
 }
 
+#[cfg(not(feature = "compact_index"))]
 const _: [(); core::mem::size_of::<usize>() * 4] = [(); core::mem::size_of::<Cursor<usize, 2>>()];
+#[cfg(feature = "compact_index")]
+const _: [(); (core::mem::size_of::<usize>() + 12)
+    .next_multiple_of(core::mem::align_of::<usize>())] =
+    [(); core::mem::size_of::<Cursor<usize, 2>>()];
 
 impl<'a, T, const N: usize> Cursor<'a, T, N>
 where
@@ -29,39 +75,103 @@ where
 {
     pub(crate) fn from_front(list: &'a ArrayList<T, N>) -> Self {
         Self {
-            index: 0,
-            chunk_index: 0,
-            inner_index: 0,
+            index: CompactIndex::ZERO,
+            chunk_index: CompactIndex::ZERO,
+            inner_index: CompactIndex::ZERO,
             list,
         }
     }
 
     pub(crate) fn from_back(list: &'a ArrayList<T, N>) -> Self {
         Self {
-            index: list.len().saturating_sub(1),
-            chunk_index: list.chunks.len().saturating_sub(1),
-            inner_index: list
-                .chunks
-                .back()
-                .map_or(0, VecDeque::len)
-                .saturating_sub(1),
+            index: CompactIndex::new(list.len().saturating_sub(1)),
+            chunk_index: CompactIndex::new(list.chunks.len().saturating_sub(1)),
+            inner_index: CompactIndex::new(
+                list.chunks
+                    .back()
+                    .map_or(0, VecDeque::len)
+                    .saturating_sub(1),
+            ),
             list,
         }
     }
 
+    /// Moves the cursor `n` positions forward, the same as calling `move_next` `n` times.
+    pub fn advance_by(&mut self, n: usize) {
+        let positions = self.list.len() + 1;
+        let position = self.index.get().min(self.list.len());
+        self.seek_to((position + n % positions) % positions);
+    }
+
+    /// Returns the contiguous runs of elements in the current chunk, starting from the current one.
+    /// If the cursor is pointing at the “ghost” non-element, both slices are empty.
+    ///
+    /// Like [`VecDeque::as_slices`], the first slice must be scanned before the second one.
+    pub fn after_slices(&self) -> (&'a [T], &'a [T]) {
+        self.split_chunk().1
+    }
+
     pub fn as_list(&self) -> &'a ArrayList<T, N> {
         self.list
     }
 
+    /// Returns `true` if the current element is the first or the last one of its chunk,
+    /// so that moving past it on that side steps into another chunk.
+    /// The “ghost” non-element is not at a chunk boundary.
+    pub fn at_chunk_boundary(&self) -> bool {
+        if self.is_ghost() {
+            return false;
+        }
+
+        let inner_index = self.inner_index.get();
+        inner_index == 0 || inner_index + 1 == self.list.chunks[self.chunk_index.get()].len()
+    }
+
     pub fn back(&self) -> Option<&'a T> {
         self.list.back()
     }
 
+    /// Returns the contiguous runs of elements in the current chunk that precede the current one.
+    /// If the cursor is pointing at the “ghost” non-element, the whole last chunk is returned.
+    ///
+    /// Like [`VecDeque::as_slices`], the first slice must be scanned before the second one.
+    pub fn before_slices(&self) -> (&'a [T], &'a [T]) {
+        self.split_chunk().0
+    }
+
     pub fn current(&self) -> Option<&'a T> {
         self.list
             .chunks
-            .get(self.chunk_index)
-            .and_then(|chunk| chunk.get(self.inner_index))
+            .get(self.chunk_index.get())
+            .and_then(|chunk| chunk.get(self.inner_index.get()))
+    }
+
+    /// Returns how many positions `other` is ahead of this cursor, negative if it's behind.
+    /// The “ghost” non-element counts as the position right after the back of the list.
+    ///
+    /// # Panics
+    /// - Panics if the cursors are over different lists.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let start = list.cursor_front();
+    /// let mut end = list.cursor_front();
+    /// end.seek_to(3);
+    ///
+    /// assert_eq!(start.distance_to(&end), 3);
+    /// assert_eq!(end.distance_to(&start), -3);
+    /// ```
+    #[track_caller]
+    pub fn distance_to(&self, other: &Self) -> isize {
+        assert!(
+            core::ptr::eq(self.list, other.list),
+            "cursors are over different lists"
+        );
+        other.offset() as isize - self.offset() as isize
     }
 
     pub fn front(&self) -> Option<&'a T> {
@@ -73,43 +183,111 @@ where
             return None;
         }
 
-        Some(self.index)
+        Some(self.index.get())
+    }
+
+    /// Returns the index of the current element counting from the back of the list,
+    /// or `None` if the cursor is pointing at the “ghost” non-element.
+    pub fn index_from_back(&self) -> Option<usize> {
+        if self.is_ghost() {
+            return None;
+        }
+
+        Some(self.list.len() - self.index.get() - 1)
+    }
+
+    /// Returns `true` if the cursor is pointing at the back element of the list.
+    pub fn is_back(&self) -> bool {
+        self.index.get() + 1 == self.list.len()
+    }
+
+    /// Returns `true` if the cursor is pointing at the front element of the list.
+    pub fn is_front(&self) -> bool {
+        self.index.get() == 0 && !self.list.is_empty()
+    }
+
+    /// Returns `true` if the cursor is pointing at the “ghost” non-element,
+    /// which is always the case over an empty list.
+    #[inline]
+    pub fn is_ghost(&self) -> bool {
+        self.index.get() >= self.list.len()
+    }
+
+    /// Returns an iterator over the elements from the current one, included,
+    /// to the one `other` is pointing at, excluded.
+    /// The iterator is empty if `other` is not ahead of this cursor.
+    ///
+    /// # Panics
+    /// - Panics if the cursors are over different lists.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from(['l', 'e', 't', ' ', 'x']);
+    ///
+    /// let start = list.cursor_front();
+    /// let mut end = list.cursor_front();
+    /// while end.current().is_some_and(|c| c.is_alphabetic()) {
+    ///     end.move_next();
+    /// }
+    ///
+    /// assert_eq!(String::from_iter(start.iter_to(&end)), "let");
+    /// assert_eq!(end.iter_to(&start).count(), 0);
+    /// ```
+    #[track_caller]
+    pub fn iter_to(&self, other: &Self) -> Iter<'a, T, N> {
+        let len = self.distance_to(other);
+        if len <= 0 {
+            return Iter::default();
+        }
+
+        Iter::from_range(
+            self.list,
+            (self.chunk_index.get(), self.inner_index.get()),
+            (other.chunk_index.get(), other.inner_index.get()),
+            len as usize,
+        )
     }
 
     pub fn move_next(&mut self) {
         if self.is_ghost() {
-            self.index = 0;
-            self.chunk_index = 0;
-            self.inner_index = 0;
+            self.index = CompactIndex::ZERO;
+            self.chunk_index = CompactIndex::ZERO;
+            self.inner_index = CompactIndex::ZERO;
             return;
         }
 
         self.index += 1;
 
         self.inner_index += 1;
-        if self.inner_index >= self.list.chunks[self.chunk_index].len() {
+        if self.inner_index.get() >= self.list.chunks[self.chunk_index.get()].len() {
             self.chunk_index += 1;
-            self.inner_index = 0;
+            self.inner_index = CompactIndex::ZERO;
         }
     }
 
     pub fn move_prev(&mut self) {
-        if self.index == 0 {
-            self.index = self.list.len();
-            self.chunk_index = self.list.chunks.len();
-            self.inner_index = 0;
+        if self.index.get() == 0 {
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
             return;
         }
 
         self.index -= 1;
 
-        if self.inner_index > 0 {
+        if self.inner_index.get() > 0 {
             self.inner_index -= 1;
             return;
         }
 
         self.chunk_index -= 1;
-        self.inner_index = self.list.chunks[self.chunk_index].len().saturating_sub(1);
+        self.inner_index = CompactIndex::new(
+            self.list.chunks[self.chunk_index.get()]
+                .len()
+                .saturating_sub(1),
+        );
     }
 
     pub fn peek_next(&self) -> Option<&'a T> {
@@ -117,34 +295,199 @@ where
             return self.front();
         }
 
-        if self.inner_index + 1 < self.list.chunks[self.chunk_index].len() {
-            return self.list.chunks[self.chunk_index].get(self.inner_index + 1);
+        if self.inner_index.get() + 1 < self.list.chunks[self.chunk_index.get()].len() {
+            return self.list.chunks[self.chunk_index.get()].get(self.inner_index.get() + 1);
         }
 
         self.list
             .chunks
-            .get(self.chunk_index + 1)
+            .get(self.chunk_index.get() + 1)
             .and_then(VecDeque::front)
     }
 
     pub fn peek_prev(&self) -> Option<&'a T> {
-        if self.index == 0 {
+        if self.index.get() == 0 {
             return None;
         }
 
-        if self.inner_index > 0 {
-            return Some(&self.list.chunks[self.chunk_index][self.inner_index - 1]);
+        if self.inner_index.get() > 0 {
+            return Some(&self.list.chunks[self.chunk_index.get()][self.inner_index.get() - 1]);
         }
 
         self.list
             .chunks
-            .get(self.chunk_index - 1)
+            .get(self.chunk_index.get() - 1)
             .and_then(VecDeque::back)
     }
 
+    /// Returns the position of the cursor, which doesn't borrow the list
+    /// and can be turned back into a cursor later on.
+    pub fn position(&self) -> CursorPosition {
+        CursorPosition {
+            index: self.index(),
+        }
+    }
+
+    /// Returns the number of elements from the current one to the back of the list, both included.
+    /// If the cursor is pointing at the “ghost” non-element, this is `0`.
+    pub fn remaining(&self) -> usize {
+        self.list.len() - self.index.get().min(self.list.len())
+    }
+
+    /// Moves the cursor `n` positions backward, the same as calling `move_prev` `n` times.
+    pub fn rewind_by(&mut self, n: usize) {
+        let positions = self.list.len() + 1;
+        let position = self.index.get().min(self.list.len());
+        self.seek_to((position + positions - n % positions) % positions);
+    }
+
+    /// Moves the cursor backward as long as the current element satisfies `predicate`,
+    /// stopping at the first one that doesn't, or at the “ghost” non-element.
+    /// Returns how many positions the cursor moved.
+    ///
+    /// The elements are scanned a chunk at a time, rather than one `move_prev` call at a time.
+    pub fn seek_back_while(&mut self, predicate: impl FnMut(&T) -> bool) -> usize {
+        if self.is_ghost() {
+            return 0;
+        }
+
+        let (n, target) =
+            self.list
+                .scan_backward(self.chunk_index.get(), self.inner_index.get(), predicate);
+        match target {
+            Some(target) => {
+                self.index -= n;
+                self.chunk_index = CompactIndex::new(target.chunk_index);
+                self.inner_index = CompactIndex::new(target.target_index);
+            }
+            None => {
+                self.index = CompactIndex::new(self.list.len());
+                self.chunk_index = CompactIndex::new(self.list.chunks.len());
+                self.inner_index = CompactIndex::ZERO;
+            }
+        }
+
+        n
+    }
+
+    /// Moves the cursor to the element at `index`, or to the “ghost” non-element if `index` is out of bounds.
+    pub fn seek_to(&mut self, index: usize) {
+        match self.list.search_target_from(
+            self.index.get(),
+            self.chunk_index.get(),
+            self.inner_index.get(),
+            index,
+        ) {
+            Some(SearchTarget {
+                chunk_index,
+                target_index,
+            }) => {
+                self.index = CompactIndex::new(index);
+                self.chunk_index = CompactIndex::new(chunk_index);
+                self.inner_index = CompactIndex::new(target_index);
+            }
+            None => {
+                self.index = CompactIndex::new(self.list.len());
+                self.chunk_index = CompactIndex::new(self.list.chunks.len());
+                self.inner_index = CompactIndex::ZERO;
+            }
+        }
+    }
+
+    /// Moves the cursor forward as long as the current element satisfies `predicate`,
+    /// stopping at the first one that doesn't, or at the “ghost” non-element.
+    /// Returns how many positions the cursor moved.
+    ///
+    /// The elements are scanned a chunk at a time, rather than one `move_next` call at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 4> = "   let x".chars().collect();
+    /// let mut cursor = list.cursor_front();
+    ///
+    /// assert_eq!(cursor.seek_while(|c| c.is_whitespace()), 3);
+    /// assert_eq!(cursor.index(), Some(3));
+    /// assert_eq!(cursor.seek_while(|c| c.is_alphabetic()), 3);
+    /// assert_eq!(cursor.seek_while(|c| c.is_whitespace()), 1);
+    /// assert_eq!(cursor.index(), Some(7));
+    /// ```
+    pub fn seek_while(&mut self, predicate: impl FnMut(&T) -> bool) -> usize {
+        if self.is_ghost() {
+            return 0;
+        }
+
+        let (n, target) =
+            self.list
+                .scan_forward(self.chunk_index.get(), self.inner_index.get(), predicate);
+        self.index += n;
+        self.chunk_index = CompactIndex::new(target.chunk_index);
+        self.inner_index = CompactIndex::new(target.target_index);
+        n
+    }
+
+    /// Splits the slices of the current chunk at the current element,
+    /// the “ghost” non-element sits right after the end of the last chunk.
+    #[allow(clippy::type_complexity)]
+    fn split_chunk(&self) -> ((&'a [T], &'a [T]), (&'a [T], &'a [T])) {
+        let (chunk, at) = if self.is_ghost() {
+            match self.list.chunks.back() {
+                Some(chunk) => (chunk, chunk.len()),
+                None => return ((&[], &[]), (&[], &[])),
+            }
+        } else {
+            (
+                &self.list.chunks[self.chunk_index.get()],
+                self.inner_index.get(),
+            )
+        };
+
+        let (front, back) = chunk.as_slices();
+        if at < front.len() {
+            let (head, tail) = front.split_at(at);
+            ((head, &[]), (tail, back))
+        } else {
+            let (head, tail) = back.split_at(at - front.len());
+            ((front, head), (tail, &[]))
+        }
+    }
+
+    /// Returns the index of the current element, or the length of the list for the “ghost” non-element.
     #[inline]
-    fn is_ghost(&self) -> bool {
-        self.index >= self.list.len()
+    fn offset(&self) -> usize {
+        self.index.get().min(self.list.len())
+    }
+}
+
+/// Iterates from the current element, included, to the back of the list.
+/// The iterator is empty if the cursor is pointing at the “ghost” non-element.
+///
+/// # Examples
+/// ```
+/// use array_list::ArrayList;
+///
+/// let list: ArrayList<_, 2> = ArrayList::from([1, 2, 3, 4, 5]);
+///
+/// let mut cursor = list.cursor_front();
+/// cursor.seek_while(|&v| v < 3);
+///
+/// assert_eq!(cursor.into_iter().map(|v| v * 10).collect::<Vec<_>>(), [30, 40, 50]);
+/// ```
+impl<'a, T, const N: usize> IntoIterator for Cursor<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::from_range(
+            self.list,
+            (self.chunk_index.get(), self.inner_index.get()),
+            (self.list.chunks.len(), 0),
+            self.remaining(),
+        )
     }
 }
 
@@ -158,8 +501,8 @@ where
             .field("list", self.list)
             .field("current", &self.current())
             .field("index", &self.index())
-            .field("chunk_index", &self.chunk_index)
-            .field("inner_index", &self.inner_index)
+            .field("chunk_index", &self.chunk_index.get())
+            .field("inner_index", &self.inner_index.get())
             .finish()
     }
 }
@@ -409,6 +752,292 @@ mod tests {
         _test::<16>(&seed);
         _test::<32>(&seed);
     }
+
+    #[quickcheck]
+    fn test_cursor_seek(seed: Vec<i32>, moves: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], moves: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let expected_list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = expected_list.cursor_front();
+            let mut sut = list.cursor_front();
+
+            for &(kind, n) in moves {
+                let n = usize::from(n);
+
+                match kind % 3 {
+                    0 => {
+                        sut.advance_by(n);
+                        (0..n).for_each(|_| expected.move_next());
+                    }
+                    1 => {
+                        sut.rewind_by(n);
+                        (0..n).for_each(|_| expected.move_prev());
+                    }
+                    _ => {
+                        sut.seek_to(n);
+                        // only the plain moves are trusted to find the expected position
+                        expected = expected_list.cursor_front();
+                        (0..n.min(seed.len())).for_each(|_| expected.move_next());
+                    }
+                }
+
+                assert_eq!(sut.index(), expected.index());
+                assert_eq!(sut.current(), expected.current());
+                assert_eq!(sut.peek_prev(), expected.peek_prev());
+                assert_eq!(sut.peek_next(), expected.peek_next());
+            }
+        }
+
+        _test::<1>(&seed, &moves);
+        _test::<2>(&seed, &moves);
+        _test::<3>(&seed, &moves);
+        _test::<4>(&seed, &moves);
+        _test::<5>(&seed, &moves);
+        _test::<8>(&seed, &moves);
+        _test::<16>(&seed, &moves);
+        _test::<32>(&seed, &moves);
+    }
+
+    #[quickcheck]
+    fn test_cursor_boundary_predicates(seed: Vec<i32>, front: u8, moves: Vec<u8>) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, moves: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let mut boundaries = Vec::new();
+            let mut start = 0;
+            for chunk in &list.chunks {
+                boundaries.extend([start, start + chunk.len() - 1]);
+                start += chunk.len();
+            }
+
+            let len = seed.len();
+            let expected = |position: usize| {
+                (
+                    position == len,
+                    position == 0 && len > 0,
+                    position + 1 == len,
+                    boundaries.contains(&position),
+                )
+            };
+
+            let mut sut = list.cursor_front();
+            for &n in moves {
+                sut.seek_to(usize::from(n));
+                assert_eq!(
+                    (
+                        sut.is_ghost(),
+                        sut.is_front(),
+                        sut.is_back(),
+                        sut.at_chunk_boundary()
+                    ),
+                    expected(usize::from(n).min(len))
+                );
+            }
+
+            let mut sut = list.cursor_front_mut();
+            for &n in moves {
+                sut.seek_to(usize::from(n));
+                assert_eq!(
+                    (
+                        sut.is_ghost(),
+                        sut.is_front(),
+                        sut.is_back(),
+                        sut.at_chunk_boundary()
+                    ),
+                    expected(usize::from(n).min(len))
+                );
+            }
+        }
+
+        _test::<1>(&seed, front, &moves);
+        _test::<2>(&seed, front, &moves);
+        _test::<3>(&seed, front, &moves);
+        _test::<4>(&seed, front, &moves);
+        _test::<5>(&seed, front, &moves);
+        _test::<8>(&seed, front, &moves);
+        _test::<16>(&seed, front, &moves);
+        _test::<32>(&seed, front, &moves);
+    }
+
+    #[quickcheck]
+    fn test_cursor_position_survives_edits(seed: Vec<i32>, index: usize, edits: Vec<(bool, i32)>) {
+        fn _test<const N: usize>(seed: &[i32], index: usize, edits: &[(bool, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut cursor = list.cursor_front();
+            cursor.seek_to(index % (seed.len() + 2));
+            let position = cursor.position();
+            assert_eq!(position.index(), cursor.index());
+            assert_eq!(
+                list.cursor_from_position(position).index(),
+                position.index()
+            );
+
+            for &(push, value) in edits {
+                if push {
+                    list.push_front(value);
+                } else {
+                    list.pop_back();
+                }
+            }
+
+            let expected = position.index().filter(|&index| index < list.len());
+            assert_eq!(list.cursor_from_position(position).index(), expected);
+            assert_eq!(list.cursor_mut_from_position(position).index(), expected);
+            assert_eq!(
+                list.cursor_from_position(position).current(),
+                expected.and_then(|index| list.get(index))
+            );
+        }
+
+        _test::<1>(&seed, index, &edits);
+        _test::<2>(&seed, index, &edits);
+        _test::<3>(&seed, index, &edits);
+        _test::<4>(&seed, index, &edits);
+        _test::<5>(&seed, index, &edits);
+        _test::<8>(&seed, index, &edits);
+        _test::<16>(&seed, index, &edits);
+        _test::<32>(&seed, index, &edits);
+    }
+
+    #[quickcheck]
+    fn test_cursor_slices(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing to the front makes chunks wrap around
+            let list = seed
+                .iter()
+                .rev()
+                .fold(ArrayList::<_, N>::new(), |mut list, &value| {
+                    list.push_front(value);
+                    list
+                });
+
+            let mut sut = list.cursor_front();
+            let mut chunk_start = 0;
+            for chunk in &list.chunks {
+                let chunk_end = chunk_start + chunk.len();
+
+                for i in chunk_start..chunk_end {
+                    let (a, b) = sut.before_slices();
+                    assert_eq!([a, b].concat(), seed[chunk_start..i]);
+
+                    let (a, b) = sut.after_slices();
+                    assert_eq!([a, b].concat(), seed[i..chunk_end]);
+                    assert_eq!(a.first(), sut.current());
+
+                    sut.move_next();
+                }
+
+                chunk_start = chunk_end;
+            }
+
+            let last_chunk_len = list.chunks.back().map_or(0, |chunk| chunk.len());
+            let (a, b) = sut.before_slices();
+            assert_eq!([a, b].concat(), seed[seed.len() - last_chunk_len..]);
+            assert_eq!(sut.after_slices(), (&[][..], &[][..]));
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[quickcheck]
+    fn test_cursor_remaining(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut sut = list.cursor_front();
+
+            for i in 0..seed.len() {
+                assert_eq!(sut.remaining(), seed.len() - i);
+                assert_eq!(sut.index_from_back(), Some(seed.len() - i - 1));
+                sut.move_next();
+            }
+
+            assert_eq!(sut.remaining(), 0);
+            assert_eq!(sut.index_from_back(), None);
+
+            sut.move_prev();
+            assert_eq!(sut.remaining(), seed.len().min(1));
+            assert_eq!(sut.index_from_back(), seed.first().map(|_| 0));
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[quickcheck]
+    fn test_cursor_iter_to(seed: Vec<i32>, moves: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], moves: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            // pushing at the front leaves the chunks partially filled and wrapped
+            if let Some(value) = list.pop_front() {
+                list.push_front(value);
+            }
+
+            let mut start = list.cursor_front();
+            let mut end = list.cursor_back();
+            end.move_next();
+
+            for &(a, b) in moves {
+                start.seek_to(usize::from(a) % (seed.len() + 1));
+                end.seek_to(usize::from(b) % (seed.len() + 1));
+
+                let (from, to) = (start.remaining(), end.remaining());
+                let (from, to) = (seed.len() - from, seed.len() - to);
+                assert_eq!(start.distance_to(&end), to as isize - from as isize);
+
+                let expected = seed.get(from..to).unwrap_or_default();
+                let iter = start.iter_to(&end);
+                assert_eq!(iter.len(), expected.len());
+                assert!(iter.clone().eq(expected.iter()));
+                assert!(iter.rev().eq(expected.iter().rev()));
+            }
+        }
+
+        _test::<1>(&seed, &moves);
+        _test::<2>(&seed, &moves);
+        _test::<3>(&seed, &moves);
+        _test::<4>(&seed, &moves);
+        _test::<5>(&seed, &moves);
+        _test::<8>(&seed, &moves);
+        _test::<16>(&seed, &moves);
+        _test::<32>(&seed, &moves);
+    }
 }
 
 #[cfg(feature = "nightly_tests")]
@@ -564,14 +1193,7 @@ mod nightly_tests {
     ) where
         Usize<N>: ChunkCapacity,
     {
-        // FIXME
-        assert_eq!(
-            // see: https://github.com/rust-lang/rust/issues/147616
-            expected
-                .index()
-                .map(|n| expected.peek_prev().map_or(0, |_| n)),
-            actual.index()
-        );
+        assert_eq!(expected.index(), actual.index());
         assert_eq!(expected.current(), actual.current());
 
         assert_eq!(expected.front(), actual.front());
@@ -1,8 +1,9 @@
 use std::collections::VecDeque;
 
-use crate::{ArrayList, ChunkCapacity, Cursor, Usize};
+use crate::compact_index::CompactIndex;
+use crate::{ArrayList, ChunkCapacity, Cursor, CursorPosition, IterMut, SearchTarget, Usize};
 
-/// A cursor over a ArrayList.
+/// A cursor over an ArrayList.
 ///
 /// A Cursor is like an iterator, except that it can freely seek back-and-forth.  
 /// Cursors always rest between two elements in the list, and index in a logically circular way.  
@@ -14,13 +15,18 @@ where
     Usize<N>: ChunkCapacity,
 {
     list: &'a mut ArrayList<T, N>,
-    index: usize,
-    chunk_index: usize,
-    inner_index: usize,
+    index: CompactIndex,
+    chunk_index: CompactIndex,
+    inner_index: CompactIndex,
 }
 
+#[cfg(not(feature = "compact_index"))]
 const _: [(); core::mem::size_of::<usize>() * 4] =
     [(); core::mem::size_of::<CursorMut<usize, 2>>()];
+#[cfg(feature = "compact_index")]
+const _: [(); (core::mem::size_of::<usize>() + 12)
+    .next_multiple_of(core::mem::align_of::<usize>())] =
+    [(); core::mem::size_of::<CursorMut<usize, 2>>()];
 
 impl<'a, T, const N: usize> CursorMut<'a, T, N>
 where
@@ -28,26 +34,42 @@ where
 {
     pub(crate) fn from_front(list: &'a mut ArrayList<T, N>) -> Self {
         Self {
-            index: 0,
-            chunk_index: 0,
-            inner_index: 0,
+            index: CompactIndex::ZERO,
+            chunk_index: CompactIndex::ZERO,
+            inner_index: CompactIndex::ZERO,
             list,
         }
     }
 
     pub(crate) fn from_back(list: &'a mut ArrayList<T, N>) -> Self {
         Self {
-            index: list.len().saturating_sub(1),
-            chunk_index: list.chunks.len().saturating_sub(1),
-            inner_index: list
-                .chunks
-                .back()
-                .map_or(0, VecDeque::len)
-                .saturating_sub(1),
+            index: CompactIndex::new(list.len().saturating_sub(1)),
+            chunk_index: CompactIndex::new(list.chunks.len().saturating_sub(1)),
+            inner_index: CompactIndex::new(
+                list.chunks
+                    .back()
+                    .map_or(0, VecDeque::len)
+                    .saturating_sub(1),
+            ),
             list,
         }
     }
 
+    /// Moves the cursor `n` positions forward, the same as calling `move_next` `n` times.
+    pub fn advance_by(&mut self, n: usize) {
+        let positions = self.list.len() + 1;
+        let position = self.index.get().min(self.list.len());
+        self.seek_to((position + n % positions) % positions);
+    }
+
+    /// Returns the contiguous runs of elements in the current chunk, starting from the current one.
+    /// If the cursor is pointing at the “ghost” non-element, both slices are empty.
+    ///
+    /// Like [`VecDeque::as_slices`], the first slice must be scanned before the second one.
+    pub fn after_slices(&self) -> (&[T], &[T]) {
+        self.as_cursor().after_slices()
+    }
+
     pub fn as_cursor(&self) -> Cursor<'_, T, N> {
         Cursor {
             list: self.list,
@@ -61,6 +83,18 @@ where
         self.list
     }
 
+    /// Returns `true` if the current element is the first or the last one of its chunk,
+    /// so that moving past it on that side steps into another chunk.
+    /// The “ghost” non-element is not at a chunk boundary.
+    pub fn at_chunk_boundary(&self) -> bool {
+        if self.is_ghost() {
+            return false;
+        }
+
+        let inner_index = self.inner_index.get();
+        inner_index == 0 || inner_index + 1 == self.list.chunks[self.chunk_index.get()].len()
+    }
+
     pub fn back(&self) -> Option<&T> {
         self.list.back()
     }
@@ -69,11 +103,27 @@ where
         self.list.back_mut()
     }
 
+    /// Returns the contiguous runs of elements in the current chunk that precede the current one.
+    /// If the cursor is pointing at the “ghost” non-element, the whole last chunk is returned.
+    ///
+    /// Like [`VecDeque::as_slices`], the first slice must be scanned before the second one.
+    pub fn before_slices(&self) -> (&[T], &[T]) {
+        self.as_cursor().before_slices()
+    }
+
     pub fn current(&mut self) -> Option<&mut T> {
         self.list
             .chunks
-            .get_mut(self.chunk_index)
-            .and_then(|chunk| chunk.get_mut(self.inner_index))
+            .get_mut(self.chunk_index.get())
+            .and_then(|chunk| chunk.get_mut(self.inner_index.get()))
+    }
+
+    /// Removes all the elements following the current one and returns them as a new list.
+    /// If the cursor is pointing at the “ghost” non-element, the whole list is drained.
+    ///
+    /// This is equivalent to calling [`Self::remove_next_n`] with `usize::MAX`.
+    pub fn drain_after(&mut self) -> ArrayList<T, N> {
+        self.remove_next_n(usize::MAX)
     }
 
     pub fn front(&self) -> Option<&T> {
@@ -89,7 +139,17 @@ where
             return None;
         }
 
-        Some(self.index)
+        Some(self.index.get())
+    }
+
+    /// Returns the index of the current element counting from the back of the list,
+    /// or `None` if the cursor is pointing at the “ghost” non-element.
+    pub fn index_from_back(&self) -> Option<usize> {
+        if self.is_ghost() {
+            return None;
+        }
+
+        Some(self.list.len() - self.index.get() - 1)
     }
 
     pub fn insert_after(&mut self, value: T) {
@@ -98,24 +158,50 @@ where
             return;
         }
 
-        let chunk_index = self.chunk_index;
-        let inner_index = self.inner_index;
+        let chunk_index = self.chunk_index.get();
+        let inner_index = self.inner_index.get();
         self.list.raw_insert(chunk_index, inner_index + 1, value);
     }
 
+    /// Inserts the elements yielded by `iter` right after the current one, in order.
+    /// If the cursor is pointing at the “ghost” non-element, they are inserted at the front of the list.
+    ///
+    /// Elements first fill the room left around the insertion point, only the rest
+    /// are collected into full chunks spliced into the list.
+    pub fn insert_after_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if self.is_ghost() {
+            self.list.insert_iter(0, 0, iter);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
+            return;
+        }
+
+        let ((chunk_index, target_index), _) =
+            self.list
+                .insert_iter(self.chunk_index.get(), self.inner_index.get() + 1, iter);
+
+        // the current element may have been moved to the previous chunk to make room
+        self.chunk_index = CompactIndex::new(chunk_index);
+        self.inner_index = CompactIndex::new(target_index - 1);
+    }
+
     pub fn insert_before(&mut self, value: T) {
         if self.is_ghost() {
             self.push_back(value);
             return;
         }
 
-        let chunk_index = self.chunk_index;
-        let inner_index = self.inner_index;
+        let chunk_index = self.chunk_index.get();
+        let inner_index = self.inner_index.get();
 
-        let chunk = &self.list.chunks[self.chunk_index];
-        if (chunk.len() + 1 > N) && (self.index == 0 || self.inner_index + 1 >= N) {
+        let chunk = &self.list.chunks[self.chunk_index.get()];
+        if (chunk.len() + 1 > N) && (self.index.get() == 0 || self.inner_index.get() + 1 >= N) {
             self.chunk_index += 1;
-            self.inner_index = 0;
+            self.inner_index = CompactIndex::ZERO;
         } else {
             self.inner_index += 1;
         }
@@ -124,40 +210,88 @@ where
         self.list.raw_insert(chunk_index, inner_index, value);
     }
 
+    /// Inserts the elements yielded by `iter` right before the current one, in order.
+    /// If the cursor is pointing at the “ghost” non-element, they are inserted at the back of the list.
+    ///
+    /// Elements first fill the room left around the insertion point, only the rest
+    /// are collected into full chunks spliced into the list.
+    pub fn insert_before_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if self.is_ghost() {
+            self.list.insert_iter(self.list.chunks.len(), 0, iter);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
+            return;
+        }
+
+        let len = self.list.len();
+        let (_, (chunk_index, target_index)) =
+            self.list
+                .insert_iter(self.chunk_index.get(), self.inner_index.get(), iter);
+
+        self.index += self.list.len() - len;
+        self.chunk_index = CompactIndex::new(chunk_index);
+        self.inner_index = CompactIndex::new(target_index);
+    }
+
+    /// Returns `true` if the cursor is pointing at the back element of the list.
+    pub fn is_back(&self) -> bool {
+        self.index.get() + 1 == self.list.len()
+    }
+
+    /// Returns `true` if the cursor is pointing at the front element of the list.
+    pub fn is_front(&self) -> bool {
+        self.index.get() == 0 && !self.list.is_empty()
+    }
+
+    /// Returns `true` if the cursor is pointing at the “ghost” non-element,
+    /// which is always the case over an empty list.
+    #[inline]
+    pub fn is_ghost(&self) -> bool {
+        self.index.get() >= self.list.len()
+    }
+
     pub fn move_next(&mut self) {
         if self.is_ghost() {
-            self.index = 0;
-            self.chunk_index = 0;
-            self.inner_index = 0;
+            self.index = CompactIndex::ZERO;
+            self.chunk_index = CompactIndex::ZERO;
+            self.inner_index = CompactIndex::ZERO;
             return;
         }
 
         self.index += 1;
 
         self.inner_index += 1;
-        if self.inner_index >= self.list.chunks[self.chunk_index].len() {
+        if self.inner_index.get() >= self.list.chunks[self.chunk_index.get()].len() {
             self.chunk_index += 1;
-            self.inner_index = 0;
+            self.inner_index = CompactIndex::ZERO;
         }
     }
 
     pub fn move_prev(&mut self) {
-        if self.index == 0 {
-            self.index = self.list.len();
-            self.chunk_index = self.list.chunks.len();
-            self.inner_index = 0;
+        if self.index.get() == 0 {
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
             return;
         }
 
         self.index -= 1;
 
-        if self.inner_index > 0 {
+        if self.inner_index.get() > 0 {
             self.inner_index -= 1;
             return;
         }
 
         self.chunk_index -= 1;
-        self.inner_index = self.list.chunks[self.chunk_index].len().saturating_sub(1);
+        self.inner_index = CompactIndex::new(
+            self.list.chunks[self.chunk_index.get()]
+                .len()
+                .saturating_sub(1),
+        );
     }
 
     pub fn peek_next(&mut self) -> Option<&mut T> {
@@ -165,28 +299,28 @@ where
             return self.front_mut();
         }
 
-        if self.inner_index + 1 < self.list.chunks[self.chunk_index].len() {
-            return self.list.chunks[self.chunk_index].get_mut(self.inner_index + 1);
+        if self.inner_index.get() + 1 < self.list.chunks[self.chunk_index.get()].len() {
+            return self.list.chunks[self.chunk_index.get()].get_mut(self.inner_index.get() + 1);
         }
 
         self.list
             .chunks
-            .get_mut(self.chunk_index + 1)
+            .get_mut(self.chunk_index.get() + 1)
             .and_then(VecDeque::front_mut)
     }
 
     pub fn peek_prev(&mut self) -> Option<&mut T> {
-        if self.index == 0 {
+        if self.index.get() == 0 {
             return None;
         }
 
-        if self.inner_index > 0 {
-            return Some(&mut self.list.chunks[self.chunk_index][self.inner_index - 1]);
+        if self.inner_index.get() > 0 {
+            return Some(&mut self.list.chunks[self.chunk_index.get()][self.inner_index.get() - 1]);
         }
 
         self.list
             .chunks
-            .get_mut(self.chunk_index.saturating_sub(1))
+            .get_mut(self.chunk_index.get().saturating_sub(1))
             .and_then(VecDeque::back_mut)
     }
 
@@ -197,9 +331,9 @@ where
         self.list.push_front(value);
 
         if is_ghost {
-            self.index = self.list.len();
-            self.chunk_index = self.list.chunks.len();
-            self.inner_index = 0;
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
             return;
         }
 
@@ -209,7 +343,7 @@ where
             self.chunk_index += 1;
         }
 
-        if self.chunk_index == 0 {
+        if self.chunk_index.get() == 0 {
             self.inner_index += 1;
         }
     }
@@ -220,9 +354,9 @@ where
         self.list.push_back(value);
 
         if is_ghost {
-            self.index = self.list.len();
-            self.chunk_index = self.list.chunks.len();
-            self.inner_index = 0;
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
         }
     }
 
@@ -231,14 +365,14 @@ where
 
         let out = self.list.pop_front();
 
-        self.index = self.index.saturating_sub(1);
+        self.index = CompactIndex::new(self.index.get().saturating_sub(1));
 
-        if self.chunk_index == 0 {
-            self.inner_index = self.inner_index.saturating_sub(1);
+        if self.chunk_index.get() == 0 {
+            self.inner_index = CompactIndex::new(self.inner_index.get().saturating_sub(1));
         }
 
         if self.list.chunks.len() < chunks_len_backup {
-            self.chunk_index = self.chunk_index.saturating_sub(1);
+            self.chunk_index = CompactIndex::new(self.chunk_index.get().saturating_sub(1));
         }
 
         out
@@ -248,32 +382,230 @@ where
         let out = self.list.pop_back();
 
         if self.is_ghost() {
-            self.chunk_index = self.list.chunks.len().saturating_sub(1);
-            self.inner_index = self
-                .list
-                .chunks
-                .get(self.chunk_index)
-                .map_or(0, VecDeque::len);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
         }
 
         out
     }
 
+    /// Returns the position of the cursor, which doesn't borrow the list
+    /// and can be turned back into a cursor later on.
+    pub fn position(&self) -> CursorPosition {
+        CursorPosition {
+            index: self.index(),
+        }
+    }
+
+    /// Returns the number of elements from the current one to the back of the list, both included.
+    /// If the cursor is pointing at the “ghost” non-element, this is `0`.
+    pub fn remaining(&self) -> usize {
+        self.list.len() - self.index.get().min(self.list.len())
+    }
+
     pub fn remove_current(&mut self) -> Option<T> {
         let index = self.index()?;
 
-        let chunk = &self.list.chunks[self.chunk_index];
-        if self.inner_index > 0 && self.inner_index + 1 >= chunk.len() {
+        let chunk = &self.list.chunks[self.chunk_index.get()];
+        if self.inner_index.get() > 0 && self.inner_index.get() + 1 >= chunk.len() {
             self.chunk_index += 1;
-            self.inner_index = 0;
+            self.inner_index = CompactIndex::ZERO;
         }
 
         self.list.remove(index)
     }
 
-    #[inline]
-    fn is_ghost(&self) -> bool {
-        self.index >= self.list.len()
+    /// Removes up to `n` elements following the current one and returns them as a new list.
+    /// If the cursor is pointing at the “ghost” non-element, elements are removed from the front of the list.
+    ///
+    /// Chunks that are entirely covered are moved to the returned list as they are.
+    pub fn remove_next_n(&mut self, n: usize) -> ArrayList<T, N> {
+        if self.is_ghost() {
+            let out = self.list.split_range(0, 0, n);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
+            return out;
+        }
+
+        self.list
+            .split_range(self.chunk_index.get(), self.inner_index.get() + 1, n)
+    }
+
+    /// Moves the cursor `n` positions backward, the same as calling `move_prev` `n` times.
+    pub fn rewind_by(&mut self, n: usize) {
+        let positions = self.list.len() + 1;
+        let position = self.index.get().min(self.list.len());
+        self.seek_to((position + positions - n % positions) % positions);
+    }
+
+    /// Moves the cursor backward as long as the current element satisfies `predicate`,
+    /// stopping at the first one that doesn't, or at the “ghost” non-element.
+    /// Returns how many positions the cursor moved.
+    ///
+    /// The elements are scanned a chunk at a time, rather than one `move_prev` call at a time.
+    pub fn seek_back_while(&mut self, predicate: impl FnMut(&T) -> bool) -> usize {
+        if self.is_ghost() {
+            return 0;
+        }
+
+        let (n, target) =
+            self.list
+                .scan_backward(self.chunk_index.get(), self.inner_index.get(), predicate);
+        match target {
+            Some(target) => {
+                self.index -= n;
+                self.chunk_index = CompactIndex::new(target.chunk_index);
+                self.inner_index = CompactIndex::new(target.target_index);
+            }
+            None => {
+                self.index = CompactIndex::new(self.list.len());
+                self.chunk_index = CompactIndex::new(self.list.chunks.len());
+                self.inner_index = CompactIndex::ZERO;
+            }
+        }
+
+        n
+    }
+
+    /// Moves the cursor to the element at `index`, or to the “ghost” non-element if `index` is out of bounds.
+    pub fn seek_to(&mut self, index: usize) {
+        match self.list.search_target_from(
+            self.index.get(),
+            self.chunk_index.get(),
+            self.inner_index.get(),
+            index,
+        ) {
+            Some(SearchTarget {
+                chunk_index,
+                target_index,
+            }) => {
+                self.index = CompactIndex::new(index);
+                self.chunk_index = CompactIndex::new(chunk_index);
+                self.inner_index = CompactIndex::new(target_index);
+            }
+            None => {
+                self.index = CompactIndex::new(self.list.len());
+                self.chunk_index = CompactIndex::new(self.list.chunks.len());
+                self.inner_index = CompactIndex::ZERO;
+            }
+        }
+    }
+
+    /// Moves the cursor forward as long as the current element satisfies `predicate`,
+    /// stopping at the first one that doesn't, or at the “ghost” non-element.
+    /// Returns how many positions the cursor moved.
+    ///
+    /// The elements are scanned a chunk at a time, rather than one `move_next` call at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 4> = "   let x".chars().collect();
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// assert_eq!(cursor.seek_while(|c| c.is_whitespace()), 3);
+    /// assert_eq!(cursor.index(), Some(3));
+    /// assert_eq!(cursor.seek_while(|c| c.is_alphabetic()), 3);
+    /// assert_eq!(cursor.seek_while(|c| c.is_whitespace()), 1);
+    /// assert_eq!(cursor.index(), Some(7));
+    /// ```
+    pub fn seek_while(&mut self, predicate: impl FnMut(&T) -> bool) -> usize {
+        if self.is_ghost() {
+            return 0;
+        }
+
+        let (n, target) =
+            self.list
+                .scan_forward(self.chunk_index.get(), self.inner_index.get(), predicate);
+        self.index += n;
+        self.chunk_index = CompactIndex::new(target.chunk_index);
+        self.inner_index = CompactIndex::new(target.target_index);
+        n
+    }
+
+    /// Moves all the elements of `other` right after the current one, leaving `other` empty.
+    /// If the cursor is pointing at the “ghost” non-element, they are moved to the front of the list.
+    ///
+    /// Elements are moved a whole chunk at a time, the current chunk is split at most once.
+    pub fn splice_after(&mut self, other: &mut ArrayList<T, N>) {
+        if other.is_empty() {
+            return;
+        }
+
+        if self.is_ghost() {
+            self.list.splice_chunks(0, 0, other);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
+            return;
+        }
+
+        self.list
+            .splice_chunks(self.chunk_index.get(), self.inner_index.get() + 1, other);
+    }
+
+    /// Moves all the elements of `other` right before the current one, leaving `other` empty.
+    /// If the cursor is pointing at the “ghost” non-element, they are moved to the back of the list.
+    ///
+    /// Elements are moved a whole chunk at a time, the current chunk is split at most once.
+    pub fn splice_before(&mut self, other: &mut ArrayList<T, N>) {
+        if other.is_empty() {
+            return;
+        }
+
+        if self.is_ghost() {
+            self.list.splice_chunks(self.list.chunks.len(), 0, other);
+            self.index = CompactIndex::new(self.list.len());
+            self.chunk_index = CompactIndex::new(self.list.chunks.len());
+            self.inner_index = CompactIndex::ZERO;
+            return;
+        }
+
+        let other_len = other.len();
+        let other_chunks_len = other.chunks.len();
+        self.list
+            .splice_chunks(self.chunk_index.get(), self.inner_index.get(), other);
+
+        self.index += other_len;
+        if self.inner_index.get() > 0 {
+            self.chunk_index += 1 + other_chunks_len;
+            self.inner_index = CompactIndex::ZERO;
+        } else {
+            self.chunk_index += other_chunks_len;
+        }
+    }
+}
+
+/// Iterates from the current element, included, to the back of the list.
+/// The iterator is empty if the cursor is pointing at the “ghost” non-element.
+///
+/// # Examples
+/// ```
+/// use array_list::ArrayList;
+///
+/// let mut list: ArrayList<_, 2> = ArrayList::from([1, 2, 3, 4, 5]);
+///
+/// let mut cursor = list.cursor_front_mut();
+/// cursor.seek_while(|&v| v < 3);
+/// cursor.into_iter().for_each(|v| *v *= 10);
+///
+/// assert_eq!(list, [1, 2, 30, 40, 50]);
+/// ```
+impl<'a, T, const N: usize> IntoIterator for CursorMut<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.remaining();
+        let end = (self.list.chunks.len(), 0);
+        let start = (self.chunk_index.get(), self.inner_index.get());
+        IterMut::from_range(self.list, start, end, len)
     }
 }
 
@@ -287,8 +619,8 @@ where
             .field("list", self.list)
             .field("current", &self.as_cursor().current())
             .field("index", &self.index())
-            .field("chunk_index", &self.chunk_index)
-            .field("inner_index", &self.inner_index)
+            .field("chunk_index", &self.chunk_index.get())
+            .field("inner_index", &self.inner_index.get())
             .finish()
     }
 }
@@ -727,6 +1059,415 @@ mod tests {
         _test::<16>(&seed);
         _test::<32>(&seed);
     }
+
+    #[quickcheck]
+    fn test_cursor_seek(seed: Vec<i32>, moves: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], moves: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected_list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = expected_list.cursor_front_mut();
+            let mut sut = list.cursor_front_mut();
+
+            for &(kind, n) in moves {
+                let n = usize::from(n);
+
+                match kind % 3 {
+                    0 => {
+                        sut.advance_by(n);
+                        (0..n).for_each(|_| expected.move_next());
+                    }
+                    1 => {
+                        sut.rewind_by(n);
+                        (0..n).for_each(|_| expected.move_prev());
+                    }
+                    _ => {
+                        sut.seek_to(n);
+                        // only the plain moves are trusted to find the expected position
+                        expected = expected_list.cursor_front_mut();
+                        (0..n.min(seed.len())).for_each(|_| expected.move_next());
+                    }
+                }
+
+                assert_eq!(sut.index(), expected.index());
+                assert_eq!(sut.current(), expected.current());
+                assert_eq!(sut.peek_prev(), expected.peek_prev());
+                assert_eq!(sut.peek_next(), expected.peek_next());
+            }
+        }
+
+        _test::<1>(&seed, &moves);
+        _test::<2>(&seed, &moves);
+        _test::<3>(&seed, &moves);
+        _test::<4>(&seed, &moves);
+        _test::<5>(&seed, &moves);
+        _test::<8>(&seed, &moves);
+        _test::<16>(&seed, &moves);
+        _test::<32>(&seed, &moves);
+    }
+
+    #[quickcheck]
+    fn test_cursor_seek_while(seed: Vec<u8>, moves: Vec<(bool, u8)>) {
+        fn _test<const N: usize>(seed: &[u8], moves: &[(bool, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a small alphabet makes for long runs
+            let seed: Vec<_> = seed.iter().map(|v| v % 4).collect();
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = expected.cursor_front_mut();
+            let mut sut = list.cursor_front_mut();
+
+            for &(forward, bound) in moves {
+                let bound = bound % 4;
+                let mut n = 0;
+
+                if forward {
+                    assert_eq!(sut.seek_while(|&v| v <= bound), {
+                        while expected.current().is_some_and(|v| *v <= bound) {
+                            expected.move_next();
+                            n += 1;
+                        }
+                        n
+                    });
+                } else {
+                    assert_eq!(sut.seek_back_while(|&v| v <= bound), {
+                        while expected.current().is_some_and(|v| *v <= bound) {
+                            expected.move_prev();
+                            n += 1;
+                        }
+                        n
+                    });
+                }
+
+                assert_eq!(sut.index(), expected.index());
+                assert_eq!(sut.current(), expected.current());
+                assert_eq!(sut.peek_prev(), expected.peek_prev());
+                assert_eq!(sut.peek_next(), expected.peek_next());
+
+                // step off the element the scan stopped at, wrapping around the ghost
+                sut.move_next();
+                expected.move_next();
+            }
+        }
+
+        _test::<1>(&seed, &moves);
+        _test::<2>(&seed, &moves);
+        _test::<3>(&seed, &moves);
+        _test::<4>(&seed, &moves);
+        _test::<5>(&seed, &moves);
+        _test::<8>(&seed, &moves);
+        _test::<16>(&seed, &moves);
+        _test::<32>(&seed, &moves);
+    }
+
+    #[quickcheck]
+    fn test_cursor_splice(seed: Vec<i32>, other: Vec<i32>, index: usize, after: bool) {
+        fn _test<const N: usize>(seed: &[i32], other: &[i32], index: usize, after: bool)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut other_list = ArrayList::<_, N>::from_iter(other.iter().copied());
+            let index = index % (seed.len() + 1);
+
+            let mut sut = list.cursor_front_mut();
+            sut.seek_to(index);
+            let current = sut.current().copied();
+
+            let at = match (after, index < seed.len()) {
+                (true, true) => index + 1,
+                (true, false) => 0,
+                (false, _) => index,
+            };
+
+            if after {
+                sut.splice_after(&mut other_list);
+            } else {
+                sut.splice_before(&mut other_list);
+            }
+
+            let expected = [&seed[..at], other, &seed[at..]].concat();
+            let expected_index = match (after, index < seed.len()) {
+                (_, false) => None,
+                (true, true) => Some(index),
+                (false, true) => Some(index + other.len()),
+            };
+
+            assert_eq!(sut.index(), expected_index);
+            assert_eq!(sut.current().copied(), current);
+            assert_eq!(
+                sut.peek_prev().copied(),
+                expected_index
+                    .map_or(expected.last(), |i| i
+                        .checked_sub(1)
+                        .and_then(|i| expected.get(i)))
+                    .copied()
+            );
+            assert_eq!(
+                sut.peek_next().copied(),
+                expected_index
+                    .map_or(expected.first(), |i| expected.get(i + 1))
+                    .copied()
+            );
+
+            assert!(other_list.is_empty());
+            assert_eq!(list.len(), expected.len());
+            assert_eq!(list, expected.as_slice());
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+        }
+
+        _test::<1>(&seed, &other, index, after);
+        _test::<2>(&seed, &other, index, after);
+        _test::<3>(&seed, &other, index, after);
+        _test::<4>(&seed, &other, index, after);
+        _test::<5>(&seed, &other, index, after);
+        _test::<8>(&seed, &other, index, after);
+        _test::<16>(&seed, &other, index, after);
+        _test::<32>(&seed, &other, index, after);
+    }
+
+    #[quickcheck]
+    fn test_cursor_insert_iter(seed: Vec<i32>, other: Vec<i32>, index: usize, after: bool) {
+        fn _test<const N: usize>(seed: &[i32], other: &[i32], index: usize, after: bool)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = list.clone();
+            let index = index % (seed.len() + 1);
+
+            let mut sut = list.cursor_front_mut();
+            sut.seek_to(index);
+            let mut reference = expected.cursor_front_mut();
+            reference.seek_to(index);
+
+            if after {
+                sut.insert_after_iter(other.iter().copied());
+                other
+                    .iter()
+                    .rev()
+                    .for_each(|&value| reference.insert_after(value));
+            } else {
+                sut.insert_before_iter(other.iter().copied());
+                other
+                    .iter()
+                    .for_each(|&value| reference.insert_before(value));
+            }
+
+            assert_eq!(sut.index(), reference.index());
+            assert_eq!(sut.current(), reference.current());
+            assert_eq!(sut.peek_prev(), reference.peek_prev());
+            assert_eq!(sut.peek_next(), reference.peek_next());
+            assert_eq!(list, expected);
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed, &other, index, after);
+        _test::<2>(&seed, &other, index, after);
+        _test::<3>(&seed, &other, index, after);
+        _test::<4>(&seed, &other, index, after);
+        _test::<5>(&seed, &other, index, after);
+        _test::<8>(&seed, &other, index, after);
+        _test::<16>(&seed, &other, index, after);
+        _test::<32>(&seed, &other, index, after);
+    }
+
+    #[quickcheck]
+    fn test_cursor_insert_iter_fills_chunks(index: usize, batches: Vec<(bool, u8)>) {
+        fn _test<const N: usize>(index: usize, batches: &[(bool, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected: Vec<usize> = (0..4 * N).collect();
+            let mut list = ArrayList::<_, N>::from_iter(expected.iter().copied());
+            let mut index = index % expected.len();
+
+            let mut cursor = list.cursor_front_mut();
+            cursor.seek_to(index);
+
+            // small batches, inserted again and again around the same element
+            for (i, &(after, len)) in batches.iter().enumerate() {
+                let values = (0..usize::from(len) % (N + 1)).map(|value| 100 * i + value);
+
+                if after {
+                    expected.splice(index + 1..index + 1, values.clone());
+                    cursor.insert_after_iter(values);
+                } else {
+                    let len = values.len();
+                    expected.splice(index..index, values.clone());
+                    cursor.insert_before_iter(values);
+                    index += len;
+                }
+
+                assert_eq!(cursor.index(), Some(index));
+            }
+
+            assert_eq!(list, expected.as_slice());
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+            // no two neighboring chunks fit in one, so chunks are at least half full on average
+            assert!(list.chunks.len() <= 2 * list.len().div_ceil(N) + 1);
+        }
+
+        _test::<1>(index, &batches);
+        _test::<2>(index, &batches);
+        _test::<3>(index, &batches);
+        _test::<4>(index, &batches);
+        _test::<5>(index, &batches);
+        _test::<8>(index, &batches);
+        _test::<16>(index, &batches);
+        _test::<32>(index, &batches);
+    }
+
+    #[quickcheck]
+    fn test_cursor_remove_next_n(seed: Vec<i32>, index: usize, n: u8) {
+        fn _test<const N: usize>(seed: &[i32], index: usize, n: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let index = index % (seed.len() + 1);
+
+            let mut sut = list.cursor_front_mut();
+            sut.seek_to(index);
+            let current = sut.current().copied();
+
+            let start = if index < seed.len() { index + 1 } else { 0 };
+            let end = seed.len().min(start + n);
+
+            let removed = sut.remove_next_n(n);
+            assert_eq!(removed.len(), end - start);
+            assert_eq!(removed, seed[start..end]);
+            assert!(
+                removed
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+
+            let expected = [&seed[..start], &seed[end..]].concat();
+            assert_eq!(sut.current().copied(), current);
+            assert_eq!(sut.index(), (index < seed.len()).then_some(index));
+            assert_eq!(
+                sut.peek_next().copied(),
+                if index < seed.len() {
+                    expected.get(index + 1)
+                } else {
+                    expected.first()
+                }
+                .copied()
+            );
+
+            let drained = sut.drain_after();
+            assert_eq!(drained, expected[start..]);
+            assert_eq!(sut.peek_next(), None);
+
+            assert_eq!(list.len(), start);
+            assert_eq!(list, expected[..start]);
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+        }
+
+        _test::<1>(&seed, index, n.into());
+        _test::<2>(&seed, index, n.into());
+        _test::<3>(&seed, index, n.into());
+        _test::<4>(&seed, index, n.into());
+        _test::<5>(&seed, index, n.into());
+        _test::<8>(&seed, index, n.into());
+        _test::<16>(&seed, index, n.into());
+        _test::<32>(&seed, index, n.into());
+    }
+
+    #[quickcheck]
+    fn test_cursor_into_iter(seed: Vec<i32>, front: u8, index: usize) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, index: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let index = index % (seed.len() + 2);
+            let expected = seed.get(index..).unwrap_or_default();
+
+            let mut cursor = list.cursor_front();
+            cursor.seek_to(index);
+            let iter = cursor.into_iter();
+            assert_eq!(iter.len(), expected.len());
+            assert!(iter.eq(expected));
+
+            let mut cursor = list.cursor_front_mut();
+            cursor.seek_to(index);
+            let iter = cursor.into_iter();
+            assert_eq!(iter.len(), expected.len());
+            assert!(iter.rev().map(|v| *v).eq(expected.iter().rev().copied()));
+        }
+
+        _test::<1>(&seed, front, index);
+        _test::<2>(&seed, front, index);
+        _test::<3>(&seed, front, index);
+        _test::<4>(&seed, front, index);
+        _test::<5>(&seed, front, index);
+        _test::<8>(&seed, front, index);
+        _test::<16>(&seed, front, index);
+        _test::<32>(&seed, front, index);
+    }
+
+    #[quickcheck]
+    fn test_cursor_remaining(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut sut = list.cursor_front_mut();
+
+            for i in 0..seed.len() {
+                assert_eq!(sut.remaining(), seed.len() - i);
+                assert_eq!(sut.index_from_back(), Some(seed.len() - i - 1));
+                sut.move_next();
+            }
+
+            assert_eq!(sut.remaining(), 0);
+            assert_eq!(sut.index_from_back(), None);
+
+            sut.move_prev();
+            assert_eq!(sut.remaining(), seed.len().min(1));
+            assert_eq!(sut.index_from_back(), seed.first().map(|_| 0));
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
 }
 
 #[cfg(feature = "nightly_tests")]
@@ -2401,20 +3142,137 @@ mod nightly_tests {
         _test::<512>(&seed);
     }
 
+    #[quickcheck]
+    fn nightly_test_cursor_splice(seed: Vec<i32>, other: Vec<i32>, moves: u8, after: bool) {
+        fn _test<const N: usize>(seed: &[i32], other: &[i32], moves: u8, after: bool)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut linked_list = LinkedList::from_iter(seed.iter().copied());
+            let mut array_list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+
+            let mut expected = linked_list.cursor_front_mut();
+            let mut actual = array_list.cursor_front_mut();
+
+            for _ in 0..moves {
+                expected.move_next();
+                actual.move_next();
+            }
+
+            assert_cursors_give_same_results(&mut expected, &mut actual);
+
+            let mut other_list = ArrayList::<_, N>::from_iter(other.iter().copied());
+            if after {
+                expected.splice_after(LinkedList::from_iter(other.iter().copied()));
+                actual.splice_after(&mut other_list);
+            } else {
+                expected.splice_before(LinkedList::from_iter(other.iter().copied()));
+                actual.splice_before(&mut other_list);
+            }
+
+            assert_cursors_give_same_results(&mut expected, &mut actual);
+            assert!(other_list.is_empty());
+            assert!(linked_list.iter().eq(array_list.iter()));
+        }
+
+        _test::<1>(&seed, &other, moves, after);
+        _test::<2>(&seed, &other, moves, after);
+        _test::<3>(&seed, &other, moves, after);
+        _test::<4>(&seed, &other, moves, after);
+        _test::<5>(&seed, &other, moves, after);
+        _test::<8>(&seed, &other, moves, after);
+        _test::<16>(&seed, &other, moves, after);
+        _test::<32>(&seed, &other, moves, after);
+    }
+
+    #[quickcheck]
+    fn nightly_test_cursor_operations(seed: Vec<i32>, operations: Vec<(u8, i32)>, back: bool) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(u8, i32)], back: bool)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut linked_list = LinkedList::from_iter(seed.iter().copied());
+            let mut array_list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+
+            let (mut expected, mut actual) = if back {
+                (linked_list.cursor_back_mut(), array_list.cursor_back_mut())
+            } else {
+                (
+                    linked_list.cursor_front_mut(),
+                    array_list.cursor_front_mut(),
+                )
+            };
+
+            for &(operation, value) in operations {
+                match operation % 13 {
+                    0 => {
+                        expected.move_next();
+                        actual.move_next();
+                    }
+                    1 => {
+                        expected.move_prev();
+                        actual.move_prev();
+                    }
+                    2 => {
+                        expected.insert_after(value);
+                        actual.insert_after(value);
+                    }
+                    3 => {
+                        expected.insert_before(value);
+                        actual.insert_before(value);
+                    }
+                    4 => assert_eq!(expected.remove_current(), actual.remove_current()),
+                    5 => {
+                        expected.push_front(value);
+                        actual.push_front(value);
+                    }
+                    6 => {
+                        expected.push_back(value);
+                        actual.push_back(value);
+                    }
+                    7 => assert_eq!(expected.pop_front(), actual.pop_front()),
+                    8 => assert_eq!(expected.pop_back(), actual.pop_back()),
+                    9 => {
+                        let other = [value; 3];
+                        expected.splice_after(LinkedList::from_iter(other));
+                        actual.splice_after(&mut ArrayList::from_iter(other));
+                    }
+                    10 => {
+                        let other = [value; 3];
+                        expected.splice_before(LinkedList::from_iter(other));
+                        actual.splice_before(&mut ArrayList::from_iter(other));
+                    }
+                    11 => assert!(expected.split_after().into_iter().eq(actual.drain_after())),
+                    _ => {
+                        for _ in 0..value.unsigned_abs() % 8 {
+                            expected.move_next();
+                        }
+                        actual.advance_by(value.unsigned_abs() as usize % 8);
+                    }
+                }
+
+                assert_cursors_give_same_results(&mut expected, &mut actual);
+                assert!(expected.as_list().iter().eq(actual.as_list().iter()));
+            }
+        }
+
+        _test::<1>(&seed, &operations, back);
+        _test::<2>(&seed, &operations, back);
+        _test::<3>(&seed, &operations, back);
+        _test::<4>(&seed, &operations, back);
+        _test::<5>(&seed, &operations, back);
+        _test::<8>(&seed, &operations, back);
+        _test::<16>(&seed, &operations, back);
+        _test::<32>(&seed, &operations, back);
+    }
+
     fn assert_cursors_give_same_results<const N: usize>(
         expected: &mut linked_list::CursorMut<'_, i32>,
         actual: &mut CursorMut<'_, i32, N>,
     ) where
         Usize<N>: ChunkCapacity,
     {
-        // FIXME
-        assert_eq!(
-            // see: https://github.com/rust-lang/rust/issues/147616
-            expected
-                .index()
-                .map(|n| expected.peek_prev().map_or(0, |_| n)),
-            actual.index()
-        );
+        assert_eq!(expected.index(), actual.index());
         assert_eq!(expected.current(), actual.current(),);
 
         assert_eq!(expected.front(), actual.front());
@@ -0,0 +1,50 @@
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A helper to `Debug`-format the chunks of an ArrayList.
+///
+/// This struct is created by ArrayList::debug_chunks().
+pub struct DebugChunks<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a ArrayList<T, N>,
+}
+
+impl<'a, T, const N: usize> DebugChunks<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: &'a ArrayList<T, N>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for DebugChunks<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.list.chunks.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayList;
+
+    #[test]
+    fn test_debug_chunks_shows_chunks() {
+        let list = ArrayList::<usize, 2>::from([0, 1, 2, 3, 4]);
+        assert_eq!(
+            format!("{:?}", list.debug_chunks()),
+            "[[0, 1], [2, 3], [4]]"
+        );
+
+        let list = ArrayList::<usize, 3>::from([0, 1, 2, 3, 4]);
+        assert_eq!(format!("{:?}", list.debug_chunks()), "[[0, 1, 2], [3, 4]]");
+
+        let list = ArrayList::<usize, 2>::new();
+        assert_eq!(format!("{:?}", list.debug_chunks()), "[]");
+    }
+}
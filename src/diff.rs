@@ -0,0 +1,341 @@
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A single change of an [`EditScript`].
+///
+/// Indices refer to positions in the list as it was before the script is applied.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// Inserts `values` right before the element at `index`,
+    /// or at the back of the list if `index` equals its length.
+    Insert { index: usize, values: Vec<T> },
+    /// Removes `len` elements starting from the one at `index`.
+    Delete { index: usize, len: usize },
+    /// Overwrites the elements starting from the one at `index` with `values`.
+    Replace { index: usize, values: Vec<T> },
+}
+
+impl<T> Edit<T> {
+    /// Returns the positions of the elements touched by the edit, empty for an insertion.
+    fn range(&self) -> core::ops::Range<usize> {
+        match *self {
+            Edit::Insert { index, .. } => index..index,
+            Edit::Delete { index, len } => index..index.saturating_add(len),
+            Edit::Replace { index, ref values } => index..index.saturating_add(values.len()),
+        }
+    }
+}
+
+/// The edits that turn an ArrayList into another one, sorted by index and never overlapping.
+///
+/// This struct is created by ArrayList::diff(), and applied by ArrayList::apply_edits().
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EditScript<T> {
+    edits: Vec<Edit<T>>,
+}
+
+impl<T> EditScript<T> {
+    /// Computes the edits that turn `from` into `to`, using Myers' algorithm.
+    pub(crate) fn between<const N: usize>(from: &ArrayList<T, N>, to: &ArrayList<T, N>) -> Self
+    where
+        T: PartialEq + Clone,
+        Usize<N>: ChunkCapacity,
+    {
+        let a: Vec<&T> = from.iter().collect();
+        let b: Vec<&T> = to.iter().collect();
+
+        let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+        let suffix = a[prefix..]
+            .iter()
+            .rev()
+            .zip(b[prefix..].iter().rev())
+            .take_while(|(x, y)| x == y)
+            .count();
+
+        let (a, b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+        let mut edits = Vec::new();
+        for (x0, y0, x1, y1) in hunks(a, b) {
+            let (deleted, inserted) = (x1 - x0, y1 - y0);
+            let common = deleted.min(inserted);
+            let values = |range: core::ops::Range<usize>| {
+                b[range]
+                    .iter()
+                    .map(|&value| value.clone())
+                    .collect::<Vec<_>>()
+            };
+
+            if common > 0 {
+                edits.push(Edit::Replace {
+                    index: prefix + x0,
+                    values: values(y0..y0 + common),
+                });
+            }
+
+            if deleted > common {
+                edits.push(Edit::Delete {
+                    index: prefix + x0 + common,
+                    len: deleted - common,
+                });
+            }
+
+            if inserted > common {
+                edits.push(Edit::Insert {
+                    index: prefix + x1,
+                    values: values(y0 + common..y1),
+                });
+            }
+        }
+
+        Self { edits }
+    }
+
+    /// Applies the edits to `list` in a single sweep, moving the chunks between them as they are.
+    #[track_caller]
+    pub(crate) fn apply<const N: usize>(self, list: &mut ArrayList<T, N>)
+    where
+        Usize<N>: ChunkCapacity,
+    {
+        let len = list.len();
+        let mut end = 0;
+        for edit in &self.edits {
+            let range = edit.range();
+            assert!(range.start >= end, "edits are not sorted or overlap");
+            assert!(
+                range.end <= len,
+                "edit range end (is {}) should be <= len (is {len})",
+                range.end
+            );
+            end = range.end;
+        }
+
+        let mut rest = core::mem::take(list);
+        list.counters = rest.counters;
+        let mut position = 0;
+
+        for edit in self.edits {
+            let start = edit.range().start;
+            list.append(&mut rest.pop_front_n(start - position));
+            position = start;
+
+            match edit {
+                Edit::Insert { values, .. } => list.extend(values),
+                Edit::Delete { len, .. } => {
+                    rest.pop_front_n(len);
+                    position += len;
+                }
+                Edit::Replace { values, .. } => {
+                    rest.pop_front_n(values.len());
+                    position += values.len();
+                    list.extend(values);
+                }
+            }
+        }
+
+        list.append(&mut rest);
+    }
+
+    pub fn edits(&self) -> &[Edit<T>] {
+        &self.edits
+    }
+
+    /// Consumes `self`, returning the edits.
+    pub fn into_edits(self) -> Vec<Edit<T>> {
+        self.edits
+    }
+
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+impl<T> From<Vec<Edit<T>>> for EditScript<T> {
+    /// Wraps edits, e.g. received from a replica, checking them only once they're applied.
+    fn from(edits: Vec<Edit<T>>) -> Self {
+        Self { edits }
+    }
+}
+
+impl<T> IntoIterator for EditScript<T> {
+    type Item = Edit<T>;
+    type IntoIter = std::vec::IntoIter<Edit<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edits.into_iter()
+    }
+}
+
+/// Returns the regions where `a` and `b` differ, as `(x0, y0, x1, y1)`:
+/// the elements of `a` in `x0..x1` are to be replaced by the ones of `b` in `y0..y1`.
+///
+/// Follows Myers' greedy algorithm, keeping only the furthest reaching paths
+/// of the diagonals each step can reach, so it takes O(D²) memory for D differences.
+fn hunks<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize, usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+
+    let mut v = vec![0isize; 2 * max as usize + 3];
+    let at = |k: isize| (k + max + 1) as usize;
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+
+        trace.push(v[at(-d)..=at(d)].to_vec());
+    }
+
+    // walk the path back, one non-diagonal move per step
+    let (mut x, mut y) = (n, m);
+    let mut moves = Vec::with_capacity(trace.len());
+    for (d, previous) in trace.iter().enumerate().rev() {
+        let (d, k) = (d as isize + 1, x - y);
+        let furthest = |k: isize| previous[(k + d - 1) as usize];
+
+        let previous_k = if k == -d || (k != d && furthest(k - 1) < furthest(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let previous_x = furthest(previous_k);
+        let previous_y = previous_x - previous_k;
+
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+        }
+
+        moves.push((previous_x, previous_y, x, y));
+        x = previous_x;
+        y = previous_y;
+    }
+
+    let mut hunks: Vec<(usize, usize, usize, usize)> = Vec::new();
+    for (x0, y0, x1, y1) in moves.into_iter().rev() {
+        let (x0, y0, x1, y1) = (x0 as usize, y0 as usize, x1 as usize, y1 as usize);
+        match hunks.last_mut() {
+            Some(hunk) if (hunk.2, hunk.3) == (x0, y0) => (hunk.2, hunk.3) = (x1, y1),
+            _ => hunks.push((x0, y0, x1, y1)),
+        }
+    }
+
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::{Edit, EditScript};
+
+    #[test]
+    fn test_diff_finds_minimal_edits() {
+        let from = ArrayList::<_, 2>::from(*b"kitten on a mat");
+        let to = ArrayList::<_, 2>::from(*b"sitting on the mat");
+
+        let script = from.diff(&to);
+        assert_eq!(
+            script.edits(),
+            [
+                Edit::Replace {
+                    index: 0,
+                    values: b"s".to_vec()
+                },
+                Edit::Replace {
+                    index: 4,
+                    values: b"i".to_vec()
+                },
+                Edit::Insert {
+                    index: 6,
+                    values: b"g".to_vec()
+                },
+                Edit::Replace {
+                    index: 10,
+                    values: b"t".to_vec()
+                },
+                Edit::Insert {
+                    index: 11,
+                    values: b"he".to_vec()
+                },
+            ]
+        );
+
+        let mut replica = from.clone();
+        replica.apply_edits(script);
+        assert_eq!(replica, to);
+    }
+
+    #[test]
+    #[should_panic(expected = "edits are not sorted or overlap")]
+    fn test_apply_edits_rejects_overlapping_edits() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.apply_edits(EditScript::from(vec![
+            Edit::Delete { index: 0, len: 2 },
+            Edit::Insert {
+                index: 1,
+                values: vec![3],
+            },
+        ]));
+    }
+
+    #[quickcheck]
+    fn test_diff_turns_list_into_other(from: Vec<u8>, to: Vec<u8>) {
+        fn _test<const N: usize>(from: &[u8], to: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a small alphabet makes for long common runs
+            let mut actual = ArrayList::<_, N>::from_iter(from.iter().map(|v| v % 4));
+            let expected = ArrayList::<_, N>::from_iter(to.iter().map(|v| v % 4));
+
+            let script = actual.diff(&expected);
+            let changed: usize = script
+                .edits()
+                .iter()
+                .map(|edit| match edit {
+                    Edit::Insert { values, .. } | Edit::Replace { values, .. } => values.len(),
+                    Edit::Delete { len, .. } => *len,
+                })
+                .sum();
+            assert!(changed <= from.len() + to.len());
+
+            actual.apply_edits(script);
+            assert_eq!(actual, expected);
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&from, &to);
+        _test::<2>(&from, &to);
+        _test::<3>(&from, &to);
+        _test::<4>(&from, &to);
+        _test::<5>(&from, &to);
+        _test::<8>(&from, &to);
+        _test::<16>(&from, &to);
+        _test::<32>(&from, &to);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A helper to `Display`-format the elements of an ArrayList.
+///
+/// Elements are written one after the other, separated by the given separator.
+/// Formatting options such as width and precision are applied to each element.
+///
+/// This struct is created by ArrayList::display() and ArrayList::display_with().
+pub struct Display<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a ArrayList<T, N>,
+    separator: &'a str,
+}
+
+impl<'a, T, const N: usize> Display<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: &'a ArrayList<T, N>, separator: &'a str) -> Self {
+        Self { list, separator }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Display for Display<'_, T, N>
+where
+    T: core::fmt::Display,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut iter = self.list.iter();
+
+        if let Some(value) = iter.next() {
+            value.fmt(f)?;
+        }
+
+        iter.try_for_each(|value| {
+            f.write_str(self.separator)?;
+            value.fmt(f)
+        })
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for Display<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Display")
+            .field("list", self.list)
+            .field("separator", &self.separator)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayList;
+
+    #[test]
+    fn test_display_joins_elements() {
+        let list = ArrayList::<usize, 2>::from([0, 1, 2, 3, 4]);
+        assert_eq!(list.display().to_string(), "0, 1, 2, 3, 4");
+        assert_eq!(list.display_with(" | ").to_string(), "0 | 1 | 2 | 3 | 4");
+        assert_eq!(list.display_with("").to_string(), "01234");
+
+        let list = ArrayList::<usize, 2>::from([42]);
+        assert_eq!(list.display().to_string(), "42");
+
+        let list = ArrayList::<usize, 2>::new();
+        assert_eq!(list.display().to_string(), "");
+    }
+
+    #[test]
+    fn test_display_applies_format_options_to_each_element() {
+        let list = ArrayList::<f64, 2>::from([0.0, 1.5, 2.25]);
+        assert_eq!(format!("{:.1}", list.display()), "0.0, 1.5, 2.2");
+        assert_eq!(format!("{:>4}", list.display_with(";")), "   0; 1.5;2.25");
+    }
+}
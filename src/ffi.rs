@@ -0,0 +1,170 @@
+//! C bindings for a list of bytes, so that a host application can own and fill lists
+//! that are later handed over to Rust as they are.
+//!
+//! Lists are created with `array_list_new`, behind an opaque [`ByteList`] pointer, and must be
+//! released exactly once, either with `array_list_free` or by taking them back with [`into_list`].
+//!
+//! ```c
+//! typedef struct ByteList ByteList;
+//!
+//! ByteList *array_list_new(void);
+//! void array_list_free(ByteList *list);
+//! void array_list_push_back(ByteList *list, uint8_t value);
+//! void array_list_extend(ByteList *list, const uint8_t *data, size_t len);
+//! bool array_list_get(const ByteList *list, size_t index, uint8_t *out);
+//! size_t array_list_len(const ByteList *list);
+//! void array_list_iterate(const ByteList *list,
+//!                         void (*callback)(const uint8_t *data, size_t len, void *context),
+//!                         void *context);
+//! ```
+
+use core::ffi::c_void;
+
+use crate::ArrayList;
+
+/// The number of bytes each chunk of a [`ByteList`] can hold.
+pub const CHUNK_CAPACITY: usize = 64;
+
+/// The list of bytes handled by the C bindings, opaque to C.
+pub type ByteList = ArrayList<u8, CHUNK_CAPACITY>;
+
+/// Takes back a list created by `array_list_new`, without copying its elements.
+///
+/// # Safety
+/// `list` must come from `array_list_new` and must not be used afterwards.
+pub unsafe fn into_list(list: *mut ByteList) -> ByteList {
+    *unsafe { Box::from_raw(list) }
+}
+
+/// Creates an empty list, to be released with `array_list_free` or taken back with [`into_list`].
+#[unsafe(no_mangle)]
+pub extern "C" fn array_list_new() -> *mut ByteList {
+    Box::into_raw(Box::default())
+}
+
+/// Releases a list and its elements. Does nothing if `list` is null.
+///
+/// # Safety
+/// `list` must be null or come from `array_list_new`, and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_free(list: *mut ByteList) {
+    if !list.is_null() {
+        drop(unsafe { into_list(list) });
+    }
+}
+
+/// Adds a byte to the back of the list.
+///
+/// # Safety
+/// `list` must be a live list from `array_list_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_push_back(list: *mut ByteList, value: u8) {
+    unsafe { &mut *list }.push_back(value);
+}
+
+/// Appends the `len` bytes starting at `data` to the back of the list, filling up the last chunk first.
+///
+/// # Safety
+/// `list` must be a live list from `array_list_new`, and `data` must be valid
+/// for reading `len` bytes, or may be null if `len` is zero.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_extend(list: *mut ByteList, data: *const u8, len: usize) {
+    if len == 0 {
+        return;
+    }
+
+    let bytes = unsafe { core::slice::from_raw_parts(data, len) };
+    unsafe { &mut *list }.append_bytes(bytes);
+}
+
+/// Writes the byte at `index` to `out`, returning `false` and leaving `out` untouched
+/// if `index` is out of bounds.
+///
+/// # Safety
+/// `list` must be a live list from `array_list_new`, and `out` must be valid for writing a byte.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_get(list: *const ByteList, index: usize, out: *mut u8) -> bool {
+    match unsafe { &*list }.get(index) {
+        Some(&value) => {
+            unsafe { out.write(value) };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns the number of bytes in the list.
+///
+/// # Safety
+/// `list` must be a live list from `array_list_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_len(list: *const ByteList) -> usize {
+    unsafe { &*list }.len()
+}
+
+/// Calls `callback` on each contiguous run of bytes of the list, in order,
+/// passing `context` along untouched.
+///
+/// # Safety
+/// `list` must be a live list from `array_list_new`, which `callback` must not change.
+/// The runs are only valid during the call that receives them.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn array_list_iterate(
+    list: *const ByteList,
+    callback: extern "C" fn(data: *const u8, len: usize, context: *mut c_void),
+    context: *mut c_void,
+) {
+    for chunk in &unsafe { &*list }.chunks {
+        let (front, back) = chunk.as_slices();
+        for run in [front, back].into_iter().filter(|run| !run.is_empty()) {
+            callback(run.as_ptr(), run.len(), context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::c_void;
+
+    use super::*;
+
+    extern "C" fn collect(data: *const u8, len: usize, context: *mut c_void) {
+        let bytes = unsafe { &mut *context.cast::<Vec<u8>>() };
+        bytes.extend_from_slice(unsafe { core::slice::from_raw_parts(data, len) });
+    }
+
+    #[test]
+    fn test_ffi_fills_a_list_taken_back_by_rust() {
+        let list = array_list_new();
+        let data: Vec<u8> = (0..=200).collect();
+
+        unsafe {
+            array_list_push_back(list, 42);
+            array_list_extend(list, data.as_ptr(), data.len());
+            array_list_extend(list, core::ptr::null(), 0);
+            assert_eq!(array_list_len(list), 202);
+
+            let mut value = 0;
+            assert!(array_list_get(list, 1, &mut value));
+            assert_eq!(value, 0);
+            assert!(!array_list_get(list, 202, &mut value));
+            assert_eq!(value, 0);
+
+            let mut bytes: Vec<u8> = Vec::new();
+            array_list_iterate(list, collect, (&raw mut bytes).cast());
+            assert_eq!(bytes[0], 42);
+            assert_eq!(bytes[1..], data);
+
+            let list = into_list(list);
+            assert!(list.iter().eq(&bytes));
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=CHUNK_CAPACITY).contains(&chunk.len()))
+            );
+
+            array_list_free(array_list_new());
+            array_list_free(core::ptr::null_mut());
+        }
+    }
+}
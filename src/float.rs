@@ -0,0 +1,149 @@
+use core::cmp::Ordering;
+
+use crate::sailed::Sailed;
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// The floating point types, which can be ordered with their `total_cmp` method.
+///
+/// This trait is sealed and implemented only for `f32` and `f64`.
+pub trait Float: Copy + Sailed {
+    /// Orders `self` and `other` following the IEEE 754 totalOrder predicate,
+    /// same as `f64::total_cmp`.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl Sailed for f32 {}
+
+impl Float for f32 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+}
+
+impl Sailed for f64 {}
+
+impl Float for f64 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+impl<T: Float, const N: usize> ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Sorts the elements of the list following their total order, see [`f64::total_cmp`].
+    ///
+    /// Negative NaNs come first and positive NaNs last, while `-0.0` comes before `0.0`.
+    /// The chunks layout is left untouched, only elements are moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<f64, 2> = ArrayList::from([2.5, f64::NAN, -1.0, 0.0, -0.0]);
+    /// list.sort_by_total_cmp();
+    ///
+    /// assert_eq!(list.get(0), Some(&-1.0));
+    /// assert!(list.get(1).unwrap().is_sign_negative());
+    /// assert!(list.get(2).unwrap().is_sign_positive());
+    /// assert_eq!(list.get(3), Some(&2.5));
+    /// assert!(list.back().unwrap().is_nan());
+    /// ```
+    pub fn sort_by_total_cmp(&mut self) {
+        let mut values: Vec<T> = self.iter().copied().collect();
+        values.sort_unstable_by(T::total_cmp);
+
+        self.iter_mut()
+            .zip(values)
+            .for_each(|(slot, value)| *slot = value);
+    }
+
+    /// Returns the greatest element of the list following their total order,
+    /// or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<f32, 2> = ArrayList::from([1.0, -f32::NAN, 3.0]);
+    /// assert_eq!(list.max_by_total_cmp(), Some(&3.0));
+    /// ```
+    pub fn max_by_total_cmp(&self) -> Option<&T> {
+        self.iter().max_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Returns the least element of the list following their total order,
+    /// or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<f32, 2> = ArrayList::from([1.0, f32::NAN, -3.0]);
+    /// assert_eq!(list.min_by_total_cmp(), Some(&-3.0));
+    /// ```
+    pub fn min_by_total_cmp(&self) -> Option<&T> {
+        self.iter().min_by(|a, b| a.total_cmp(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_sort_by_total_cmp_matches_vec(seed: Vec<f64>, front: u8) {
+        fn _test<const N: usize>(seed: &[f64], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut actual = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| actual.push_front(value));
+
+            let mut expected = seed.to_vec();
+            assert_eq!(
+                actual.max_by_total_cmp().map(|value| value.to_bits()),
+                expected
+                    .iter()
+                    .max_by(|a, b| a.total_cmp(b))
+                    .map(|value| value.to_bits())
+            );
+            assert_eq!(
+                actual.min_by_total_cmp().map(|value| value.to_bits()),
+                expected
+                    .iter()
+                    .min_by(|a, b| a.total_cmp(b))
+                    .map(|value| value.to_bits())
+            );
+
+            let layout: Vec<usize> = actual.chunks.iter().map(|chunk| chunk.len()).collect();
+            actual.sort_by_total_cmp();
+            expected.sort_by(f64::total_cmp);
+
+            assert!(
+                actual
+                    .iter()
+                    .map(|value| value.to_bits())
+                    .eq(expected.iter().map(|value| value.to_bits()))
+            );
+            assert!(actual.chunks.iter().map(|chunk| chunk.len()).eq(layout));
+        }
+
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
+}
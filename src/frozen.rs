@@ -0,0 +1,484 @@
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// An immutable snapshot of an ArrayList whose chunks are shared between clones.
+///
+/// Cloning is a reference count increment, and the snapshot is `Send + Sync` whenever `T` is,
+/// so it can be handed out to reader threads. Each chunk is shared on its own, so the lists
+/// thawed from a snapshot only copy the chunks they change, leaving the others shared.
+///
+/// This struct is created by ArrayList::freeze().
+pub struct FrozenArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    chunks: Arc<VecDeque<Arc<VecDeque<T>>>>,
+    len: usize,
+}
+
+impl<T, const N: usize> FrozenArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(mut list: ArrayList<T, N>) -> Self {
+        let chunks = core::mem::take(&mut list.chunks);
+        Self {
+            chunks: Arc::new(chunks.into_iter().map(Arc::new).collect()),
+            len: list.len,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the specified index, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk_index, target_index) = self.locate(index)?;
+        self.chunks[chunk_index].get(target_index)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.chunks.front()?.front()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.chunks.back()?.back()
+    }
+
+    /// Returns an iterator over the elements of the snapshot.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.chunks.iter().flat_map(|chunk| chunk.iter())
+    }
+
+    /// Turns the snapshot back into a mutable list, in O(1).
+    ///
+    /// The list keeps sharing the chunks of the snapshot, and copies each one
+    /// only the first time it's changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let frozen = ArrayList::<_, 2>::from([0, 1, 2]).freeze();
+    /// let reader = frozen.clone();
+    ///
+    /// let mut list = frozen.thaw();
+    /// list.push_back(3);
+    ///
+    /// assert!(list.iter().eq(&[0, 1, 2, 3]));
+    /// assert!(reader.iter().eq(&[0, 1, 2]));
+    /// ```
+    pub fn thaw(self) -> ThawedArrayList<T, N> {
+        ThawedArrayList { frozen: self }
+    }
+
+    /// Returns `true` if both snapshots share the same storage.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.chunks, &other.chunks)
+    }
+
+    /// Returns the chunk index and the index within that chunk of the element at `index`,
+    /// walking from the closer end.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index <= self.len / 2 {
+            let mut index = index;
+            return self
+                .chunks
+                .iter()
+                .enumerate()
+                .find_map(
+                    |(chunk_index, chunk)| match index.checked_sub(chunk.len()) {
+                        Some(rest) => {
+                            index = rest;
+                            None
+                        }
+                        None => Some((chunk_index, index)),
+                    },
+                );
+        }
+
+        let mut index = self.len - 1 - index;
+        self.chunks
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(
+                |(chunk_index, chunk)| match index.checked_sub(chunk.len()) {
+                    Some(rest) => {
+                        index = rest;
+                        None
+                    }
+                    None => Some((chunk_index, chunk.len() - 1 - index)),
+                },
+            )
+    }
+}
+
+impl<T, const N: usize> Clone for FrozenArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chunks: Arc::clone(&self.chunks),
+            len: self.len,
+        }
+    }
+}
+
+impl<T, const N: usize> From<ArrayList<T, N>> for FrozenArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn from(list: ArrayList<T, N>) -> Self {
+        Self::from_list(list)
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for FrozenArrayList<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A mutable list thawed from a [`FrozenArrayList`], copying its chunks on write.
+///
+/// The chunks are shared with the snapshot it was thawed from, and with the ones taken of it
+/// since, until they're changed: only then is a chunk copied, along with the table of pointers
+/// to the chunks, so that every other chunk stays shared. The list is read through `Deref`.
+///
+/// This struct is created by FrozenArrayList::thaw().
+pub struct ThawedArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    frozen: FrozenArrayList<T, N>,
+}
+
+impl<T, const N: usize> ThawedArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    /// Returns a snapshot of the list, sharing all of its chunks, in O(1).
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<_, 2>::from([0, 1, 2]).freeze().thaw();
+    /// let snapshot = list.snapshot();
+    ///
+    /// list.push_front(-1);
+    ///
+    /// assert!(list.iter().eq(&[-1, 0, 1, 2]));
+    /// assert!(snapshot.iter().eq(&[0, 1, 2]));
+    /// ```
+    pub fn snapshot(&self) -> FrozenArrayList<T, N> {
+        self.frozen.clone()
+    }
+
+    /// Turns the list into a snapshot, in O(1).
+    pub fn freeze(self) -> FrozenArrayList<T, N> {
+        self.frozen
+    }
+
+    /// Consumes `self`, returning a plain list, which copies the chunks that are still shared.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        let FrozenArrayList { chunks, len } = self.frozen;
+        let chunks = Arc::unwrap_or_clone(chunks)
+            .into_iter()
+            .map(Arc::unwrap_or_clone)
+            .collect();
+
+        ArrayList {
+            chunks,
+            len,
+            ..ArrayList::new()
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (chunk_index, target_index) = self.frozen.locate(index)?;
+        let chunks = Arc::make_mut(&mut self.frozen.chunks);
+        Arc::make_mut(&mut chunks[chunk_index]).get_mut(target_index)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let chunks = Arc::make_mut(&mut self.frozen.chunks);
+        match chunks.front_mut() {
+            Some(chunk) if chunk.len() < N => make_room::<T, N>(chunk).push_front(value),
+            _ => {
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_front(value);
+                chunks.push_front(Arc::new(chunk));
+            }
+        }
+
+        self.frozen.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let chunks = Arc::make_mut(&mut self.frozen.chunks);
+        match chunks.back_mut() {
+            Some(chunk) if chunk.len() < N => make_room::<T, N>(chunk).push_back(value),
+            _ => {
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_back(value);
+                chunks.push_back(Arc::new(chunk));
+            }
+        }
+
+        self.frozen.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.frozen.is_empty() {
+            return None;
+        }
+
+        let chunks = Arc::make_mut(&mut self.frozen.chunks);
+        let chunk = chunks.front_mut()?;
+
+        let value = Arc::make_mut(chunk).pop_front();
+        if chunk.is_empty() {
+            chunks.pop_front();
+        }
+
+        self.frozen.len -= 1;
+        value
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.frozen.is_empty() {
+            return None;
+        }
+
+        let chunks = Arc::make_mut(&mut self.frozen.chunks);
+        let chunk = chunks.back_mut()?;
+
+        let value = Arc::make_mut(chunk).pop_back();
+        if chunk.is_empty() {
+            chunks.pop_back();
+        }
+
+        self.frozen.len -= 1;
+        value
+    }
+
+    /// Removes all the elements, dropping the list's handles to its chunks.
+    pub fn clear(&mut self) {
+        self.frozen.chunks = Arc::default();
+        self.frozen.len = 0;
+    }
+}
+
+impl<T, const N: usize> Clone for ThawedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            frozen: self.frozen.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for ThawedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = FrozenArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frozen
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ThawedArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ThawedArrayList<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.frozen, f)
+    }
+}
+
+/// Gets a chunk ready to grow, copying it first if it's shared.
+fn make_room<T: Clone, const N: usize>(chunk: &mut Arc<VecDeque<T>>) -> &mut VecDeque<T> {
+    // a copied chunk only has room for its own elements, and must not grow past `N`
+    let chunk = Arc::make_mut(chunk);
+    chunk.reserve_exact(N - chunk.len());
+    chunk
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+    use std::thread;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::FrozenArrayList;
+
+    #[test]
+    fn test_freeze_shares_storage_between_threads() {
+        let mut list = ArrayList::<usize, 2>::from_iter(0..5);
+        let frozen = list.clone().freeze();
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let snapshot = frozen.clone();
+                assert!(snapshot.ptr_eq(&frozen));
+                thread::spawn(move || snapshot.iter().sum::<usize>())
+            })
+            .collect();
+
+        list.push_back(5);
+
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), 10);
+        }
+
+        assert!(frozen.iter().eq(&[0, 1, 2, 3, 4]));
+        assert_eq!(list, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(format!("{frozen:?}"), "[0, 1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn test_thaw_copies_only_the_chunks_it_changes() {
+        // counts how many times a value went through `clone`
+        #[derive(Debug, PartialEq)]
+        struct Counted(usize);
+
+        impl Clone for Counted {
+            fn clone(&self) -> Self {
+                Self(self.0 + 1)
+            }
+        }
+
+        let frozen =
+            FrozenArrayList::from(ArrayList::<_, 2>::from_iter((0..5).map(|_| Counted(0))));
+        let reader = frozen.clone();
+
+        let mut list = frozen.thaw();
+        list.push_back(Counted(0));
+        assert!(list.iter().map(|value| value.0).eq([0, 0, 0, 0, 1, 0]));
+        assert!(reader.iter().all(|value| value.0 == 0));
+
+        // the chunks left untouched are still shared with the reader
+        let shared = |list: &FrozenArrayList<_, 2>| {
+            (0..3)
+                .map(|chunk_index| {
+                    Arc::ptr_eq(&list.chunks[chunk_index], &reader.chunks[chunk_index])
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(shared(&list), [true, true, false]);
+
+        *list.get_mut(0).unwrap() = Counted(7);
+        assert_eq!(shared(&list), [false, true, false]);
+
+        let list = list.into_list();
+        assert!(list.iter().map(|value| value.0).eq([7, 1, 1, 1, 1, 0]));
+    }
+
+    #[quickcheck]
+    fn test_thawed_matches_vec_deque(seed: Vec<i32>, operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let frozen = ArrayList::<_, N>::from_iter(seed.iter().copied()).freeze();
+            let mut snapshots = vec![(frozen.clone(), seed.to_vec())];
+
+            let mut expected: VecDeque<_> = seed.iter().copied().collect();
+            let mut actual = frozen.thaw();
+
+            for &(operation, value) in operations {
+                match operation % 6 {
+                    0 => {
+                        actual.push_front(value);
+                        expected.push_front(value);
+                    }
+                    1 => {
+                        actual.push_back(value);
+                        expected.push_back(value);
+                    }
+                    2 => assert_eq!(actual.pop_front(), expected.pop_front()),
+                    3 => assert_eq!(actual.pop_back(), expected.pop_back()),
+                    4 => {
+                        let index = value.unsigned_abs() as usize % (expected.len() + 1);
+                        assert_eq!(actual.get_mut(index), expected.get_mut(index));
+                        if let Some(value) = actual.get_mut(index) {
+                            *value = value.wrapping_add(1);
+                            expected[index] = expected[index].wrapping_add(1);
+                        }
+                    }
+                    _ => snapshots.push((actual.snapshot(), Vec::from(expected.clone()))),
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(actual.iter().rev().eq(expected.iter().rev()));
+                assert_eq!(actual.front(), expected.front());
+                assert_eq!(actual.back(), expected.back());
+            }
+
+            for (snapshot, values) in snapshots {
+                assert!(snapshot.iter().eq(values.iter()));
+                for index in 0..=values.len() {
+                    assert_eq!(snapshot.get(index), values.get(index));
+                }
+            }
+
+            let list = actual.into_list();
+            assert!(list.iter().eq(expected.iter()));
+            assert!(
+                list.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::VecDeque;
+
+use crate::{ArrayList, ChunkCapacity, SearchTarget, Usize};
+
+/// A gap-buffer-like editor over an ArrayList, tailored to repeated edits around the same point.
+///
+/// The editor rests in a gap between two elements. The chunk holding the gap is split in two
+/// on creation, so that inserting or removing elements next to the gap only ever touches
+/// the back of the chunk before it or the front of the chunk after it, which is O(1).
+/// A new chunk is allocated once every `N` insertions, as the one before the gap fills up.
+///
+/// Moving the gap shifts one element across it, while dropping the editor or seeking
+/// merges the chunks around the gap back together when they fit in a single one.
+///
+/// The gap can be anywhere from before the front element to after the back one, so there is
+/// no “ghost” non-element: the elements around the gap are peeked at and removed from either
+/// side, and inserted elements always land right before the gap.
+///
+/// This struct is created by ArrayList::edit_at().
+pub struct GapEditor<'a, T, const N: usize>
+where
+    T: 'a,
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a mut ArrayList<T, N>,
+    /// The number of elements before the gap.
+    index: usize,
+    /// The number of chunks before the gap.
+    chunk_index: usize,
+}
+
+impl<'a, T, const N: usize> GapEditor<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    #[track_caller]
+    pub(crate) fn new(list: &'a mut ArrayList<T, N>, index: usize) -> Self {
+        let len = list.len();
+        assert!(
+            index <= len,
+            "gap index (is {index}) should be <= len (is {len})"
+        );
+
+        let mut this = Self {
+            list,
+            index: 0,
+            chunk_index: 0,
+        };
+        this.open(index);
+        this
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        self.list
+    }
+
+    /// Returns the number of elements before the gap.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Inserts an element into the gap, after the ones inserted so far.
+    pub fn insert(&mut self, value: T) {
+        match self.chunk_before() {
+            Some(chunk) if chunk.len() < N => chunk.push_back(value),
+            _ => {
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_back(value);
+                self.list.chunks.insert(self.chunk_index, chunk);
+                self.list.counters.allocation();
+                self.chunk_index += 1;
+            }
+        }
+
+        self.index += 1;
+        self.list.len += 1;
+    }
+
+    /// Moves the gap one element forward.
+    /// Returns `false` if the gap is already at the back of the list.
+    pub fn move_next(&mut self) -> bool {
+        let Some(value) = self.take_next() else {
+            return false;
+        };
+
+        match self.chunk_before() {
+            Some(chunk) if chunk.len() < N => chunk.push_back(value),
+            _ => {
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_back(value);
+                self.list.chunks.insert(self.chunk_index, chunk);
+                self.list.counters.allocation();
+                self.chunk_index += 1;
+            }
+        }
+
+        self.index += 1;
+        true
+    }
+
+    /// Moves the gap one element backward.
+    /// Returns `false` if the gap is already at the front of the list.
+    pub fn move_prev(&mut self) -> bool {
+        let Some(value) = self.take_prev() else {
+            return false;
+        };
+
+        match self.list.chunks.get_mut(self.chunk_index) {
+            Some(chunk) if chunk.len() < N => chunk.push_front(value),
+            _ => {
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_front(value);
+                self.list.chunks.insert(self.chunk_index, chunk);
+                self.list.counters.allocation();
+            }
+        }
+
+        self.index -= 1;
+        true
+    }
+
+    /// Returns the element right after the gap, if any.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.list.chunks.get_mut(self.chunk_index)?.front_mut()
+    }
+
+    /// Returns the element right before the gap, if any.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        self.chunk_before()?.back_mut()
+    }
+
+    /// Removes the element right after the gap, if any.
+    pub fn remove_next(&mut self) -> Option<T> {
+        let value = self.take_next()?;
+        self.list.len -= 1;
+        Some(value)
+    }
+
+    /// Removes the element right before the gap, if any.
+    pub fn remove_prev(&mut self) -> Option<T> {
+        let value = self.take_prev()?;
+        self.index -= 1;
+        self.list.len -= 1;
+        Some(value)
+    }
+
+    /// Moves the gap right before the element at `index`, or at the back of the list
+    /// if `index` equals its length.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn seek_to(&mut self, index: usize) {
+        let len = self.list.len();
+        assert!(
+            index <= len,
+            "gap index (is {index}) should be <= len (is {len})"
+        );
+
+        self.close();
+        self.open(index);
+    }
+
+    fn chunk_before(&mut self) -> Option<&mut VecDeque<T>> {
+        let chunk_index = self.chunk_index.checked_sub(1)?;
+        self.list.chunks.get_mut(chunk_index)
+    }
+
+    /// Pops the element right after the gap, dropping its chunk if it becomes empty.
+    fn take_next(&mut self) -> Option<T> {
+        let chunk = self.list.chunks.get_mut(self.chunk_index)?;
+
+        let value = chunk.pop_front();
+        if chunk.is_empty() {
+            self.list.chunks.remove(self.chunk_index);
+        }
+
+        value
+    }
+
+    /// Pops the element right before the gap, dropping its chunk if it becomes empty.
+    fn take_prev(&mut self) -> Option<T> {
+        let chunk = self.chunk_before()?;
+
+        let value = chunk.pop_back();
+        if chunk.is_empty() {
+            self.chunk_index -= 1;
+            self.list.chunks.remove(self.chunk_index);
+        }
+
+        value
+    }
+
+    /// Splits the chunk holding the element at `index` so that the gap falls on a chunk boundary.
+    fn open(&mut self, index: usize) {
+        self.chunk_index = match self.list.search_target(index) {
+            None => self.list.chunks.len(),
+            Some(SearchTarget {
+                chunk_index,
+                target_index: 0,
+            }) => chunk_index,
+            Some(SearchTarget {
+                chunk_index,
+                target_index,
+            }) => {
+                let mut tail = VecDeque::with_capacity(N);
+                tail.extend(self.list.chunks[chunk_index].drain(target_index..));
+                self.list.chunks.insert(chunk_index + 1, tail);
+                self.list.counters.allocation();
+                self.list.counters.split();
+                chunk_index + 1
+            }
+        };
+
+        self.index = index;
+    }
+
+    /// Merges the chunks around the gap back together if they fit in a single one.
+    fn close(&mut self) {
+        let Some(before) = self.chunk_index.checked_sub(1) else {
+            return;
+        };
+
+        let fits = match self.list.chunks.get(self.chunk_index) {
+            Some(after) => self.list.chunks[before].len() + after.len() <= N,
+            None => false,
+        };
+
+        if fits {
+            let mut after = self.list.chunks.remove(self.chunk_index).unwrap();
+            self.list.chunks[before].append(&mut after);
+            self.list.counters.merge();
+        }
+    }
+}
+
+/// The gap-based cursor, which is the same type as the editor.
+#[deprecated(note = "use `GapEditor`, created by `ArrayList::edit_at`")]
+pub type CursorGap<'a, T, const N: usize> = GapEditor<'a, T, N>;
+
+impl<T, const N: usize> Drop for GapEditor<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for GapEditor<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GapEditor")
+            .field("list", self.as_list())
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_gap_editor_types_around_the_gap() {
+        let mut list: ArrayList<char, 4> = "hello world".chars().collect();
+
+        let mut editor = list.edit_at(5);
+        assert_eq!(editor.peek_prev(), Some(&mut 'o'));
+        assert_eq!(editor.peek_next(), Some(&mut ' '));
+
+        "there,".chars().for_each(|c| editor.insert(c));
+        assert_eq!(editor.remove_prev(), Some(','));
+        assert_eq!(editor.index(), 10);
+
+        editor.seek_to(0);
+        assert!(!editor.move_prev());
+        editor.insert('>');
+        assert!(editor.move_next());
+        assert_eq!(editor.remove_next(), Some('e'));
+        editor.insert('E');
+        drop(editor);
+
+        assert_eq!(String::from_iter(list.iter()), ">hEllothere world");
+        assert_eq!(list.len(), 17);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_cursor_gap_edits_on_both_sides() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2, 3]);
+        let mut gap = sut.cursor_gap(2);
+
+        gap.insert(4);
+        gap.insert(5);
+        assert_eq!(gap.index(), 4);
+        assert_eq!(gap.remove_prev(), Some(5));
+        assert_eq!(gap.remove_next(), Some(2));
+        assert_eq!(gap.peek_prev(), Some(&mut 4));
+        assert_eq!(gap.peek_next(), Some(&mut 3));
+
+        assert!(gap.move_next());
+        assert!(!gap.move_next());
+        assert_eq!(gap.remove_next(), None);
+        drop(gap);
+        assert_eq!(sut, [0, 1, 4, 3]);
+    }
+
+    #[quickcheck]
+    fn test_gap_editor_matches_vec(seed: Vec<i32>, start: usize, operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(seed: &[i32], start: usize, operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.to_vec();
+            let mut gap = start % (seed.len() + 1);
+
+            let mut editor = list.edit_at(gap);
+            for &(operation, value) in operations {
+                match operation % 7 {
+                    0 | 1 => {
+                        editor.insert(value);
+                        expected.insert(gap, value);
+                        gap += 1;
+                    }
+                    2 => {
+                        let removed = (gap > 0).then(|| {
+                            gap -= 1;
+                            expected.remove(gap)
+                        });
+                        assert_eq!(editor.remove_prev(), removed);
+                    }
+                    3 => {
+                        let removed = (gap < expected.len()).then(|| expected.remove(gap));
+                        assert_eq!(editor.remove_next(), removed);
+                    }
+                    4 => {
+                        assert_eq!(editor.move_prev(), gap > 0);
+                        gap = gap.saturating_sub(1);
+                    }
+                    5 => {
+                        assert_eq!(editor.move_next(), gap < expected.len());
+                        gap = expected.len().min(gap + 1);
+                    }
+                    _ => {
+                        gap = value.unsigned_abs() as usize % (expected.len() + 1);
+                        editor.seek_to(gap);
+                    }
+                }
+
+                assert_eq!(editor.index(), gap);
+                assert_eq!(
+                    editor.peek_prev().copied(),
+                    gap.checked_sub(1).map(|i| expected[i])
+                );
+                assert_eq!(editor.peek_next().copied(), expected.get(gap).copied());
+
+                let list = editor.as_list();
+                assert_eq!(list.len(), expected.len());
+                assert!(list.iter().eq(expected.iter()));
+                assert!(
+                    list.chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+
+            drop(editor);
+            assert_eq!(list, expected.as_slice());
+        }
+
+        _test::<1>(&seed, start, &operations);
+        _test::<2>(&seed, start, &operations);
+        _test::<3>(&seed, start, &operations);
+        _test::<4>(&seed, start, &operations);
+        _test::<5>(&seed, start, &operations);
+        _test::<8>(&seed, start, &operations);
+        _test::<16>(&seed, start, &operations);
+        _test::<32>(&seed, start, &operations);
+    }
+}
@@ -0,0 +1,135 @@
+use core::iter::FusedIterator;
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// An iterator that splits an ArrayList into runs of adjacent elements, yielding each run as a list.
+///
+/// Runs are moved out of the list with [`ArrayList::pop_front_n`],
+/// so the chunks that are entirely covered by a run are handed over as they are.
+///
+/// This struct is created by ArrayList::group_adjacent_by().
+pub struct GroupAdjacentBy<T, const N: usize, F>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    same: F,
+}
+
+impl<T, const N: usize, F> GroupAdjacentBy<T, N, F>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn new(list: ArrayList<T, N>, same: F) -> Self {
+        Self { list, same }
+    }
+}
+
+impl<T, const N: usize, F> Iterator for GroupAdjacentBy<T, N, F>
+where
+    F: FnMut(&T, &T) -> bool,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = ArrayList<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = self.list.iter();
+        let mut previous = iter.next()?;
+        let mut len = 1;
+
+        for value in iter {
+            if !(self.same)(previous, value) {
+                break;
+            }
+
+            previous = value;
+            len += 1;
+        }
+
+        Some(self.list.pop_front_n(len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len.min(1), Some(len))
+    }
+}
+
+impl<T, const N: usize, F> FusedIterator for GroupAdjacentBy<T, N, F>
+where
+    F: FnMut(&T, &T) -> bool,
+    Usize<N>: ChunkCapacity,
+{
+}
+
+impl<T, const N: usize, F> core::fmt::Debug for GroupAdjacentBy<T, N, F>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GroupAdjacentBy")
+            .field("list", &self.list)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_group_adjacent_by_moves_whole_chunks() {
+        let list = ArrayList::<_, 2>::from([1, 1, 1, 1, 2, 3, 3]);
+        let chunks: Vec<_> = list
+            .chunks
+            .iter()
+            .map(|chunk| chunk.as_slices().0.as_ptr())
+            .collect();
+
+        let groups: Vec<_> = list.group_adjacent_by(|a, b| a == b).collect();
+        assert_eq!(groups, [&[1, 1, 1, 1][..], &[2], &[3, 3]]);
+
+        let moved: Vec<_> = groups[0]
+            .chunks
+            .iter()
+            .map(|chunk| chunk.as_slices().0.as_ptr())
+            .collect();
+        assert_eq!(moved, chunks[..2]);
+    }
+
+    #[quickcheck]
+    fn test_group_adjacent_by_matches_chunk_by(values: Vec<u8>) {
+        fn _test<const N: usize>(values: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(values.iter().copied());
+            let mut groups = list.group_adjacent_by(|a, b| a / 64 == b / 64);
+
+            for expected in values.chunk_by(|a, b| a / 64 == b / 64) {
+                let group = groups.next().unwrap();
+                assert_eq!(group, expected);
+                assert!(
+                    group
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+
+            assert!(groups.next().is_none());
+        }
+
+        _test::<1>(&values);
+        _test::<2>(&values);
+        _test::<3>(&values);
+        _test::<4>(&values);
+        _test::<5>(&values);
+        _test::<8>(&values);
+        _test::<16>(&values);
+        _test::<32>(&values);
+    }
+}
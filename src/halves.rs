@@ -0,0 +1,123 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+/// An iterator over the two contiguous halves of a ring buffer, as returned by [`VecDeque::as_slices`].
+///
+/// Unlike the iterators of [`VecDeque`], the remaining elements can always be viewed as slices.
+///
+/// [`VecDeque`]: std::collections::VecDeque
+/// [`VecDeque::as_slices`]: std::collections::VecDeque::as_slices
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Halves<I> {
+    head: I,
+    tail: I,
+}
+
+impl<I> Halves<I> {
+    pub(crate) fn new(head: I, tail: I) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<'a, T> Halves<slice::Iter<'a, T>> {
+    pub(crate) fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.head.as_slice(), self.tail.as_slice())
+    }
+}
+
+impl<'a, T> From<(&'a [T], &'a [T])> for Halves<slice::Iter<'a, T>> {
+    fn from((head, tail): (&'a [T], &'a [T])) -> Self {
+        Self::new(head.iter(), tail.iter())
+    }
+}
+
+impl<T> Halves<slice::IterMut<'_, T>> {
+    pub(crate) fn as_slices(&self) -> (&[T], &[T]) {
+        (self.head.as_slice(), self.tail.as_slice())
+    }
+}
+
+impl<'a, T> From<(&'a mut [T], &'a mut [T])> for Halves<slice::IterMut<'a, T>> {
+    fn from((head, tail): (&'a mut [T], &'a mut [T])) -> Self {
+        Self::new(head.iter_mut(), tail.iter_mut())
+    }
+}
+
+impl<I> Iterator for Halves<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head.next().or_else(|| self.tail.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let head_len = self.head.len();
+        if n < head_len {
+            return self.head.nth(n);
+        }
+
+        self.head.nth(head_len);
+        self.tail.nth(n - head_len)
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let accum = self.head.fold(init, &mut f);
+        self.tail.fold(accum, f)
+    }
+
+    fn find_map<B, F>(&mut self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        self.head.find_map(&mut f).or_else(|| self.tail.find_map(f))
+    }
+}
+
+impl<I> DoubleEndedIterator for Halves<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tail.next_back().or_else(|| self.head.next_back())
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let tail_len = self.tail.len();
+        if n < tail_len {
+            return self.tail.nth_back(n);
+        }
+
+        self.tail.nth_back(tail_len);
+        self.head.nth_back(n - tail_len)
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let accum = self.tail.rfold(init, &mut f);
+        self.head.rfold(accum, f)
+    }
+}
+
+impl<I> ExactSizeIterator for Halves<I>
+where
+    I: ExactSizeIterator + DoubleEndedIterator,
+{
+    fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+}
+
+impl<I> FusedIterator for Halves<I> where I: FusedIterator + ExactSizeIterator + DoubleEndedIterator {}
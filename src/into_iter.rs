@@ -1,10 +1,9 @@
 use core::iter::FusedIterator;
-use std::collections::{VecDeque, vec_deque};
-use std::iter::Flatten;
+use std::collections::VecDeque;
 
 use crate::{ArrayList, ChunkCapacity, Usize};
 
-/// An iterator over the elements of a ArrayList.
+/// An iterator over the elements of an ArrayList.
 ///
 /// This struct is created by ArrayList::into_iter().
 #[derive(Clone)]
@@ -12,30 +11,122 @@ pub struct IntoIter<T, const N: usize>
 where
     Usize<N>: ChunkCapacity,
 {
-    delegate: Flatten<vec_deque::IntoIter<VecDeque<T>>>,
+    // the chunks are kept as deques rather than their iterators, so that they can be peeked into
+    chunks: VecDeque<VecDeque<T>>,
+    front: VecDeque<T>,
+    back: VecDeque<T>,
+    len: usize,
 }
 
-const _: [(); core::mem::size_of::<usize>() * 12] =
+const _: [(); core::mem::size_of::<usize>() * 13] =
     [(); core::mem::size_of::<IntoIter<usize, 2>>()];
 
-impl<T, const N: usize> Default for IntoIter<T, N>
+impl<T, const N: usize> IntoIter<T, N>
 where
     Usize<N>: ChunkCapacity,
 {
-    fn default() -> Self {
+    pub(crate) fn from_list(list: ArrayList<T, N>) -> Self {
         Self {
-            delegate: VecDeque::new().into_iter().flatten(),
+            len: list.len(),
+            chunks: list.chunks,
+            front: VecDeque::new(),
+            back: VecDeque::new(),
         }
     }
+
+    /// Returns a reference to the next element, without consuming it.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    /// let mut iter = list.into_iter();
+    ///
+    /// assert_eq!(iter.peek(), Some(&0));
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.peek_back(), Some(&2));
+    /// assert_eq!(iter.next_back(), Some(2));
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.front
+            .front()
+            .or_else(|| self.chunks.front().and_then(VecDeque::front))
+            .or_else(|| self.back.front())
+    }
+
+    /// Returns a reference to the next element from the back, without consuming it.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.back
+            .back()
+            .or_else(|| self.chunks.back().and_then(VecDeque::back))
+            .or_else(|| self.front.back())
+    }
+
+    /// Yields the first element for which `f` returns `Some`, moving through the chunks front to back.
+    fn find_map_front<B>(&mut self, mut f: impl FnMut(T) -> Option<B>) -> Option<B> {
+        loop {
+            while let Some(value) = self.front.pop_front() {
+                self.len -= 1;
+                if let Some(found) = f(value) {
+                    return Some(found);
+                }
+            }
+
+            match self.chunks.pop_front() {
+                Some(chunk) => self.front = chunk,
+                None => break,
+            }
+        }
+
+        while let Some(value) = self.back.pop_front() {
+            self.len -= 1;
+            if let Some(found) = f(value) {
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
+    /// Yields the last element for which `f` returns `Some`, moving through the chunks back to front.
+    fn find_map_back<B>(&mut self, mut f: impl FnMut(T) -> Option<B>) -> Option<B> {
+        loop {
+            while let Some(value) = self.back.pop_back() {
+                self.len -= 1;
+                if let Some(found) = f(value) {
+                    return Some(found);
+                }
+            }
+
+            match self.chunks.pop_back() {
+                Some(chunk) => self.back = chunk,
+                None => break,
+            }
+        }
+
+        while let Some(value) = self.front.pop_back() {
+            self.len -= 1;
+            if let Some(found) = f(value) {
+                return Some(found);
+            }
+        }
+
+        None
+    }
 }
 
-impl<T, const N: usize> IntoIter<T, N>
+impl<T, const N: usize> Default for IntoIter<T, N>
 where
     Usize<N>: ChunkCapacity,
 {
-    pub(crate) fn from_list(list: ArrayList<T, N>) -> Self {
+    fn default() -> Self {
         Self {
-            delegate: list.chunks.into_iter().flatten(),
+            chunks: VecDeque::new(),
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+            len: 0,
         }
     }
 }
@@ -47,90 +138,106 @@ where
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.delegate.next()
+        loop {
+            if let Some(value) = self.front.pop_front() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.pop_front() {
+                Some(chunk) => self.front = chunk,
+                None => {
+                    let value = self.back.pop_front()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.delegate.size_hint()
+        (self.len, Some(self.len))
     }
 
-    fn last(self) -> Option<Self::Item> {
-        self.delegate.last()
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 
     fn count(self) -> usize
     where
         Self: Sized,
     {
-        self.delegate.count()
+        self.len
     }
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth(n)
-    }
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
 
-    fn for_each<F>(self, f: F)
-    where
-        Self: Sized,
-        F: FnMut(Self::Item),
-    {
-        self.delegate.for_each(f);
-    }
+        self.len -= n + 1;
 
-    fn collect<B: FromIterator<Self::Item>>(self) -> B
-    where
-        Self: Sized,
-    {
-        self.delegate.collect()
-    }
+        if n < self.front.len() {
+            self.front.drain(..n);
+            return self.front.pop_front();
+        }
 
-    fn partition<B, F>(self, f: F) -> (B, B)
-    where
-        Self: Sized,
-        B: Default + Extend<Self::Item>,
-        F: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.partition(f)
-    }
+        n -= self.front.len();
+        self.front = VecDeque::new();
 
-    fn fold<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> B,
-    {
-        self.delegate.fold(init, f)
+        while let Some(chunk) = self.chunks.pop_front() {
+            if n < chunk.len() {
+                self.front = chunk;
+                self.front.drain(..n);
+                return self.front.pop_front();
+            }
+
+            n -= chunk.len();
+        }
+
+        self.back.drain(..n);
+        self.back.pop_front()
     }
 
-    fn reduce<F>(self, f: F) -> Option<Self::Item>
+    fn fold<B, F>(self, init: B, mut f: F) -> B
     where
         Self: Sized,
-        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+        F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.reduce(f)
+        let accum = self.front.into_iter().fold(init, &mut f);
+        let accum = self
+            .chunks
+            .into_iter()
+            .fold(accum, |accum, chunk| chunk.into_iter().fold(accum, &mut f));
+        self.back.into_iter().fold(accum, f)
     }
 
-    fn all<F>(&mut self, f: F) -> bool
+    fn all<F>(&mut self, mut f: F) -> bool
     where
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.all(f)
+        self.find_map_front(|value| (!f(value)).then_some(()))
+            .is_none()
     }
 
-    fn any<F>(&mut self, f: F) -> bool
+    fn any<F>(&mut self, mut f: F) -> bool
     where
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.any(f)
+        self.find_map_front(|value| f(value).then_some(()))
+            .is_some()
     }
 
-    fn find<P>(&mut self, predicate: P) -> Option<Self::Item>
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
         Self: Sized,
         P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.find(predicate)
+        self.find_map_front(|value| predicate(&value).then_some(value))
     }
 
     fn find_map<B, F>(&mut self, f: F) -> Option<B>
@@ -138,217 +245,130 @@ where
         Self: Sized,
         F: FnMut(Self::Item) -> Option<B>,
     {
-        self.delegate.find_map(f)
+        self.find_map_front(f)
     }
 
-    fn position<P>(&mut self, predicate: P) -> Option<usize>
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
     where
         Self: Sized,
         P: FnMut(Self::Item) -> bool,
     {
-        self.delegate.position(predicate)
-    }
-
-    fn max(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.max()
-    }
-
-    fn min(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.min()
-    }
-
-    fn max_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item) -> B,
-    {
-        self.delegate.max_by_key(f)
-    }
-
-    fn max_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.max_by(compare)
-    }
-
-    fn min_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item) -> B,
-    {
-        self.delegate.min_by_key(f)
-    }
-
-    fn min_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.min_by(compare)
-    }
-
-    fn sum<S>(self) -> S
-    where
-        Self: Sized,
-        S: std::iter::Sum<Self::Item>,
-    {
-        self.delegate.sum()
-    }
-
-    fn product<P>(self) -> P
-    where
-        Self: Sized,
-        P: std::iter::Product<Self::Item>,
-    {
-        self.delegate.product()
-    }
+        let mut index = 0;
+        self.find_map_front(|value| {
+            if predicate(value) {
+                return Some(index);
+            }
 
-    fn cmp<I>(self, other: I) -> std::cmp::Ordering
-    where
-        I: IntoIterator<Item = Self::Item>,
-        Self::Item: Ord,
-        Self: Sized,
-    {
-        self.delegate.cmp(other)
+            index += 1;
+            None
+        })
     }
+}
 
-    fn partial_cmp<I>(self, other: I) -> Option<std::cmp::Ordering>
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.partial_cmp(other)
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.back.pop_back() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.pop_back() {
+                Some(chunk) => self.back = chunk,
+                None => {
+                    let value = self.front.pop_back()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
-    fn eq<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.eq(other)
-    }
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
 
-    fn ne<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.ne(other)
-    }
+        self.len -= n + 1;
 
-    fn lt<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.lt(other)
-    }
+        if n < self.back.len() {
+            self.back.truncate(self.back.len() - n);
+            return self.back.pop_back();
+        }
 
-    fn le<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.le(other)
-    }
+        n -= self.back.len();
+        self.back = VecDeque::new();
 
-    fn gt<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.gt(other)
-    }
+        while let Some(chunk) = self.chunks.pop_back() {
+            if n < chunk.len() {
+                self.back = chunk;
+                self.back.truncate(self.back.len() - n);
+                return self.back.pop_back();
+            }
 
-    fn ge<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.ge(other)
-    }
+            n -= chunk.len();
+        }
 
-    fn is_sorted(self) -> bool
-    where
-        Self: Sized,
-        Self::Item: PartialOrd,
-    {
-        self.delegate.is_sorted()
+        self.front.truncate(self.front.len() - n);
+        self.front.pop_back()
     }
 
-    fn is_sorted_by<F>(self, compare: F) -> bool
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
     where
         Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.is_sorted_by(compare)
+        let accum = self.back.into_iter().rfold(init, &mut f);
+        let accum = self
+            .chunks
+            .into_iter()
+            .rfold(accum, |accum, chunk| chunk.into_iter().rfold(accum, &mut f));
+        self.front.into_iter().rfold(accum, f)
     }
 
-    fn is_sorted_by_key<F, K>(self, f: F) -> bool
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> K,
-        K: PartialOrd,
+        P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.is_sorted_by_key(f)
+        self.find_map_back(|value| predicate(&value).then_some(value))
     }
 }
 
-impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N>
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N>
 where
     Usize<N>: ChunkCapacity,
 {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.delegate.next_back()
-    }
-
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth_back(n)
-    }
-
-    fn rfold<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> B,
-    {
-        self.delegate.rfold(init, f)
-    }
-
-    fn rfind<P>(&mut self, predicate: P) -> Option<Self::Item>
-    where
-        Self: Sized,
-        P: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.rfind(predicate)
+    fn len(&self) -> usize {
+        self.len
     }
 }
 
 impl<T, const N: usize> FusedIterator for IntoIter<T, N> where Usize<N>: ChunkCapacity {}
 
+// SAFETY: `size_hint` always returns the exact number of remaining elements.
+#[cfg(feature = "trusted_len")]
+unsafe impl<T, const N: usize> core::iter::TrustedLen for IntoIter<T, N> where
+    Usize<N>: ChunkCapacity
+{
+}
+
 impl<T, const N: usize> core::fmt::Debug for IntoIter<T, N>
 where
     T: core::fmt::Debug,
     Usize<N>: ChunkCapacity,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:?}", self.delegate)
+        f.debug_struct("IntoIter")
+            .field("front", &self.front)
+            .field("chunks", &self.chunks)
+            .field("back", &self.back)
+            .finish()
     }
 }
 
@@ -440,38 +460,35 @@ mod tests {
             Usize<N>: ChunkCapacity,
         {
             let mut actual = ArrayList::<_, N>::new();
-            actual.extend(expected.into_iter().copied());
+            actual.extend(expected.iter().copied());
 
-            assert!(actual.clone().into_iter().eq(expected.into_iter().copied()));
+            assert!(actual.clone().into_iter().eq(expected.iter().copied()));
             assert!(
                 actual
                     .clone()
                     .into_iter()
                     .rev()
-                    .eq(expected.into_iter().copied().rev())
+                    .eq(expected.iter().copied().rev())
             );
             assert_eq!(
                 actual
                     .clone()
                     .into_iter()
-                    .partial_cmp(expected.into_iter().copied()),
+                    .partial_cmp(expected.iter().copied()),
                 Some(Ordering::Equal)
             );
-            assert_eq!(
-                actual.clone().into_iter().count(),
-                expected.into_iter().count()
-            );
+            assert_eq!(actual.clone().into_iter().count(), expected.len());
             assert_eq!(
                 actual.clone().into_iter().max(),
-                expected.into_iter().copied().max()
+                expected.iter().copied().max()
             );
             assert_eq!(
                 actual.clone().into_iter().min(),
-                expected.into_iter().copied().min()
+                expected.iter().copied().min()
             );
             assert_eq!(
                 actual.clone().into_iter().is_sorted(),
-                expected.into_iter().is_sorted()
+                expected.iter().is_sorted()
             );
             assert_eq!(
                 actual.clone().into_iter().collect::<ArrayList<_, N>>(),
@@ -492,4 +509,92 @@ mod tests {
         _test::<256>(&seed);
         _test::<512>(&seed);
     }
+
+    #[quickcheck]
+    fn test_exact_size(seed: Vec<i32>, operations: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.iter().copied();
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied()).into_iter();
+
+            for &(operation, n) in operations {
+                let n = usize::from(n % 8);
+                let needle = n as i32 - 4;
+
+                match operation % 9 {
+                    0 => assert_eq!(actual.next(), expected.next()),
+                    1 => assert_eq!(actual.next_back(), expected.next_back()),
+                    2 => assert_eq!(actual.nth(n), expected.nth(n)),
+                    3 => assert_eq!(actual.nth_back(n), expected.nth_back(n)),
+                    4 => assert_eq!(
+                        actual.find(|v| *v == needle),
+                        expected.find(|v| *v == needle)
+                    ),
+                    5 => assert_eq!(
+                        actual.rfind(|v| *v == needle),
+                        expected.rfind(|v| *v == needle)
+                    ),
+                    6 => assert_eq!(
+                        actual.position(|v| v == needle),
+                        expected.position(|v| v == needle)
+                    ),
+                    7 => assert_eq!(actual.any(|v| v == needle), expected.any(|v| v == needle)),
+                    _ => assert_eq!(actual.all(|v| v != needle), expected.all(|v| v != needle)),
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(actual.size_hint(), expected.size_hint());
+                assert_eq!(actual.peek().copied(), expected.clone().next());
+                assert_eq!(actual.peek_back().copied(), expected.clone().next_back());
+                assert_eq!(actual.clone().count(), expected.clone().count());
+            }
+
+            assert!(actual.eq(expected));
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_nth(seed: Vec<i32>, steps: Vec<(bool, usize)>) {
+        fn _test<const N: usize>(seed: &[i32], steps: &[(bool, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.iter().copied();
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied()).into_iter();
+
+            for &(back, n) in steps {
+                let n = n % (seed.len() + 2);
+
+                if back {
+                    assert_eq!(actual.nth_back(n), expected.nth_back(n));
+                } else {
+                    assert_eq!(actual.nth(n), expected.nth(n));
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.clone().eq(expected.clone()));
+                assert!(actual.clone().rev().eq(expected.clone().rev()));
+            }
+        }
+
+        _test::<1>(&seed, &steps);
+        _test::<2>(&seed, &steps);
+        _test::<3>(&seed, &steps);
+        _test::<4>(&seed, &steps);
+        _test::<5>(&seed, &steps);
+        _test::<8>(&seed, &steps);
+        _test::<16>(&seed, &steps);
+        _test::<32>(&seed, &steps);
+    }
 }
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use rayon::collections::vec_deque;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::{FlattenIter, IntoParallelIterator, ParallelIterator};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A parallel iterator over the elements of an ArrayList.
+///
+/// Work is split on chunk boundaries, each chunk is then iterated sequentially.
+///
+/// This struct is created by ArrayList::into_par_iter().
+pub struct IntoParIter<T, const N: usize>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    delegate: FlattenIter<vec_deque::IntoIter<VecDeque<T>>>,
+}
+
+impl<T, const N: usize> IntoParIter<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: ArrayList<T, N>) -> Self {
+        Self {
+            delegate: list.chunks.into_par_iter().flatten_iter(),
+        }
+    }
+}
+
+impl<T, const N: usize> ParallelIterator for IntoParIter<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.delegate.drive_unindexed(consumer)
+    }
+}
+
+impl<T, const N: usize> IntoParallelIterator for ArrayList<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = T;
+    type Iter = IntoParIter<T, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter::from_list(self)
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for IntoParIter<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IntoParIter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_into_par_iter_behavioural(seed: Vec<i32>) {
+        fn _test<const N: usize>(expected: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let actual = ArrayList::<_, N>::from_iter(expected.iter().copied());
+
+            assert_eq!(actual.clone().into_par_iter().count(), expected.len());
+            assert_eq!(actual.into_par_iter().collect::<Vec<_>>(), expected);
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+}
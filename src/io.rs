@@ -0,0 +1,607 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+
+use crate::{ArrayList, ChunkCapacity, Cursor, Usize};
+
+/// Reading consumes bytes from the front of the list.
+impl<const N: usize> Read for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut read = 0;
+
+        while read < buf.len() {
+            let available = self.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+
+            let n = available.len().min(buf.len() - read);
+            buf[read..read + n].copy_from_slice(&available[..n]);
+            self.consume(n);
+            read += n;
+        }
+
+        Ok(read)
+    }
+}
+
+/// The buffer is the contiguous head of the front chunk.
+impl<const N: usize> BufRead for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.chunks.front().map_or(&[], |chunk| chunk.as_slices().0))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let Some(chunk) = self.chunks.front_mut() else {
+            return;
+        };
+
+        let amt = amt.min(chunk.len());
+        chunk.drain(..amt);
+        if chunk.is_empty() {
+            self.chunks.pop_front();
+        }
+
+        self.len -= amt;
+    }
+}
+
+/// Writing appends bytes to the back of the list.
+impl<const N: usize> Write for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.append_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reading starts at the current element and moves the cursor forward,
+/// the “ghost” non-element marks the end of the stream.
+impl<const N: usize> Read for Cursor<'_, u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut read = 0;
+
+        while read < buf.len() {
+            let available = self.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+
+            let n = available.len().min(buf.len() - read);
+            buf[read..read + n].copy_from_slice(&available[..n]);
+            self.consume(n);
+            read += n;
+        }
+
+        Ok(read)
+    }
+}
+
+/// The buffer is the contiguous run starting at the current element.
+impl<const N: usize> BufRead for Cursor<'_, u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        let Some(chunk) = self.list.chunks.get(self.chunk_index.get()) else {
+            return Ok(&[]);
+        };
+
+        let (front, back) = chunk.as_slices();
+        if self.inner_index.get() < front.len() {
+            return Ok(&front[self.inner_index.get()..]);
+        }
+
+        Ok(back
+            .get(self.inner_index.get() - front.len()..)
+            .unwrap_or(&[]))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let position = self.index.get().min(self.list.len());
+        self.seek_to(position.saturating_add(amt));
+    }
+}
+
+/// Seeking beyond the end of the list moves the cursor to the “ghost” non-element.
+impl<const N: usize> Seek for Cursor<'_, u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => (0, i128::from(n)),
+            SeekFrom::End(n) => (self.list.len(), i128::from(n)),
+            SeekFrom::Current(n) => (self.index.get().min(self.list.len()), i128::from(n)),
+        };
+
+        let Some(position) = i128::try_from(base)
+            .ok()
+            .and_then(|base| base.checked_add(offset))
+            .filter(|&position| position >= 0)
+        else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+
+        let position = usize::try_from(position).unwrap_or(usize::MAX);
+        self.seek_to(position);
+        Ok(self.index.get() as u64)
+    }
+
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        Ok(self.index.get().min(self.list.len()) as u64)
+    }
+}
+
+impl<const N: usize> ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Returns one [`IoSlice`] per contiguous run of bytes, in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<u8, 4> = ArrayList::from_iter(*b"hello world");
+    /// let slices = list.as_io_slices();
+    ///
+    /// assert_eq!(slices.len(), 3);
+    /// assert_eq!(&*slices[0], b"hell");
+    /// assert_eq!(&*slices[2], b"rld");
+    /// ```
+    pub fn as_io_slices(&self) -> Vec<IoSlice<'_>> {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| {
+                let (front, back) = chunk.as_slices();
+                [front, back]
+            })
+            .filter(|slice| !slice.is_empty())
+            .map(IoSlice::new)
+            .collect()
+    }
+
+    /// Returns one [`IoSliceMut`] per contiguous run of bytes, in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 4> = ArrayList::from_iter(*b"hello world");
+    /// list.as_io_slices_mut()[1].make_ascii_uppercase();
+    ///
+    /// assert_eq!(list, b"hellO WOrld".as_slice());
+    /// ```
+    pub fn as_io_slices_mut(&mut self) -> Vec<IoSliceMut<'_>> {
+        self.chunks
+            .iter_mut()
+            .flat_map(|chunk| {
+                let (front, back) = chunk.as_mut_slices();
+                [front, back]
+            })
+            .filter(|slice| !slice.is_empty())
+            .map(IoSliceMut::new)
+            .collect()
+    }
+
+    /// Writes the bytes of the list to `writer` with a single call to
+    /// [`Write::write_vectored`], then removes the written bytes from the front of the list.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 4> = ArrayList::from_iter(*b"hello world");
+    /// let mut writer = Vec::new();
+    ///
+    /// assert_eq!(list.write_vectored_to(&mut writer).unwrap(), 11);
+    /// assert_eq!(writer, b"hello world");
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn write_vectored_to<W>(&mut self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write + ?Sized,
+    {
+        let written = writer.write_vectored(&self.as_io_slices())?;
+        self.consume_bytes(written);
+        Ok(written)
+    }
+
+    /// Reads at most `limit` bytes from `reader` with a single call to
+    /// [`Read::read_vectored`], appending them to the back of the list.
+    ///
+    /// Returns the number of bytes read, on error the list is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 4> = ArrayList::from_iter(*b"hello");
+    /// let mut reader: &[u8] = b" world";
+    ///
+    /// assert_eq!(list.read_vectored_from(&mut reader, 4).unwrap(), 4);
+    /// assert_eq!(list, b"hello wor".as_slice());
+    /// ```
+    pub fn read_vectored_from<R>(&mut self, reader: &mut R, limit: usize) -> std::io::Result<usize>
+    where
+        R: Read + ?Sized,
+    {
+        let len = self.len();
+        let (chunk_index, inner_index) = match self.chunks.back() {
+            Some(chunk) if chunk.len() < N => (self.chunks.len() - 1, chunk.len()),
+            _ => (self.chunks.len(), 0),
+        };
+
+        // Chunks cannot expose their spare capacity, so zeroed room is made for
+        // the incoming bytes and the excess is trimmed once the read completes.
+        let mut rest = limit;
+        if let Some(chunk) = self.chunks.get_mut(chunk_index) {
+            let n = (N - chunk.len()).min(rest);
+            chunk.resize(chunk.len() + n, 0);
+            rest -= n;
+        }
+
+        while rest > 0 {
+            let n = rest.min(N);
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.resize(n, 0);
+            self.chunks.push_back(chunk);
+            self.counters.allocation();
+            rest -= n;
+        }
+
+        self.len += limit;
+
+        let mut slices: Vec<IoSliceMut<'_>> = Vec::new();
+        let mut skip = inner_index;
+        for chunk in self.chunks.range_mut(chunk_index..) {
+            let (front, back) = chunk.as_mut_slices();
+            for slice in [front, back] {
+                let start = skip.min(slice.len());
+                skip -= start;
+                if start < slice.len() {
+                    slices.push(IoSliceMut::new(&mut slice[start..]));
+                }
+            }
+        }
+
+        let result = reader.read_vectored(&mut slices);
+        let read = result.as_ref().map_or(0, |&read| read.min(limit));
+        self.truncate_bytes(len + read);
+        result.map(|_| read)
+    }
+
+    /// Appends `bytes` to the back of the list, filling up the last chunk first.
+    pub(crate) fn append_bytes(&mut self, bytes: &[u8]) {
+        let mut rest = bytes;
+
+        if let Some(chunk) = self.chunks.back_mut() {
+            let n = (N - chunk.len()).min(rest.len());
+            chunk.extend(&rest[..n]);
+            rest = &rest[n..];
+        }
+
+        for values in rest.chunks(N) {
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.extend(values);
+            self.chunks.push_back(chunk);
+            self.counters.allocation();
+        }
+
+        self.len += bytes.len();
+    }
+
+    /// Removes `cnt` bytes from the front of the list.
+    pub(crate) fn consume_bytes(&mut self, mut cnt: usize) {
+        while cnt > 0 {
+            let chunk = &mut self.chunks[0];
+            let n = cnt.min(chunk.len());
+
+            chunk.drain(..n);
+            if chunk.is_empty() {
+                self.chunks.pop_front();
+            }
+
+            self.len -= n;
+            cnt -= n;
+        }
+    }
+
+    /// Removes bytes from the back of the list until its length is `len`.
+    fn truncate_bytes(&mut self, len: usize) {
+        while self.len > len {
+            let chunk = self.chunks.back_mut().unwrap();
+            let n = (self.len - len).min(chunk.len());
+
+            chunk.truncate(chunk.len() - n);
+            if chunk.is_empty() {
+                self.chunks.pop_back();
+            }
+
+            self.len -= n;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_write_then_read(seed: Vec<Vec<u8>>) {
+        fn _test<const N: usize>(seed: &[Vec<u8>])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::new();
+            for bytes in seed {
+                sut.write_all(bytes).unwrap();
+            }
+
+            let expected = seed.concat();
+            assert_eq!(sut.len(), expected.len());
+            assert_eq!(sut, expected.as_slice());
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+
+            let mut actual = vec![0; expected.len() / 2];
+            sut.read_exact(&mut actual).unwrap();
+            assert_eq!(actual, expected[..expected.len() / 2]);
+            assert_eq!(sut.len(), expected.len() - expected.len() / 2);
+
+            let mut actual = Vec::new();
+            sut.read_to_end(&mut actual).unwrap();
+            assert_eq!(actual, expected[expected.len() / 2..]);
+            assert!(sut.is_empty());
+            assert_eq!(sut.read(&mut [0; 8]).unwrap(), 0);
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[test]
+    fn test_buf_read_lines() {
+        let mut sut = ArrayList::<u8, 4>::new();
+        sut.write_all(b"hello\nworld\n!").unwrap();
+
+        let lines: Vec<String> = (&mut sut).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["hello", "world", "!"]);
+        assert!(sut.is_empty());
+    }
+
+    #[quickcheck]
+    fn test_cursor_read_and_seek(seed: Vec<u8>, offset: usize) {
+        fn _test<const N: usize>(seed: &[u8], offset: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<u8, N>::from_iter(seed.iter().copied());
+            let offset = offset % (seed.len() + 1);
+
+            let mut sut = list.cursor_front();
+            let mut actual = Vec::new();
+            sut.read_to_end(&mut actual).unwrap();
+            assert_eq!(actual, seed);
+            assert_eq!(sut.stream_position().unwrap(), seed.len() as u64);
+            assert_eq!(sut.index(), None);
+
+            assert_eq!(
+                sut.seek(SeekFrom::Start(offset as u64)).unwrap(),
+                offset as u64
+            );
+            assert_eq!(sut.current(), seed.get(offset));
+            let mut actual = Vec::new();
+            sut.read_to_end(&mut actual).unwrap();
+            assert_eq!(actual, seed[offset..]);
+
+            let back = -(offset as i64);
+            assert_eq!(
+                sut.seek(SeekFrom::End(back)).unwrap(),
+                (seed.len() - offset) as u64
+            );
+            assert_eq!(sut.current(), seed.get(seed.len() - offset));
+
+            if 2 * offset <= seed.len() {
+                let position = sut.seek(SeekFrom::Current(back)).unwrap();
+                assert_eq!(position, (seed.len() - 2 * offset) as u64);
+            } else {
+                assert!(sut.seek(SeekFrom::Current(back)).is_err());
+            }
+            assert!(
+                sut.seek(SeekFrom::Current(-(seed.len() as i64) - 1))
+                    .is_err()
+            );
+
+            assert_eq!(
+                sut.seek(SeekFrom::Start(u64::MAX)).unwrap(),
+                seed.len() as u64
+            );
+            assert_eq!(sut.index(), None);
+        }
+
+        _test::<1>(&seed, offset);
+        _test::<2>(&seed, offset);
+        _test::<3>(&seed, offset);
+        _test::<4>(&seed, offset);
+        _test::<5>(&seed, offset);
+        _test::<8>(&seed, offset);
+        _test::<16>(&seed, offset);
+        _test::<32>(&seed, offset);
+    }
+
+    /// Accepts at most `limit` bytes per call, like a congested socket.
+    struct Throttled {
+        limit: usize,
+        bytes: Vec<u8>,
+    }
+
+    impl Write for Throttled {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.write_vectored(&[IoSlice::new(buf)])
+        }
+
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+            let mut written = 0;
+            for buf in bufs {
+                let n = buf.len().min(self.limit - written);
+                self.bytes.extend_from_slice(&buf[..n]);
+                written += n;
+            }
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[quickcheck]
+    fn test_io_slices(seed: Vec<u8>) {
+        fn _test<const N: usize>(seed: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::from_iter(seed.iter().copied());
+
+            let slices = sut.as_io_slices();
+            assert!(slices.iter().all(|slice| (1..=N).contains(&slice.len())));
+            let actual: Vec<u8> = slices
+                .iter()
+                .flat_map(|slice| slice.iter().copied())
+                .collect();
+            assert_eq!(actual, seed);
+
+            let mut slices = sut.as_io_slices_mut();
+            slices.iter_mut().for_each(|slice| slice.reverse());
+            let expected: Vec<u8> = seed
+                .chunks(N)
+                .flat_map(|chunk| chunk.iter().rev().copied())
+                .collect();
+            assert_eq!(sut, expected.as_slice());
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[quickcheck]
+    fn test_write_vectored_to(seed: Vec<u8>, limit: usize) {
+        fn _test<const N: usize>(seed: &[u8], limit: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::from_iter(seed.iter().copied());
+            let limit = limit % (seed.len() + 1);
+            let mut writer = Throttled {
+                limit,
+                bytes: Vec::new(),
+            };
+
+            assert_eq!(sut.write_vectored_to(&mut writer).unwrap(), limit);
+            assert_eq!(writer.bytes, seed[..limit]);
+            assert_eq!(sut, seed[limit..]);
+            assert_eq!(sut.len(), seed.len() - limit);
+        }
+
+        _test::<1>(&seed, limit);
+        _test::<2>(&seed, limit);
+        _test::<3>(&seed, limit);
+        _test::<4>(&seed, limit);
+        _test::<5>(&seed, limit);
+        _test::<8>(&seed, limit);
+        _test::<16>(&seed, limit);
+        _test::<32>(&seed, limit);
+    }
+
+    #[quickcheck]
+    fn test_read_vectored_from(seed: Vec<u8>, input: Vec<u8>, limit: u8) {
+        fn _test<const N: usize>(seed: &[u8], input: &[u8], limit: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<u8, N>::from_iter(seed.iter().copied());
+            let mut reader = input;
+
+            let read = sut.read_vectored_from(&mut reader, limit).unwrap();
+            assert_eq!(read, limit.min(input.len()));
+
+            let expected = [seed, &input[..read]].concat();
+            assert_eq!(sut.len(), expected.len());
+            assert_eq!(sut, expected.as_slice());
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+        }
+
+        _test::<1>(&seed, &input, limit.into());
+        _test::<2>(&seed, &input, limit.into());
+        _test::<3>(&seed, &input, limit.into());
+        _test::<4>(&seed, &input, limit.into());
+        _test::<5>(&seed, &input, limit.into());
+        _test::<8>(&seed, &input, limit.into());
+        _test::<16>(&seed, &input, limit.into());
+        _test::<32>(&seed, &input, limit.into());
+    }
+
+    #[test]
+    fn test_read_vectored_from_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+
+            fn read_vectored(&mut self, _: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        let mut sut = ArrayList::<u8, 4>::from_iter(*b"hello");
+        assert!(sut.read_vectored_from(&mut Failing, 16).is_err());
+        assert_eq!(sut, b"hello".as_slice());
+        assert_eq!(sut.len(), 5);
+    }
+}
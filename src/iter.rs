@@ -1,21 +1,25 @@
 use core::iter::FusedIterator;
-use std::collections::{VecDeque, vec_deque};
-use std::iter::Flatten;
+use core::ops::Range;
+use core::slice;
+use std::collections::VecDeque;
 
+use crate::halves::Halves;
 use crate::{ArrayList, ChunkCapacity, Usize};
 
-/// An iterator over the elements of a ArrayList.
+/// An iterator over the elements of an ArrayList.
 ///
 /// This struct is created by ArrayList::iter().
-#[derive(Default, Clone)]
 pub struct Iter<'a, T, const N: usize>
 where
     Usize<N>: ChunkCapacity,
 {
-    delegate: Flatten<vec_deque::Iter<'a, VecDeque<T>>>,
+    chunks: Halves<slice::Iter<'a, VecDeque<T>>>,
+    front: Halves<slice::Iter<'a, T>>,
+    back: Halves<slice::Iter<'a, T>>,
+    len: usize,
 }
 
-const _: [(); core::mem::size_of::<usize>() * 12] = [(); core::mem::size_of::<Iter<usize, 2>>()];
+const _: [(); core::mem::size_of::<usize>() * 13] = [(); core::mem::size_of::<Iter<usize, 2>>()];
 
 impl<'a, T, const N: usize> Iter<'a, T, N>
 where
@@ -23,279 +27,353 @@ where
 {
     pub(crate) fn from_list(list: &'a ArrayList<T, N>) -> Self {
         Self {
-            delegate: list.chunks.iter().flatten(),
+            chunks: Halves::from(list.chunks.as_slices()),
+            front: Default::default(),
+            back: Default::default(),
+            len: list.len(),
         }
     }
-}
 
-impl<'a, T, const N: usize> Iterator for Iter<'a, T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
-    type Item = &'a T;
+    /// Creates an iterator over the `len` elements from the `start` position, included,
+    /// to the `end` position, excluded, each given as a chunk index and an index within that chunk.
+    pub(crate) fn from_range(
+        list: &'a ArrayList<T, N>,
+        (start_chunk, start_inner): (usize, usize),
+        (end_chunk, end_inner): (usize, usize),
+        len: usize,
+    ) -> Self {
+        if len == 0 {
+            return Self::default();
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.delegate.next()
-    }
+        let chunk = &list.chunks[start_chunk];
+        if start_chunk == end_chunk {
+            return Self {
+                chunks: Default::default(),
+                front: Halves::from(range_of(chunk.as_slices(), start_inner..end_inner)),
+                back: Default::default(),
+                len,
+            };
+        }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.delegate.size_hint()
-    }
+        let back = match list.chunks.get(end_chunk) {
+            Some(chunk) => range_of(chunk.as_slices(), 0..end_inner),
+            None => Default::default(),
+        };
 
-    fn last(self) -> Option<Self::Item> {
-        self.delegate.last()
+        Self {
+            chunks: Halves::from(range_of(
+                list.chunks.as_slices(),
+                start_chunk + 1..end_chunk,
+            )),
+            front: Halves::from(range_of(chunk.as_slices(), start_inner..chunk.len())),
+            back: Halves::from(back),
+            len,
+        }
     }
 
-    fn count(self) -> usize
-    where
-        Self: Sized,
-    {
-        self.delegate.count()
+    /// Returns the remaining elements as contiguous slices, in iteration order.
+    ///
+    /// Like [`slice::Iter::as_slice`], this doesn't advance the iterator,
+    /// so the rest of the elements can be handed to slice-based code at any point.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let mut iter = list.iter();
+    /// iter.next();
+    /// iter.next_back();
+    ///
+    /// let chunks: Vec<&[i32]> = iter.as_chunks().collect();
+    /// assert_eq!(chunks, [&[1][..], &[2, 3][..]]);
+    /// ```
+    pub fn as_chunks(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + use<'a, T, N> {
+        let (front_head, front_tail) = self.front.as_slices();
+        let (chunks_head, chunks_tail) = self.chunks.as_slices();
+        let (back_head, back_tail) = self.back.as_slices();
+
+        let chunks = chunks_head.iter().chain(chunks_tail).flat_map(|chunk| {
+            let (head, tail) = chunk.as_slices();
+            [head, tail]
+        });
+
+        [front_head, front_tail]
+            .into_iter()
+            .chain(chunks)
+            .chain([back_head, back_tail])
+            .filter(|run| !run.is_empty())
+    }
+
+    /// Returns a reference to the next element, without consuming it.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    /// let mut iter = list.iter();
+    ///
+    /// assert_eq!(iter.peek(), Some(&0));
+    /// iter.next();
+    /// assert_eq!(iter.peek_back(), Some(&2));
+    /// iter.next_back();
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&'a T> {
+        self.as_chunks().next().map(|run| &run[0])
+    }
+
+    /// Returns a reference to the next element from the back, without consuming it.
+    pub fn peek_back(&self) -> Option<&'a T> {
+        self.as_chunks().next_back().map(|run| &run[run.len() - 1])
+    }
+
+    /// Copies the remaining elements into `dst`, a contiguous run at a time,
+    /// without advancing the iterator.
+    ///
+    /// # Panics
+    /// - Panics if the length of `dst` differs from the number of remaining elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let mut buffer = [0; 4];
+    /// list.batches(4).next().unwrap().copy_to_slice(&mut buffer);
+    /// assert_eq!(buffer, [0, 1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            dst.len(),
+            self.len,
+            "destination and source slices have different lengths"
+        );
+
+        let mut offset = 0;
+        for run in self.as_chunks() {
+            dst[offset..offset + run.len()].copy_from_slice(run);
+            offset += run.len();
+        }
     }
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth(n)
-    }
+    /// Yields the first element for which `f` returns `Some`, moving through the chunks front to back.
+    fn find_map_front<B>(&mut self, mut f: impl FnMut(&'a T) -> Option<B>) -> Option<B> {
+        let len = &mut self.len;
+        let mut f = |value| {
+            *len -= 1;
+            f(value)
+        };
 
-    fn for_each<F>(self, f: F)
-    where
-        Self: Sized,
-        F: FnMut(Self::Item),
-    {
-        self.delegate.for_each(f);
-    }
+        if let Some(found) = self.front.find_map(&mut f) {
+            return Some(found);
+        }
 
-    fn collect<B: FromIterator<Self::Item>>(self) -> B
-    where
-        Self: Sized,
-    {
-        self.delegate.collect()
-    }
+        for chunk in self.chunks.by_ref() {
+            self.front = Halves::from(chunk.as_slices());
+            if let Some(found) = self.front.find_map(&mut f) {
+                return Some(found);
+            }
+        }
 
-    fn partition<B, F>(self, f: F) -> (B, B)
-    where
-        Self: Sized,
-        B: Default + Extend<Self::Item>,
-        F: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.partition(f)
+        self.back.find_map(f)
     }
 
-    fn fold<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> B,
-    {
-        self.delegate.fold(init, f)
-    }
+    /// Yields the last element for which `f` returns `Some`, moving through the chunks back to front.
+    fn find_map_back<B>(&mut self, mut f: impl FnMut(&'a T) -> Option<B>) -> Option<B> {
+        let len = &mut self.len;
+        let mut f = |value| {
+            *len -= 1;
+            f(value)
+        };
 
-    fn reduce<F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(Self::Item, Self::Item) -> Self::Item,
-    {
-        self.delegate.reduce(f)
-    }
+        if let Some(found) = self.back.by_ref().rev().find_map(&mut f) {
+            return Some(found);
+        }
 
-    fn all<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.all(f)
-    }
+        while let Some(chunk) = self.chunks.next_back() {
+            self.back = Halves::from(chunk.as_slices());
+            if let Some(found) = self.back.by_ref().rev().find_map(&mut f) {
+                return Some(found);
+            }
+        }
 
-    fn any<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.any(f)
+        self.front.by_ref().rev().find_map(f)
     }
+}
 
-    fn find<P>(&mut self, predicate: P) -> Option<Self::Item>
-    where
-        Self: Sized,
-        P: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.find(predicate)
-    }
+/// Narrows a pair of slices, taken as a single one, down to `range`.
+fn range_of<'a, U>((head, tail): (&'a [U], &'a [U]), range: Range<usize>) -> (&'a [U], &'a [U]) {
+    let split = head.len();
+    (
+        &head[range.start.min(split)..range.end.min(split)],
+        &tail[range.start.saturating_sub(split)..range.end.saturating_sub(split)],
+    )
+}
 
-    fn find_map<B, F>(&mut self, f: F) -> Option<B>
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> Option<B>,
-    {
-        self.delegate.find_map(f)
+impl<T, const N: usize> Default for Iter<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self {
+            chunks: Default::default(),
+            front: Default::default(),
+            back: Default::default(),
+            len: 0,
+        }
     }
+}
 
-    fn position<P>(&mut self, predicate: P) -> Option<usize>
-    where
-        Self: Sized,
-        P: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.position(predicate)
+impl<T, const N: usize> Clone for Iter<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+            len: self.len,
+        }
     }
+}
 
-    fn max(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.max()
-    }
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
 
-    fn min(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.min()
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.front.next() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.next() {
+                Some(chunk) => self.front = Halves::from(chunk.as_slices()),
+                None => {
+                    let value = self.back.next()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
-    fn max_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item) -> B,
-    {
-        self.delegate.max_by_key(f)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 
-    fn max_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.max_by(compare)
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 
-    fn min_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
+    fn count(self) -> usize
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> B,
     {
-        self.delegate.min_by_key(f)
+        self.len
     }
 
-    fn min_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.min_by(compare)
-    }
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
 
-    fn sum<S>(self) -> S
-    where
-        Self: Sized,
-        S: std::iter::Sum<Self::Item>,
-    {
-        self.delegate.sum()
-    }
+        self.len -= n + 1;
 
-    fn product<P>(self) -> P
-    where
-        Self: Sized,
-        P: std::iter::Product<Self::Item>,
-    {
-        self.delegate.product()
-    }
+        if n < self.front.len() {
+            return self.front.nth(n);
+        }
 
-    fn cmp<I>(self, other: I) -> std::cmp::Ordering
-    where
-        I: IntoIterator<Item = Self::Item>,
-        Self::Item: Ord,
-        Self: Sized,
-    {
-        self.delegate.cmp(other)
-    }
+        n -= self.front.len();
+        self.front = Default::default();
 
-    fn partial_cmp<I>(self, other: I) -> Option<std::cmp::Ordering>
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.partial_cmp(other)
-    }
+        for chunk in self.chunks.by_ref() {
+            if n < chunk.len() {
+                self.front = Halves::from(chunk.as_slices());
+                return self.front.nth(n);
+            }
 
-    fn eq<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.eq(other)
-    }
+            n -= chunk.len();
+        }
 
-    fn ne<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.ne(other)
+        self.back.nth(n)
     }
 
-    fn lt<I>(self, other: I) -> bool
+    fn fold<B, F>(self, init: B, mut f: F) -> B
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.lt(other)
+        let accum = self.front.fold(init, &mut f);
+        let accum = self
+            .chunks
+            .fold(accum, |accum, chunk| chunk.iter().fold(accum, &mut f));
+        self.back.fold(accum, f)
     }
 
-    fn le<I>(self, other: I) -> bool
+    fn all<F>(&mut self, mut f: F) -> bool
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.le(other)
+        self.find_map_front(|value| (!f(value)).then_some(()))
+            .is_none()
     }
 
-    fn gt<I>(self, other: I) -> bool
+    fn any<F>(&mut self, mut f: F) -> bool
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.gt(other)
+        self.find_map_front(|value| f(value).then_some(()))
+            .is_some()
     }
 
-    fn ge<I>(self, other: I) -> bool
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.ge(other)
+        self.find_map_front(|value| predicate(&value).then_some(value))
     }
 
-    fn is_sorted(self) -> bool
+    fn find_map<B, F>(&mut self, f: F) -> Option<B>
     where
         Self: Sized,
-        Self::Item: PartialOrd,
+        F: FnMut(Self::Item) -> Option<B>,
     {
-        self.delegate.is_sorted()
+        self.find_map_front(f)
     }
 
-    fn is_sorted_by<F>(self, compare: F) -> bool
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
     where
         Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        P: FnMut(Self::Item) -> bool,
     {
-        self.delegate.is_sorted_by(compare)
-    }
+        let mut index = 0;
+        self.find_map_front(|value| {
+            if predicate(value) {
+                return Some(index);
+            }
 
-    fn is_sorted_by_key<F, K>(self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> K,
-        K: PartialOrd,
-    {
-        self.delegate.is_sorted_by_key(f)
+            index += 1;
+            None
+        })
     }
 }
 
@@ -304,39 +382,101 @@ where
     Usize<N>: ChunkCapacity,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.delegate.next_back()
+        loop {
+            if let Some(value) = self.back.next_back() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.next_back() {
+                Some(chunk) => self.back = Halves::from(chunk.as_slices()),
+                None => {
+                    let value = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth_back(n)
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
+
+        self.len -= n + 1;
+
+        if n < self.back.len() {
+            return self.back.nth_back(n);
+        }
+
+        n -= self.back.len();
+        self.back = Default::default();
+
+        while let Some(chunk) = self.chunks.next_back() {
+            if n < chunk.len() {
+                self.back = Halves::from(chunk.as_slices());
+                return self.back.nth_back(n);
+            }
+
+            n -= chunk.len();
+        }
+
+        self.front.nth_back(n)
     }
 
-    fn rfold<B, F>(self, init: B, f: F) -> B
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
     where
         Self: Sized,
         F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.rfold(init, f)
+        let accum = self.back.rfold(init, &mut f);
+        let accum = self
+            .chunks
+            .rfold(accum, |accum, chunk| chunk.iter().rfold(accum, &mut f));
+        self.front.rfold(accum, f)
     }
 
-    fn rfind<P>(&mut self, predicate: P) -> Option<Self::Item>
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
         Self: Sized,
         P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.rfind(predicate)
+        self.find_map_back(|value| predicate(&value).then_some(value))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn len(&self) -> usize {
+        self.len
     }
 }
 
 impl<T, const N: usize> FusedIterator for Iter<'_, T, N> where Usize<N>: ChunkCapacity {}
 
+// SAFETY: `size_hint` always returns the exact number of remaining elements.
+#[cfg(feature = "trusted_len")]
+unsafe impl<T, const N: usize> core::iter::TrustedLen for Iter<'_, T, N> where
+    Usize<N>: ChunkCapacity
+{
+}
+
 impl<T, const N: usize> core::fmt::Debug for Iter<'_, T, N>
 where
     T: core::fmt::Debug,
     Usize<N>: ChunkCapacity,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:?}", self.delegate)
+        f.debug_struct("Iter")
+            .field("front", &self.front)
+            .field("chunks", &self.chunks)
+            .field("back", &self.back)
+            .finish()
     }
 }
 
@@ -350,6 +490,17 @@ mod tests {
 
     use super::Iter;
 
+    #[test]
+    #[cfg(feature = "trusted_len")]
+    fn test_collect_allocates_exactly() {
+        let mut list = ArrayList::<_, 2>::from([0, 1, 2]);
+
+        assert_eq!(list.iter().collect::<Vec<_>>().capacity(), 3);
+        assert_eq!(list.iter_mut().collect::<Vec<_>>().capacity(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>().capacity(), 3);
+        assert_eq!(list.into_iter().collect::<Vec<_>>().capacity(), 3);
+    }
+
     #[test]
     fn test_default_iterator_yields_nothing() {
         let mut sut: Iter<i32, 2> = Default::default();
@@ -436,7 +587,7 @@ mod tests {
                 actual.iter().partial_cmp(expected.iter()),
                 Some(Ordering::Equal)
             );
-            assert_eq!(actual.iter().count(), expected.iter().count());
+            assert_eq!(actual.iter().count(), expected.len());
             assert_eq!(actual.iter().max(), expected.iter().max());
             assert_eq!(actual.iter().min(), expected.iter().min());
             assert_eq!(actual.iter().is_sorted(), expected.iter().is_sorted());
@@ -456,4 +607,132 @@ mod tests {
         _test::<256>(&seed);
         _test::<512>(&seed);
     }
+
+    #[quickcheck]
+    fn test_exact_size(seed: Vec<i32>, operations: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter();
+            let mut actual = list.iter();
+
+            for &(operation, n) in operations {
+                let n = usize::from(n % 8);
+                let needle = &(n as i32 - 4);
+
+                match operation % 9 {
+                    0 => assert_eq!(actual.next(), expected.next()),
+                    1 => assert_eq!(actual.next_back(), expected.next_back()),
+                    2 => assert_eq!(actual.nth(n), expected.nth(n)),
+                    3 => assert_eq!(actual.nth_back(n), expected.nth_back(n)),
+                    4 => assert_eq!(
+                        actual.find(|&v| v == needle),
+                        expected.find(|&v| v == needle)
+                    ),
+                    5 => assert_eq!(
+                        actual.rfind(|&v| v == needle),
+                        expected.rfind(|&v| v == needle)
+                    ),
+                    6 => assert_eq!(
+                        actual.position(|v| v == needle),
+                        expected.position(|v| v == needle)
+                    ),
+                    7 => assert_eq!(actual.any(|v| v == needle), expected.any(|v| v == needle)),
+                    _ => assert_eq!(actual.all(|v| v != needle), expected.all(|v| v != needle)),
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(actual.size_hint(), expected.size_hint());
+                assert_eq!(actual.peek(), expected.as_slice().first());
+                assert_eq!(actual.peek_back(), expected.as_slice().last());
+                assert_eq!(actual.clone().count(), expected.clone().count());
+            }
+
+            assert!(actual.eq(expected));
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_nth(seed: Vec<i32>, steps: Vec<(bool, usize)>) {
+        fn _test<const N: usize>(seed: &[i32], steps: &[(bool, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter();
+            let mut actual = list.iter();
+
+            for &(back, n) in steps {
+                let n = n % (seed.len() + 2);
+
+                if back {
+                    assert_eq!(actual.nth_back(n), expected.nth_back(n));
+                } else {
+                    assert_eq!(actual.nth(n), expected.nth(n));
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.clone().eq(expected.clone()));
+                assert!(actual.clone().rev().eq(expected.clone().rev()));
+            }
+        }
+
+        _test::<1>(&seed, &steps);
+        _test::<2>(&seed, &steps);
+        _test::<3>(&seed, &steps);
+        _test::<4>(&seed, &steps);
+        _test::<5>(&seed, &steps);
+        _test::<8>(&seed, &steps);
+        _test::<16>(&seed, &steps);
+        _test::<32>(&seed, &steps);
+    }
+
+    #[quickcheck]
+    fn test_as_chunks(seed: Vec<i32>, steps: Vec<(bool, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], steps: &[(bool, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter();
+            let mut actual = list.iter();
+
+            for &(back, n) in steps {
+                let n = usize::from(n % 8);
+
+                if back {
+                    assert_eq!(actual.nth_back(n), expected.nth_back(n));
+                } else {
+                    assert_eq!(actual.nth(n), expected.nth(n));
+                }
+
+                assert!(
+                    actual
+                        .as_chunks()
+                        .all(|run| !run.is_empty() && run.len() <= N)
+                );
+                assert!(actual.as_chunks().flatten().eq(expected.as_slice()));
+            }
+        }
+
+        _test::<1>(&seed, &steps);
+        _test::<2>(&seed, &steps);
+        _test::<3>(&seed, &steps);
+        _test::<4>(&seed, &steps);
+        _test::<5>(&seed, &steps);
+        _test::<8>(&seed, &steps);
+        _test::<16>(&seed, &steps);
+        _test::<32>(&seed, &steps);
+    }
 }
@@ -1,21 +1,25 @@
-use std::collections::{VecDeque, vec_deque};
-use std::iter::Flatten;
-use std::iter::FusedIterator;
+use core::iter::FusedIterator;
+use core::slice;
+use std::collections::VecDeque;
 
+use crate::halves::Halves;
+use crate::view_mut::{split_first_pair, split_pair};
 use crate::{ArrayList, ChunkCapacity, Usize};
 
-/// An iterator over the elements of a ArrayList.
+/// An iterator over the elements of an ArrayList.
 ///
 /// This struct is created by ArrayList::iter_mut().
-#[derive(Default)]
 pub struct IterMut<'a, T, const N: usize>
 where
     Usize<N>: ChunkCapacity,
 {
-    delegate: Flatten<vec_deque::IterMut<'a, VecDeque<T>>>,
+    chunks: Halves<slice::IterMut<'a, VecDeque<T>>>,
+    front: Halves<slice::IterMut<'a, T>>,
+    back: Halves<slice::IterMut<'a, T>>,
+    len: usize,
 }
 
-const _: [(); core::mem::size_of::<usize>() * 12] = [(); core::mem::size_of::<IterMut<usize, 2>>()];
+const _: [(); core::mem::size_of::<usize>() * 13] = [(); core::mem::size_of::<IterMut<usize, 2>>()];
 
 impl<'a, T, const N: usize> IterMut<'a, T, N>
 where
@@ -23,279 +27,313 @@ where
 {
     pub(crate) fn from_list(list: &'a mut ArrayList<T, N>) -> Self {
         Self {
-            delegate: list.chunks.iter_mut().flatten(),
+            len: list.len(),
+            chunks: Halves::from(list.chunks.as_mut_slices()),
+            front: Default::default(),
+            back: Default::default(),
         }
     }
-}
-
-impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
-    type Item = &'a mut T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.delegate.next()
-    }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.delegate.size_hint()
-    }
+    /// Creates an iterator over the `len` elements from the `start` position, included,
+    /// to the `end` position, excluded, each given as a chunk index and an index within that chunk.
+    pub(crate) fn from_range(
+        list: &'a mut ArrayList<T, N>,
+        (start_chunk, start_inner): (usize, usize),
+        (end_chunk, end_inner): (usize, usize),
+        len: usize,
+    ) -> Self {
+        if len == 0 {
+            return Self::default();
+        }
 
-    fn last(self) -> Option<Self::Item> {
-        self.delegate.last()
-    }
+        let (_, chunks) = split_pair(list.chunks.as_mut_slices(), start_chunk);
+        let (chunk, chunks) = split_first_pair(chunks).unwrap();
+        if start_chunk == end_chunk {
+            let (front, _) = split_pair(chunk.as_mut_slices(), end_inner);
+            let (_, front) = split_pair(front, start_inner);
+            return Self {
+                chunks: Default::default(),
+                front: Halves::from(front),
+                back: Default::default(),
+                len,
+            };
+        }
 
-    fn count(self) -> usize
-    where
-        Self: Sized,
-    {
-        self.delegate.count()
-    }
+        let (chunks, rest) = split_pair(chunks, end_chunk - start_chunk - 1);
+        let back = match split_first_pair(rest) {
+            Some((chunk, _)) => split_pair(chunk.as_mut_slices(), end_inner).0,
+            None => Default::default(),
+        };
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth(n)
+        let (_, front) = split_pair(chunk.as_mut_slices(), start_inner);
+        Self {
+            chunks: Halves::from(chunks),
+            front: Halves::from(front),
+            back: Halves::from(back),
+            len,
+        }
     }
 
-    fn for_each<F>(self, f: F)
-    where
-        Self: Sized,
-        F: FnMut(Self::Item),
-    {
-        self.delegate.for_each(f);
+    /// Creates an iterator over the `len` elements of the given runs, then chunks, then runs.
+    pub(crate) fn from_parts(
+        front: (&'a mut [T], &'a mut [T]),
+        chunks: (&'a mut [VecDeque<T>], &'a mut [VecDeque<T>]),
+        back: (&'a mut [T], &'a mut [T]),
+        len: usize,
+    ) -> Self {
+        Self {
+            chunks: Halves::from(chunks),
+            front: Halves::from(front),
+            back: Halves::from(back),
+            len,
+        }
     }
 
-    fn collect<B: FromIterator<Self::Item>>(self) -> B
-    where
-        Self: Sized,
-    {
-        self.delegate.collect()
-    }
+    /// Returns the remaining elements as contiguous slices, in iteration order.
+    ///
+    /// Like [`slice::Iter::as_slice`], this doesn't advance the iterator,
+    /// so the rest of the elements can be handed to slice-based code at any point.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let mut iter = list.iter_mut();
+    /// iter.next();
+    /// iter.next_back();
+    ///
+    /// let chunks: Vec<&[i32]> = iter.as_chunks().collect();
+    /// assert_eq!(chunks, [&[1][..], &[2, 3][..]]);
+    /// ```
+    pub fn as_chunks(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        let (front_head, front_tail) = self.front.as_slices();
+        let (chunks_head, chunks_tail) = self.chunks.as_slices();
+        let (back_head, back_tail) = self.back.as_slices();
+
+        let chunks = chunks_head.iter().chain(chunks_tail).flat_map(|chunk| {
+            let (head, tail) = chunk.as_slices();
+            [head, tail]
+        });
+
+        [front_head, front_tail]
+            .into_iter()
+            .chain(chunks)
+            .chain([back_head, back_tail])
+            .filter(|run| !run.is_empty())
+    }
+
+    /// Returns a reference to the next element, without consuming it.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    /// let mut iter = list.iter_mut();
+    ///
+    /// assert_eq!(iter.peek(), Some(&0));
+    /// iter.next();
+    /// assert_eq!(iter.peek_back(), Some(&2));
+    /// iter.next_back();
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.as_chunks().next().map(|run| &run[0])
+    }
+
+    /// Returns a reference to the next element from the back, without consuming it.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.as_chunks().next_back().map(|run| &run[run.len() - 1])
+    }
+
+    /// Yields the first element for which `f` returns `Some`, moving through the chunks front to back.
+    fn find_map_front<B>(&mut self, mut f: impl FnMut(&'a mut T) -> Option<B>) -> Option<B> {
+        let len = &mut self.len;
+        let mut f = |value| {
+            *len -= 1;
+            f(value)
+        };
+
+        if let Some(found) = self.front.find_map(&mut f) {
+            return Some(found);
+        }
 
-    fn partition<B, F>(self, f: F) -> (B, B)
-    where
-        Self: Sized,
-        B: Default + Extend<Self::Item>,
-        F: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.partition(f)
-    }
+        for chunk in self.chunks.by_ref() {
+            self.front = Halves::from(chunk.as_mut_slices());
+            if let Some(found) = self.front.find_map(&mut f) {
+                return Some(found);
+            }
+        }
 
-    fn fold<B, F>(self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> B,
-    {
-        self.delegate.fold(init, f)
+        self.back.find_map(f)
     }
 
-    fn reduce<F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(Self::Item, Self::Item) -> Self::Item,
-    {
-        self.delegate.reduce(f)
-    }
+    /// Yields the last element for which `f` returns `Some`, moving through the chunks back to front.
+    fn find_map_back<B>(&mut self, mut f: impl FnMut(&'a mut T) -> Option<B>) -> Option<B> {
+        let len = &mut self.len;
+        let mut f = |value| {
+            *len -= 1;
+            f(value)
+        };
 
-    fn all<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.all(f)
-    }
-
-    fn any<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.any(f)
-    }
+        if let Some(found) = self.back.by_ref().rev().find_map(&mut f) {
+            return Some(found);
+        }
 
-    fn find<P>(&mut self, predicate: P) -> Option<Self::Item>
-    where
-        Self: Sized,
-        P: FnMut(&Self::Item) -> bool,
-    {
-        self.delegate.find(predicate)
-    }
+        while let Some(chunk) = self.chunks.next_back() {
+            self.back = Halves::from(chunk.as_mut_slices());
+            if let Some(found) = self.back.by_ref().rev().find_map(&mut f) {
+                return Some(found);
+            }
+        }
 
-    fn find_map<B, F>(&mut self, f: F) -> Option<B>
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> Option<B>,
-    {
-        self.delegate.find_map(f)
+        self.front.by_ref().rev().find_map(f)
     }
+}
 
-    fn position<P>(&mut self, predicate: P) -> Option<usize>
-    where
-        Self: Sized,
-        P: FnMut(Self::Item) -> bool,
-    {
-        self.delegate.position(predicate)
+impl<T, const N: usize> Default for IterMut<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self {
+            chunks: Default::default(),
+            front: Default::default(),
+            back: Default::default(),
+            len: 0,
+        }
     }
+}
 
-    fn max(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.max()
-    }
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a mut T;
 
-    fn min(self) -> Option<Self::Item>
-    where
-        Self: Sized,
-        Self::Item: Ord,
-    {
-        self.delegate.min()
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.front.next() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.next() {
+                Some(chunk) => self.front = Halves::from(chunk.as_mut_slices()),
+                None => {
+                    let value = self.back.next()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
-    fn max_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item) -> B,
-    {
-        self.delegate.max_by_key(f)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 
-    fn max_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.max_by(compare)
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 
-    fn min_by_key<B: Ord, F>(self, f: F) -> Option<Self::Item>
+    fn count(self) -> usize
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> B,
     {
-        self.delegate.min_by_key(f)
+        self.len
     }
 
-    fn min_by<F>(self, compare: F) -> Option<Self::Item>
-    where
-        Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-    {
-        self.delegate.min_by(compare)
-    }
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
 
-    fn sum<S>(self) -> S
-    where
-        Self: Sized,
-        S: std::iter::Sum<Self::Item>,
-    {
-        self.delegate.sum()
-    }
+        self.len -= n + 1;
 
-    fn product<P>(self) -> P
-    where
-        Self: Sized,
-        P: std::iter::Product<Self::Item>,
-    {
-        self.delegate.product()
-    }
+        if n < self.front.len() {
+            return self.front.nth(n);
+        }
 
-    fn cmp<I>(self, other: I) -> std::cmp::Ordering
-    where
-        I: IntoIterator<Item = Self::Item>,
-        Self::Item: Ord,
-        Self: Sized,
-    {
-        self.delegate.cmp(other)
-    }
+        n -= self.front.len();
+        self.front = Default::default();
 
-    fn partial_cmp<I>(self, other: I) -> Option<std::cmp::Ordering>
-    where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.partial_cmp(other)
-    }
+        for chunk in self.chunks.by_ref() {
+            if n < chunk.len() {
+                self.front = Halves::from(chunk.as_mut_slices());
+                return self.front.nth(n);
+            }
 
-    fn eq<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.eq(other)
-    }
+            n -= chunk.len();
+        }
 
-    fn ne<I>(self, other: I) -> bool
-    where
-        I: IntoIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self: Sized,
-    {
-        self.delegate.ne(other)
+        self.back.nth(n)
     }
 
-    fn lt<I>(self, other: I) -> bool
+    fn fold<B, F>(self, init: B, mut f: F) -> B
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.lt(other)
+        let accum = self.front.fold(init, &mut f);
+        let accum = self
+            .chunks
+            .fold(accum, |accum, chunk| chunk.iter_mut().fold(accum, &mut f));
+        self.back.fold(accum, f)
     }
 
-    fn le<I>(self, other: I) -> bool
+    fn all<F>(&mut self, mut f: F) -> bool
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.le(other)
+        self.find_map_front(|value| (!f(value)).then_some(()))
+            .is_none()
     }
 
-    fn gt<I>(self, other: I) -> bool
+    fn any<F>(&mut self, mut f: F) -> bool
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        F: FnMut(Self::Item) -> bool,
     {
-        self.delegate.gt(other)
+        self.find_map_front(|value| f(value).then_some(()))
+            .is_some()
     }
 
-    fn ge<I>(self, other: I) -> bool
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
-        I: IntoIterator,
-        Self::Item: PartialOrd<I::Item>,
         Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.ge(other)
+        self.find_map_front(|value| predicate(&value).then_some(value))
     }
 
-    fn is_sorted(self) -> bool
+    fn find_map<B, F>(&mut self, f: F) -> Option<B>
     where
         Self: Sized,
-        Self::Item: PartialOrd,
+        F: FnMut(Self::Item) -> Option<B>,
     {
-        self.delegate.is_sorted()
+        self.find_map_front(f)
     }
 
-    fn is_sorted_by<F>(self, compare: F) -> bool
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
     where
         Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        P: FnMut(Self::Item) -> bool,
     {
-        self.delegate.is_sorted_by(compare)
-    }
+        let mut index = 0;
+        self.find_map_front(|value| {
+            if predicate(value) {
+                return Some(index);
+            }
 
-    fn is_sorted_by_key<F, K>(self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> K,
-        K: PartialOrd,
-    {
-        self.delegate.is_sorted_by_key(f)
+            index += 1;
+            None
+        })
     }
 }
 
@@ -304,39 +342,101 @@ where
     Usize<N>: ChunkCapacity,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.delegate.next_back()
+        loop {
+            if let Some(value) = self.back.next_back() {
+                self.len -= 1;
+                return Some(value);
+            }
+
+            match self.chunks.next_back() {
+                Some(chunk) => self.back = Halves::from(chunk.as_mut_slices()),
+                None => {
+                    let value = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(value);
+                }
+            }
+        }
     }
 
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.delegate.nth_back(n)
+    /// Skips whole chunks at a time, only stepping through the elements of the chunk holding the target.
+    fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            *self = Self::default();
+            return None;
+        }
+
+        self.len -= n + 1;
+
+        if n < self.back.len() {
+            return self.back.nth_back(n);
+        }
+
+        n -= self.back.len();
+        self.back = Default::default();
+
+        while let Some(chunk) = self.chunks.next_back() {
+            if n < chunk.len() {
+                self.back = Halves::from(chunk.as_mut_slices());
+                return self.back.nth_back(n);
+            }
+
+            n -= chunk.len();
+        }
+
+        self.front.nth_back(n)
     }
 
-    fn rfold<B, F>(self, init: B, f: F) -> B
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
     where
         Self: Sized,
         F: FnMut(B, Self::Item) -> B,
     {
-        self.delegate.rfold(init, f)
+        let accum = self.back.rfold(init, &mut f);
+        let accum = self
+            .chunks
+            .rfold(accum, |accum, chunk| chunk.iter_mut().rfold(accum, &mut f));
+        self.front.rfold(accum, f)
     }
 
-    fn rfind<P>(&mut self, predicate: P) -> Option<Self::Item>
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
     where
         Self: Sized,
         P: FnMut(&Self::Item) -> bool,
     {
-        self.delegate.rfind(predicate)
+        self.find_map_back(|value| predicate(&value).then_some(value))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IterMut<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn len(&self) -> usize {
+        self.len
     }
 }
 
 impl<T, const N: usize> FusedIterator for IterMut<'_, T, N> where Usize<N>: ChunkCapacity {}
 
+// SAFETY: `size_hint` always returns the exact number of remaining elements.
+#[cfg(feature = "trusted_len")]
+unsafe impl<T, const N: usize> core::iter::TrustedLen for IterMut<'_, T, N> where
+    Usize<N>: ChunkCapacity
+{
+}
+
 impl<T, const N: usize> core::fmt::Debug for IterMut<'_, T, N>
 where
     T: core::fmt::Debug,
     Usize<N>: ChunkCapacity,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:?}", self.delegate)
+        f.debug_struct("IterMut")
+            .field("front", &self.front)
+            .field("chunks", &self.chunks)
+            .field("back", &self.back)
+            .finish()
     }
 }
 
@@ -437,7 +537,7 @@ mod tests {
                 actual.iter_mut().partial_cmp(expected.iter_mut()),
                 Some(Ordering::Equal)
             );
-            assert_eq!(actual.iter_mut().count(), expected.iter_mut().count());
+            assert_eq!(actual.iter_mut().count(), expected.len());
             assert_eq!(actual.iter_mut().max(), expected.iter_mut().max());
             assert_eq!(actual.iter_mut().min(), expected.iter_mut().min());
             assert_eq!(
@@ -463,4 +563,132 @@ mod tests {
         _test::<256>(&mut seed);
         _test::<512>(&mut seed);
     }
+
+    #[quickcheck]
+    fn test_exact_size(mut seed: Vec<i32>, operations: Vec<(u8, u8)>) {
+        fn _test<const N: usize>(seed: &mut [i32], operations: &[(u8, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter_mut();
+            let mut actual = list.iter_mut();
+
+            for &(operation, n) in operations {
+                let n = usize::from(n % 8);
+                let needle = n as i32 - 4;
+
+                match operation % 9 {
+                    0 => assert_eq!(actual.next(), expected.next()),
+                    1 => assert_eq!(actual.next_back(), expected.next_back()),
+                    2 => assert_eq!(actual.nth(n), expected.nth(n)),
+                    3 => assert_eq!(actual.nth_back(n), expected.nth_back(n)),
+                    4 => assert_eq!(
+                        actual.find(|v| **v == needle),
+                        expected.find(|v| **v == needle)
+                    ),
+                    5 => assert_eq!(
+                        actual.rfind(|v| **v == needle),
+                        expected.rfind(|v| **v == needle)
+                    ),
+                    6 => assert_eq!(
+                        actual.position(|v| *v == needle),
+                        expected.position(|v| *v == needle)
+                    ),
+                    7 => assert_eq!(actual.any(|v| *v == needle), expected.any(|v| *v == needle)),
+                    _ => assert_eq!(actual.all(|v| *v != needle), expected.all(|v| *v != needle)),
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(actual.size_hint(), expected.size_hint());
+                assert_eq!(actual.peek(), expected.as_slice().first());
+                assert_eq!(actual.peek_back(), expected.as_slice().last());
+            }
+
+            assert!(actual.eq(expected));
+        }
+
+        _test::<1>(&mut seed, &operations);
+        _test::<2>(&mut seed, &operations);
+        _test::<3>(&mut seed, &operations);
+        _test::<4>(&mut seed, &operations);
+        _test::<5>(&mut seed, &operations);
+        _test::<8>(&mut seed, &operations);
+        _test::<16>(&mut seed, &operations);
+        _test::<32>(&mut seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_nth(mut seed: Vec<i32>, steps: Vec<(bool, usize)>) {
+        fn _test<const N: usize>(seed: &mut [i32], steps: &[(bool, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let len = seed.len();
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter_mut();
+            let mut actual = list.iter_mut();
+
+            for &(back, n) in steps {
+                let n = n % (len + 2);
+
+                if back {
+                    assert_eq!(actual.nth_back(n), expected.nth_back(n));
+                } else {
+                    assert_eq!(actual.nth(n), expected.nth(n));
+                }
+
+                assert_eq!(actual.len(), expected.len());
+            }
+
+            assert!(actual.eq(expected));
+        }
+
+        _test::<1>(&mut seed, &steps);
+        _test::<2>(&mut seed, &steps);
+        _test::<3>(&mut seed, &steps);
+        _test::<4>(&mut seed, &steps);
+        _test::<5>(&mut seed, &steps);
+        _test::<8>(&mut seed, &steps);
+        _test::<16>(&mut seed, &steps);
+        _test::<32>(&mut seed, &steps);
+    }
+
+    #[quickcheck]
+    fn test_as_chunks(mut seed: Vec<i32>, steps: Vec<(bool, u8)>) {
+        fn _test<const N: usize>(seed: &mut [i32], steps: &[(bool, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.iter_mut();
+            let mut actual = list.iter_mut();
+
+            for &(back, n) in steps {
+                let n = usize::from(n % 8);
+
+                if back {
+                    assert_eq!(actual.nth_back(n), expected.nth_back(n));
+                } else {
+                    assert_eq!(actual.nth(n), expected.nth(n));
+                }
+
+                assert!(
+                    actual
+                        .as_chunks()
+                        .all(|run| !run.is_empty() && run.len() <= N)
+                );
+                assert!(actual.as_chunks().flatten().eq(expected.as_slice()));
+            }
+        }
+
+        _test::<1>(&mut seed, &steps);
+        _test::<2>(&mut seed, &steps);
+        _test::<3>(&mut seed, &steps);
+        _test::<4>(&mut seed, &steps);
+        _test::<5>(&mut seed, &steps);
+        _test::<8>(&mut seed, &steps);
+        _test::<16>(&mut seed, &steps);
+        _test::<32>(&mut seed, &steps);
+    }
 }
@@ -41,24 +41,120 @@
 //! ```
 
 #![cfg_attr(feature = "nightly_tests", feature(linked_list_cursors))]
-
+#![cfg_attr(feature = "extend_one", feature(extend_one))]
+#![cfg_attr(feature = "trusted_len", feature(trusted_len))]
+
+mod aggregated;
+mod aligned;
+mod appender;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod batches;
+#[cfg(feature = "bincode")]
+mod bincode;
+mod bounded;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod chunk_sums;
+mod compact_index;
 mod cursor;
 mod cursor_mut;
+mod debug_chunks;
+mod diff;
+mod display;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod float;
+mod frozen;
+mod gap_editor;
+mod group_adjacent;
+mod halves;
 mod into_iter;
+#[cfg(feature = "rayon")]
+mod into_par_iter;
+mod io;
 mod iter;
 mod iter_mut;
+mod measured;
+mod observer;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "rayon")]
+mod par_iter_mut;
+mod policy;
+mod pooled;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+mod rle;
 mod sailed;
-
-pub use cursor::Cursor;
+mod sequence;
+mod spare_capacity;
+mod stats;
+#[cfg(feature = "futures")]
+mod stream;
+mod sub_list_view;
+mod text;
+#[cfg(feature = "value_index")]
+mod value_index;
+mod view_cursor_mut;
+mod view_mut;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+pub use aggregated::{AggregatedArrayList, Max, Min, Monoid, Sum};
+pub use aligned::{Align16, Align32, Align64, Aligned, Alignment};
+pub use appender::Appender;
+pub use batches::Batches;
+pub use bounded::{BoundedArrayList, OverflowPolicy};
+pub use cursor::{Cursor, CursorPosition};
 pub use cursor_mut::CursorMut;
+pub use debug_chunks::DebugChunks;
+pub use diff::{Edit, EditScript};
+pub use display::Display;
+pub use float::Float;
+pub use frozen::{FrozenArrayList, ThawedArrayList};
+#[allow(deprecated)]
+pub use gap_editor::{CursorGap, GapEditor};
+pub use group_adjacent::GroupAdjacentBy;
+#[cfg(feature = "rayon")]
+pub use into_par_iter::IntoParIter;
 pub use iter::Iter;
 pub use iter_mut::IterMut;
+pub use measured::{Measure, MeasuredArrayList};
+pub use observer::{ListObserver, ObservedArrayList};
+#[cfg(feature = "rayon")]
+pub use par_iter::ParIter;
+#[cfg(feature = "rayon")]
+pub use par_iter_mut::ParIterMut;
+pub use policy::{ChunkPolicy, DefaultPolicy, PolicyArrayList};
+pub use pooled::PooledArrayList;
+pub use rle::RleArrayList;
+pub use sequence::Sequence;
+pub use spare_capacity::BackSpareCapacity;
+#[cfg(feature = "stats")]
+pub use stats::Stats;
+#[cfg(feature = "futures")]
+pub use stream::{IntoChunkStream, IntoStream};
+pub use sub_list_view::SubListView;
+pub use text::TextBuffer;
+#[cfg(feature = "value_index")]
+pub use value_index::IndexedArrayList;
+pub use view_cursor_mut::ViewCursorMut;
+pub use view_mut::ViewMut;
+#[cfg(feature = "zeroize")]
+pub use zeroize::SecretArrayList;
 
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{LinkedList, VecDeque};
 use std::hash::{Hash, Hasher};
+use std::ops::{Bound, Range, RangeBounds};
 
 use crate::into_iter::IntoIter;
+use crate::stats::Counters;
 
 pub enum Usize<const N: usize> {}
 
@@ -98,6 +194,7 @@ where
 {
     chunks: VecDeque<VecDeque<T>>,
     len: usize,
+    counters: Counters,
 }
 
 impl<T, const N: usize, const M: usize> From<[T; M]> for ArrayList<T, N>
@@ -109,14 +206,109 @@ where
     }
 }
 
+impl<T, const N: usize> From<&[T]> for ArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    /// Clones the slice a window of `N` elements at a time, each window into a chunk of its own.
+    fn from(values: &[T]) -> Self {
+        let mut list = Self::new();
+        list.chunks.reserve_exact(values.len().div_ceil(N));
+
+        for window in values.chunks(N) {
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.extend(window.iter().cloned());
+            list.chunks.push_back(chunk);
+            list.counters.allocation();
+        }
+
+        list.len = values.len();
+        list
+    }
+}
+
+impl<T, const N: usize> From<&mut [T]> for ArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    fn from(values: &mut [T]) -> Self {
+        Self::from(&*values)
+    }
+}
+
+impl<T, const N: usize> From<ArrayList<T, N>> for VecDeque<T>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Moves the elements a chunk at a time into a deque sized for all of them up front,
+    /// or hands the chunk over as it is if there is a single one.
+    fn from(list: ArrayList<T, N>) -> Self {
+        let mut chunks = list.chunks;
+        if chunks.len() == 1 {
+            return chunks.pop_front().unwrap();
+        }
+
+        let mut deque = VecDeque::with_capacity(list.len);
+        for mut chunk in chunks {
+            deque.append(&mut chunk);
+        }
+
+        deque
+    }
+}
+
+impl<T, const N: usize> From<ArrayList<T, N>> for LinkedList<T>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn from(list: ArrayList<T, N>) -> Self {
+        list.chunks.into_iter().flatten().collect()
+    }
+}
+
+impl<T, const N: usize, const M: usize> TryFrom<ArrayList<T, N>> for [T; M]
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Error = ArrayList<T, N>;
+
+    /// Moves the elements into an array, handing the list back if its length isn't `M`.
+    fn try_from(list: ArrayList<T, N>) -> Result<Self, Self::Error> {
+        if list.len != M {
+            return Err(list);
+        }
+
+        let mut values = list.into_iter();
+        Ok(core::array::from_fn(|_| values.next().unwrap()))
+    }
+}
+
 impl<T, const N: usize> FromIterator<T> for ArrayList<T, N>
 where
     Usize<N>: ChunkCapacity,
 {
+    /// Sizes the chunks table from the iterator's lower bound up front,
+    /// then fills one chunk at a time, allocating it only once it has a first element.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut this = Self::new();
-        this.extend(iter);
-        this
+        let mut iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+
+        let mut list = Self::new();
+        let _ = list.chunks.try_reserve_exact(lower.div_ceil(N));
+
+        while let Some(value) = iter.next() {
+            let mut chunk = VecDeque::with_capacity(N);
+            chunk.push_back(value);
+            chunk.extend(iter.by_ref().take(N - 1));
+
+            list.len += chunk.len();
+            list.chunks.push_back(chunk);
+            list.counters.allocation();
+        }
+
+        list
     }
 }
 
@@ -124,21 +316,87 @@ impl<T, const N: usize> Extend<T> for ArrayList<T, N>
 where
     Usize<N>: ChunkCapacity,
 {
+    /// Fills the last chunk first, then appends full chunks, allocating each one only
+    /// once the iterator has produced an element for it.
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        let iter = &mut iter.into_iter().peekable();
+        let mut iter = iter.into_iter();
 
-        if let Some(chunk) = self.chunks.back_mut() {
-            chunk.extend(iter.take(N - chunk.len()));
-        }
+        // Unlike `reserve`, a lower bound that cannot be honored is not an error here:
+        // the chunks table grows on demand anyway.
+        let additional = self.chunks_needed(iter.size_hint().0);
+        let _ = self.chunks.try_reserve(additional);
 
-        while iter.peek().is_some() {
-            let mut chunk = VecDeque::with_capacity(N);
-            chunk.extend(iter.take(N));
+        loop {
+            if self.chunks.back().is_none_or(|chunk| chunk.len() >= N) {
+                let Some(value) = iter.next() else {
+                    return;
+                };
+
+                let mut chunk = VecDeque::with_capacity(N);
+                chunk.push_back(value);
+
+                self.len += 1;
+                self.chunks.push_back(chunk);
+                self.counters.allocation();
+            }
+
+            let mut guard = ExtendGuard::new(self);
+            let chunk = guard.back_chunk();
+            let room = N - chunk.len();
+            chunk.extend(iter.by_ref().take(room));
 
-            self.len += chunk.len();
-            self.chunks.push_back(chunk);
+            if chunk.len() < N {
+                return;
+            }
         }
     }
+
+    #[cfg(feature = "extend_one")]
+    fn extend_one(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    #[cfg(feature = "extend_one")]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+/// Accounts for the elements pushed into the last chunk of the list while it is being extended,
+/// so that `len` stays correct even if the source iterator panics halfway through.
+struct ExtendGuard<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a mut ArrayList<T, N>,
+    accounted: usize,
+}
+
+impl<'a, T, const N: usize> ExtendGuard<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn new(list: &'a mut ArrayList<T, N>) -> Self {
+        let accounted = list.chunks.back().map_or(0, VecDeque::len);
+        Self { list, accounted }
+    }
+
+    fn back_chunk(&mut self) -> &mut VecDeque<T> {
+        self.list
+            .chunks
+            .back_mut()
+            .expect("list must have a last chunk")
+    }
+}
+
+impl<T, const N: usize> Drop for ExtendGuard<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn drop(&mut self) {
+        let filled = self.list.chunks.back().map_or(0, VecDeque::len);
+        self.list.len += filled - self.accounted;
+    }
 }
 
 impl<'a, T, const N: usize> Extend<&'a T> for ArrayList<T, N>
@@ -149,6 +407,74 @@ where
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
+
+    #[cfg(feature = "extend_one")]
+    fn extend_one(&mut self, value: &'a T) {
+        self.push_back(value.clone());
+    }
+
+    #[cfg(feature = "extend_one")]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, const N: usize> rayon::iter::FromParallelIterator<T> for ArrayList<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    /// Each worker collects its elements into its own list,
+    /// the resulting chunks are then stitched together in order.
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        use rayon::iter::ParallelIterator;
+
+        par_iter
+            .into_par_iter()
+            .fold(Self::new, |mut list, value| {
+                list.push_back(value);
+                list
+            })
+            .reduce(Self::new, |mut list, mut other| {
+                list.append(&mut other);
+                list
+            })
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, const N: usize> rayon::iter::ParallelExtend<T> for ArrayList<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        let mut other: Self = rayon::iter::FromParallelIterator::from_par_iter(par_iter);
+        self.append(&mut other);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T, const N: usize> rayon::iter::ParallelExtend<&'a T> for ArrayList<T, N>
+where
+    T: Copy + Send + Sync,
+    Usize<N>: ChunkCapacity,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: rayon::iter::IntoParallelIterator<Item = &'a T>,
+    {
+        use rayon::iter::ParallelIterator;
+
+        self.par_extend(par_iter.into_par_iter().copied());
+    }
 }
 
 impl<T, const N: usize> Default for ArrayList<T, N>
@@ -174,13 +500,95 @@ where
     ///
     /// assert!(list.is_empty());
     /// ```
+    ///
+    /// Since it doesn't allocate, it can initialize a `static`:
+    /// ```rust
+    /// use std::sync::Mutex;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// static LOG: Mutex<ArrayList<&str, 8>> = Mutex::new(ArrayList::new());
+    ///
+    /// LOG.lock().unwrap().push_back("started");
+    /// assert_eq!(LOG.lock().unwrap().len(), 1);
+    /// ```
     pub const fn new() -> Self {
         Self {
             chunks: VecDeque::new(),
             len: 0,
+            counters: Counters::new(),
         }
     }
 
+    /// Creates a new, empty list that never holds more than `max_len` elements,
+    /// applying `policy` to the values pushed at its back once it's full.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::{ArrayList, OverflowPolicy};
+    ///
+    /// let mut history = ArrayList::<u32, 4>::bounded(2, OverflowPolicy::EvictFront);
+    /// history.extend([10, 20, 30]);
+    ///
+    /// assert_eq!(*history, [20, 30]);
+    /// assert!(history.is_full());
+    /// ```
+    pub const fn bounded(max_len: usize, policy: OverflowPolicy) -> BoundedArrayList<T, N> {
+        BoundedArrayList::new(max_len, policy)
+    }
+
+    /// Creates a new, empty list that never holds more than `max_len` elements,
+    /// rejecting the values pushed or inserted once it's full.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<u32, 4>::with_max_len(2);
+    /// list.try_push_back(10).unwrap();
+    /// list.try_insert(0, 0).unwrap();
+    ///
+    /// assert_eq!(list.try_push_back(20), Err(20));
+    /// assert_eq!(*list, [0, 10]);
+    /// ```
+    pub const fn with_max_len(max_len: usize) -> BoundedArrayList<T, N> {
+        BoundedArrayList::new(max_len, OverflowPolicy::Reject)
+    }
+
+    /// Creates a new, empty list whose chunks are split and merged following the policy `P`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::{ArrayList, DefaultPolicy};
+    ///
+    /// let mut list = ArrayList::<u32, 4>::with_policy::<DefaultPolicy>();
+    /// list.push_back(10);
+    /// list.insert(0, 0);
+    ///
+    /// assert_eq!(*list, [0, 10]);
+    /// ```
+    pub const fn with_policy<P: ChunkPolicy>() -> PolicyArrayList<T, N, P> {
+        PolicyArrayList::from_list(Self::new())
+    }
+
+    /// Creates a new, empty list whose chunks are taken from a pool of `chunks` chunks
+    /// allocated right away, so that editing it never allocates afterwards.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<u32, 4>::with_pool(2);
+    /// assert_eq!(list.try_push_back(10), Ok(()));
+    /// assert_eq!(list.try_insert(0, 0), Ok(()));
+    ///
+    /// assert_eq!(*list, [0, 10]);
+    /// assert_eq!(list.spare_chunks(), 1);
+    /// ```
+    pub fn with_pool(chunks: usize) -> PooledArrayList<T, N> {
+        PooledArrayList::new(chunks)
+    }
+
     /// Adds an element to the front of the `ArrayList`.
     ///
     /// The element is inserted at the beginning of the list, shifting existing elements
@@ -201,12 +609,17 @@ where
     /// assert_eq!(list.pop_front(), Some(10));
     /// ```
     pub fn push_front(&mut self, value: T) {
+        self.push_front_with::<DefaultPolicy>(value);
+    }
+
+    fn push_front_with<P: ChunkPolicy>(&mut self, value: T) {
         match self.chunks.front_mut() {
-            Some(chunk) if chunk.len() < N => chunk.push_front(value),
+            Some(chunk) if chunk.len() < P::push_limit(N).clamp(1, N) => chunk.push_front(value),
             _ => {
                 let mut chunk = VecDeque::with_capacity(N);
                 chunk.push_front(value);
-                self.chunks.push_front(chunk)
+                self.chunks.push_front(chunk);
+                self.counters.allocation();
             }
         }
 
@@ -233,18 +646,107 @@ where
     /// assert_eq!(list.pop_back(), Some(10));
     /// ```
     pub fn push_back(&mut self, value: T) {
+        self.push_back_with::<DefaultPolicy>(value);
+    }
+
+    fn push_back_with<P: ChunkPolicy>(&mut self, value: T) {
         match self.chunks.back_mut() {
-            Some(chunk) if chunk.len() < N => chunk.push_back(value),
+            Some(chunk) if chunk.len() < P::push_limit(N).clamp(1, N) => chunk.push_back(value),
             _ => {
                 let mut chunk = VecDeque::with_capacity(N);
-                chunk.push_front(value);
-                self.chunks.push_back(chunk)
+                chunk.push_back(value);
+                self.chunks.push_back(chunk);
+                self.counters.allocation();
             }
         }
 
         self.len += 1;
     }
 
+    /// Appends an owned buffer at the back of the list as a new chunk, without moving its elements.
+    ///
+    /// Buffers longer than `N` are split in chunks of `N` elements: the first one stays in place,
+    /// while the others are moved to new chunks. A buffer whose capacity is not exactly `N`
+    /// is resized to fit a chunk.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 3> = ArrayList::from([10]);
+    /// list.push_chunk(vec![20, 30, 40]);
+    /// list.push_chunk([50, 60, 70, 80]);
+    ///
+    /// assert_eq!(list, [10, 20, 30, 40, 50, 60, 70, 80]);
+    /// ```
+    pub fn push_chunk(&mut self, chunk: impl Into<Vec<T>>) {
+        let mut chunk = chunk.into();
+        if chunk.is_empty() {
+            return;
+        }
+
+        let mut tails = Vec::new();
+        while chunk.len() > N {
+            let at = (chunk.len() - 1) / N * N;
+            let mut tail = Vec::with_capacity(N);
+            tail.extend(chunk.drain(at..));
+            tails.push(tail);
+        }
+
+        // chunks must be able to grow up to `N` elements without exceeding that capacity
+        if chunk.capacity() > N {
+            chunk.shrink_to(N);
+        } else {
+            chunk.reserve_exact(N - chunk.len());
+        }
+
+        self.len += chunk.len();
+        self.chunks.push_back(VecDeque::from(chunk));
+
+        for tail in tails.into_iter().rev() {
+            self.len += tail.len();
+            self.chunks.push_back(VecDeque::from(tail));
+            self.counters.allocation();
+        }
+    }
+
+    /// Provides the uninitialized room at the back of the list, to fill it in place
+    /// without going through an intermediate buffer.
+    ///
+    /// It's the spare capacity of the back chunk, or of a new chunk if that one is full,
+    /// so it holds between 1 and `N` values. The values written at its front are appended
+    /// with [`BackSpareCapacity::assume_init_push`].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Read;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let mut socket = "hello world".as_bytes();
+    /// let mut list: ArrayList<u8, 4> = ArrayList::new();
+    ///
+    /// loop {
+    ///     let mut spare = list.back_spare_capacity_mut();
+    ///     spare.fill(MaybeUninit::new(0));
+    ///     // SAFETY: every value has just been initialized
+    ///     let buf = unsafe { &mut *(&mut *spare as *mut [MaybeUninit<u8>] as *mut [u8]) };
+    ///
+    ///     let n = socket.read(buf).unwrap();
+    ///     // SAFETY: the first `n` values are initialized
+    ///     unsafe { spare.assume_init_push(n) };
+    ///     if n == 0 {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(list, b"hello world".as_slice());
+    /// ```
+    pub fn back_spare_capacity_mut(&mut self) -> BackSpareCapacity<'_, T, N> {
+        BackSpareCapacity::new(self)
+    }
+
     /// Inserts an element at the specified index, shifting subsequent elements to the right.
     /// If the target chunk is full, a new one will be allocated to accommodate the element.
     ///
@@ -264,8 +766,18 @@ where
     /// assert_eq!(list.get(1), Some(&20));
     /// assert_eq!(list.get(2), Some(&30));
     /// ```
+    #[track_caller]
     pub fn insert(&mut self, index: usize, value: T) {
-        assert!(index <= self.len());
+        self.insert_with::<DefaultPolicy>(index, value);
+    }
+
+    #[track_caller]
+    fn insert_with<P: ChunkPolicy>(&mut self, index: usize, value: T) {
+        let len = self.len;
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
 
         let SearchTarget {
             chunk_index,
@@ -275,12 +787,21 @@ where
             target_index: self.chunks.back().map(VecDeque::len).unwrap_or(0),
         });
 
-        self.raw_insert(chunk_index, target_index, value);
+        self.raw_insert_with::<P>(chunk_index, target_index, value);
     }
 
     fn raw_insert(&mut self, chunk_index: usize, target_index: usize, value: T) {
+        self.raw_insert_with::<DefaultPolicy>(chunk_index, target_index, value);
+    }
+
+    fn raw_insert_with<P: ChunkPolicy>(
+        &mut self,
+        chunk_index: usize,
+        target_index: usize,
+        value: T,
+    ) {
         if chunk_index == 0 && target_index == 0 {
-            self.push_front(value);
+            self.push_front_with::<P>(value);
             return;
         }
 
@@ -291,7 +812,7 @@ where
         assert!(target_index <= chunk.len());
 
         if chunk_index + 1 >= chunks_len && target_index >= chunk.len() {
-            self.push_back(value);
+            self.push_back_with::<P>(value);
             return;
         }
 
@@ -300,27 +821,16 @@ where
             chunk.push_front(value);
 
             self.chunks.insert(chunk_index + 1, chunk);
+            self.counters.allocation();
             self.len += 1;
             return;
         }
 
-        if chunk.len() >= N {
-            let spilled_value = chunk.pop_back().unwrap();
-
-            match self.chunks.get_mut(chunk_index + 1) {
-                Some(chunk) if chunk.len() < N => chunk.push_front(spilled_value),
-                next_chunk => {
-                    let mut chunk = VecDeque::with_capacity(N);
-                    chunk.push_front(spilled_value);
-
-                    if next_chunk.is_none() {
-                        self.chunks.push_back(chunk);
-                    } else {
-                        self.chunks.insert(chunk_index + 1, chunk);
-                    }
-                }
-            }
-        }
+        let (chunk_index, target_index) = if chunk.len() >= N {
+            self.spill(chunk_index, target_index, P::split_len(N).min(N - 1).max(1))
+        } else {
+            (chunk_index, target_index)
+        };
 
         let chunk = &mut self.chunks[chunk_index];
         chunk.insert(target_index, value);
@@ -328,45 +838,529 @@ where
         self.len += 1;
     }
 
-    /// Moves all elements from the `other` list to the end of this one.
+    /// Moves the last `n` elements of the full chunk at `chunk_index` to the front of the next one,
+    /// or to a new chunk if they don't fit, along with the room for inserting at `target_index`.
+    /// Returns where that insertion has to happen after the move.
+    fn spill(&mut self, chunk_index: usize, target_index: usize, n: usize) -> (usize, usize) {
+        let at = N - n;
+        let room = n + usize::from(target_index > at);
+        self.counters.spill();
+
+        match self.chunks.get(chunk_index + 1) {
+            Some(next) if next.len() + room <= N => {
+                let mut chunks = self.chunks.range_mut(chunk_index..=chunk_index + 1);
+                let (Some(chunk), Some(next)) = (chunks.next(), chunks.next()) else {
+                    unreachable!()
+                };
+
+                chunk
+                    .drain(at..)
+                    .rev()
+                    .for_each(|value| next.push_front(value));
+            }
+            _ => {
+                let mut spilled = VecDeque::with_capacity(N);
+                spilled.extend(self.chunks[chunk_index].drain(at..));
+                self.chunks.insert(chunk_index + 1, spilled);
+                self.counters.allocation();
+                self.counters.split();
+            }
+        }
+
+        if target_index > at {
+            (chunk_index + 1, target_index - at)
+        } else {
+            (chunk_index, target_index)
+        }
+    }
+
+    /// Inserts all the given values in a single sweep over the list.
     ///
-    /// This reuses all the chunks from other list and moves them into self.
-    /// After this operation, other becomes empty.
+    /// Each index refers to a position in the list as it was before the call, so a value is
+    /// inserted right before the element that was at its index, or at the back of the list
+    /// if the index equals its length. Values with the same index keep their relative order.
     ///
-    /// # Example
-    /// ```rust
-    /// use array_list::ArrayList;
+    /// Chunks without insertions are left as they are, while each of the others is rebuilt once,
+    /// in place if the new values fit, or spread over new chunks otherwise.
     ///
-    /// let mut list1: ArrayList<i32, 4> = ArrayList::new();
-    /// list1.push_back(1);
-    /// list1.push_back(2);
+    /// # Panics
+    /// - Panics if the values are not sorted by index in ascending order.
+    /// - Panics if an index is out of bounds (greater than the list's current length).
     ///
-    /// let mut list2: ArrayList<i32, 4> = ArrayList::new();
-    /// list2.push_back(3);
-    /// list2.push_back(4);
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
     ///
-    /// list1.append(&mut list2);
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
     ///
-    /// assert_eq!(list1.len(), 4);
-    /// assert_eq!(list1.get(0), Some(&1));
-    /// assert_eq!(list1.get(1), Some(&2));
-    /// assert_eq!(list1.get(2), Some(&3));
-    /// assert_eq!(list1.get(3), Some(&4));
+    /// list.insert_batch([(0, 5), (2, 25), (2, 26), (3, 35)]);
+    /// assert_eq!(list, [5, 10, 20, 25, 26, 30, 35]);
     /// ```
-    pub fn append(&mut self, other: &mut Self) {
+    #[track_caller]
+    pub fn insert_batch<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = (usize, T)>,
+    {
+        let values: Vec<(usize, T)> = values.into_iter().collect();
+        assert!(
+            values.is_sorted_by_key(|(index, _)| *index),
+            "indices are not sorted"
+        );
+        if let Some(&(index, _)) = values.last() {
+            let len = self.len;
+            assert!(
+                index <= len,
+                "insertion index (is {index}) should be <= len (is {len})"
+            );
+        }
+
+        let next_at = |values: &mut std::vec::IntoIter<(usize, T)>, index: usize| {
+            let (next, _) = values.as_slice().first()?;
+            (*next == index).then(|| values.next()).flatten()
+        };
+
+        let mut values = values.into_iter();
+        let mut out = Self {
+            counters: self.counters,
+            ..Self::new()
+        };
+        let mut offset = 0;
+
+        for mut chunk in core::mem::take(&mut self.chunks) {
+            let start = offset;
+            offset += chunk.len();
+
+            let count = values
+                .as_slice()
+                .partition_point(|(index, _)| *index < offset);
+            if count == 0 {
+                out.len += chunk.len();
+                out.chunks.push_back(chunk);
+                continue;
+            }
+
+            if chunk.len() + count <= N {
+                // rotate the chunk once, interleaving the new values with the old ones
+                chunk.reserve_exact(count);
+                for index in start..offset {
+                    while let Some((_, value)) = next_at(&mut values, index) {
+                        chunk.push_back(value);
+                    }
+
+                    let value = chunk.pop_front().unwrap();
+                    chunk.push_back(value);
+                }
+
+                out.len += chunk.len();
+                out.chunks.push_back(chunk);
+                continue;
+            }
+
+            let mut woven = Self {
+                counters: out.counters,
+                ..Self::new()
+            };
+            for (index, value) in (start..offset).zip(chunk) {
+                while let Some((_, value)) = next_at(&mut values, index) {
+                    woven.push_back(value);
+                }
+
+                woven.push_back(value);
+            }
+
+            out.len += woven.len;
+            out.chunks.append(&mut woven.chunks);
+            out.counters = woven.counters;
+        }
+
+        values.for_each(|(_, value)| out.push_back(value));
+        *self = out;
+    }
+
+    /// Moves all the chunks of `other` in between the elements at `target_index - 1` and
+    /// `target_index` of the chunk at `chunk_index`, splitting that chunk if needed.
+    /// Returns the index that the first chunk of `other` ends up at.
+    fn splice_chunks(
+        &mut self,
+        chunk_index: usize,
+        target_index: usize,
+        other: &mut Self,
+    ) -> usize {
+        let at = match self.chunks.get_mut(chunk_index) {
+            Some(chunk) if target_index > 0 && target_index < chunk.len() => {
+                let mut tail = VecDeque::with_capacity(N);
+                tail.extend(chunk.drain(target_index..));
+                self.chunks.insert(chunk_index + 1, tail);
+                self.counters.allocation();
+                self.counters.split();
+                chunk_index + 1
+            }
+            Some(_) if target_index > 0 => chunk_index + 1,
+            _ => chunk_index,
+        };
+
+        let mut tail = self.chunks.split_off(at);
         self.chunks.append(&mut other.chunks);
+        self.chunks.append(&mut tail);
+
         self.len += other.len;
         other.len = 0;
+        at
     }
 
-    /// Removes and returns the first element of the `ArrayList`, if any.
-    /// If the list is empty, it returns `None`.
+    /// Inserts the values yielded by `iter` in between the elements at `target_index - 1` and
+    /// `target_index` of the chunk at `chunk_index`, in order.
     ///
-    /// # Examples
-    /// ```
-    /// use array_list::ArrayList;
+    /// The values first fill the room left in the chunk before the insertion point, which may
+    /// be made by moving the elements that precede it to the previous chunk. The rest are collected
+    /// into full chunks spliced in between, the last of which is then moved as far as possible
+    /// into the room left at the front of the chunk after the insertion point. This way no two
+    /// chunks around the insertion point end up fitting in one, no matter how small the batches.
     ///
-    /// let mut list: ArrayList<i64, 4> = ArrayList::new();
+    /// Returns where the insertion point ends up, right after the element that precedes it,
+    /// along with where the element that was right after it ends up.
+    fn insert_iter<I>(
+        &mut self,
+        chunk_index: usize,
+        target_index: usize,
+        iter: I,
+    ) -> ((usize, usize), (usize, usize))
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter().peekable();
+
+        // inserting at the front of a chunk is the same as inserting at the back of the previous one
+        let (mut chunk_index, mut target_index) = match chunk_index.checked_sub(1) {
+            Some(previous) if target_index == 0 => (previous, self.chunks[previous].len()),
+            _ => (chunk_index, target_index),
+        };
+        let mut start = (chunk_index, target_index);
+
+        if let Some(chunk) = self
+            .chunks
+            .get_mut(chunk_index)
+            .filter(|_| target_index > 0)
+        {
+            // moves the elements past the insertion point out of the way while extending the chunk
+            let moved = chunk.len() - target_index;
+            let len = chunk.len();
+            chunk.rotate_right(moved);
+            chunk.extend(iter.by_ref().take(N - len));
+            chunk.rotate_left(moved);
+
+            let n = chunk.len() - len;
+            self.len += n;
+            target_index += n;
+        }
+
+        let fits_previous = chunk_index > 0
+            && target_index < self.chunks[chunk_index].len()
+            && target_index <= N - self.chunks[chunk_index - 1].len();
+
+        if fits_previous && iter.peek().is_some() {
+            // rather than splitting the chunk, the previous one takes the elements
+            // before the insertion point along with as many values as it has room for
+            let mut chunks = self.chunks.range_mut(chunk_index - 1..=chunk_index);
+            let (Some(previous), Some(chunk)) = (chunks.next(), chunks.next()) else {
+                unreachable!()
+            };
+
+            start = (chunk_index - 1, previous.len() + start.1);
+            previous.extend(chunk.drain(..target_index));
+            let len = previous.len();
+            previous.extend(iter.by_ref().take(N - len));
+
+            self.len += previous.len() - len;
+            chunk_index -= 1;
+            target_index = previous.len();
+        }
+
+        let mut other = Self {
+            counters: self.counters,
+            ..Self::new()
+        };
+        other.extend(iter);
+        self.counters = other.counters;
+
+        if other.is_empty() {
+            let end = match self.chunks.get(chunk_index) {
+                Some(chunk) if target_index >= chunk.len() => (chunk_index + 1, 0),
+                _ => (chunk_index, target_index),
+            };
+
+            return (start, end);
+        }
+
+        let spliced = other.chunks.len();
+        let mut next = self.splice_chunks(chunk_index, target_index, &mut other) + spliced;
+        let Some(room) = self.chunks.get(next).map(|chunk| N - chunk.len()) else {
+            return (start, (next, 0));
+        };
+
+        let mut chunks = self.chunks.range_mut(next - 1..=next);
+        let (Some(last), Some(chunk)) = (chunks.next(), chunks.next()) else {
+            unreachable!()
+        };
+
+        let n = room.min(last.len());
+        last.drain(last.len() - n..)
+            .rev()
+            .for_each(|value| chunk.push_front(value));
+
+        if last.is_empty() {
+            self.chunks.remove(next - 1);
+            self.counters.merge();
+            next -= 1;
+        }
+
+        (start, (next, n))
+    }
+
+    /// Removes up to `n` elements starting from the one at `target_index` of the chunk at
+    /// `chunk_index`, returning them as a new list. Chunks that are entirely covered are moved
+    /// as they are, only the first and the last ones are split.
+    fn split_range(&mut self, mut chunk_index: usize, target_index: usize, n: usize) -> Self {
+        let mut out = Self::new();
+        let mut rest = n;
+
+        if let Some(chunk) = self.chunks.get_mut(chunk_index) {
+            if target_index > 0 {
+                let end = chunk.len().min(target_index.saturating_add(rest));
+                if target_index < end {
+                    let mut head = VecDeque::with_capacity(N);
+                    head.extend(chunk.drain(target_index..end));
+                    self.counters.allocation();
+                    self.counters.split();
+                    rest -= head.len();
+                    out.len += head.len();
+                    out.chunks.push_back(head);
+                }
+
+                chunk_index += 1;
+            }
+        }
+
+        let mut tail = self.chunks.split_off(chunk_index);
+        while let Some(chunk) = tail.front_mut() {
+            if rest == 0 {
+                break;
+            }
+
+            if chunk.len() > rest {
+                let mut head = VecDeque::with_capacity(N);
+                head.extend(chunk.drain(..rest));
+                self.counters.allocation();
+                self.counters.split();
+                out.len += head.len();
+                out.chunks.push_back(head);
+                break;
+            }
+
+            rest -= chunk.len();
+            out.len += chunk.len();
+            out.chunks.extend(tail.pop_front());
+        }
+
+        self.chunks.append(&mut tail);
+        self.len -= out.len;
+        out
+    }
+
+    /// Moves all elements from the `other` list to the end of this one.
+    ///
+    /// This reuses all the chunks from other list and moves them into self.
+    /// After this operation, other becomes empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list1: ArrayList<i32, 4> = ArrayList::new();
+    /// list1.push_back(1);
+    /// list1.push_back(2);
+    ///
+    /// let mut list2: ArrayList<i32, 4> = ArrayList::new();
+    /// list2.push_back(3);
+    /// list2.push_back(4);
+    ///
+    /// list1.append(&mut list2);
+    ///
+    /// assert_eq!(list1.len(), 4);
+    /// assert_eq!(list1.get(0), Some(&1));
+    /// assert_eq!(list1.get(1), Some(&2));
+    /// assert_eq!(list1.get(2), Some(&3));
+    /// assert_eq!(list1.get(3), Some(&4));
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.chunks.append(&mut other.chunks);
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Prepends the elements of `iter` to the list, keeping them in the order they come in.
+    ///
+    /// The elements are packed into full chunks, and the last of them is merged
+    /// into the first chunk of the list when both fit in a single one.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 4> = ArrayList::from([4, 5]);
+    /// list.extend_front([1, 2, 3]);
+    ///
+    /// assert_eq!(list, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut front = Self {
+            counters: self.counters,
+            ..Self::new()
+        };
+        front.extend(iter);
+        self.counters = front.counters;
+        self.len += front.len;
+
+        let fits = match (front.chunks.back(), self.chunks.front()) {
+            (Some(last), Some(first)) => last.len() + first.len() <= N,
+            _ => false,
+        };
+        if fits {
+            let last = front.chunks.pop_back().unwrap();
+            let first = self.chunks.front_mut().unwrap();
+            last.into_iter()
+                .rev()
+                .for_each(|value| first.push_front(value));
+        }
+
+        while let Some(chunk) = front.chunks.pop_back() {
+            self.chunks.push_front(chunk);
+        }
+    }
+
+    /// Merges the elements of the sorted `other` list into this sorted list, in O(n + m).
+    /// After this operation, other becomes empty.
+    ///
+    /// Runs of elements that come from the same side are moved in bulk, and chunks that
+    /// fall entirely within such a run are moved as they are, the rest are packed into full chunks.
+    /// The merge is stable: equal elements from `self` come before the ones from `other`.
+    ///
+    /// If either list is not sorted the elements are still all kept, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list1: ArrayList<i32, 2> = ArrayList::from([1, 4, 5, 9]);
+    /// let mut list2: ArrayList<i32, 2> = ArrayList::from([2, 3, 6, 7, 8]);
+    ///
+    /// list1.merge(&mut list2);
+    ///
+    /// assert_eq!(list1, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// assert!(list2.is_empty());
+    /// ```
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        let mut left = core::mem::take(self);
+        let mut right = core::mem::take(other);
+        self.counters = left.counters;
+
+        while let (Some(l), Some(r)) = (left.chunks.front(), right.chunks.front()) {
+            let head = &r[0];
+            let run = l.partition_point(|value| value <= head);
+            if run > 0 {
+                self.take_run(&mut left, run);
+                continue;
+            }
+
+            let head = &l[0];
+            let run = r.partition_point(|value| value < head);
+            self.take_run(&mut right, run);
+        }
+
+        self.append(&mut left);
+        self.append(&mut right);
+    }
+
+    /// Moves the first `run` elements of the front chunk of `source` to the back of the list,
+    /// taking over the whole chunk if possible.
+    fn take_run(&mut self, source: &mut Self, run: usize) {
+        let Some(chunk) = source.chunks.front_mut() else {
+            return;
+        };
+
+        source.len -= run;
+        if run == chunk.len() && self.chunks.back().is_none_or(|back| back.len() >= N) {
+            self.len += run;
+            self.chunks.extend(source.chunks.pop_front());
+            return;
+        }
+
+        self.extend(chunk.drain(..run));
+        if chunk.is_empty() {
+            source.chunks.pop_front();
+        }
+    }
+
+    /// Creates a list by merging sorted inputs, in O(n log k) for n elements over k inputs.
+    ///
+    /// The heads of the inputs are kept in a binary heap ordered by `cmp`, and the smallest one
+    /// is appended to the list each time, filling its chunks up as it goes.
+    /// The merge is stable: equal elements come in the order of their inputs.
+    ///
+    /// If any input is not sorted the elements are still all kept, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let shards = [vec![1, 4, 7], vec![2, 5, 8], vec![], vec![0, 3, 6, 9]];
+    /// let list: ArrayList<_, 4> = ArrayList::from_sorted_iters(shards, i32::cmp);
+    ///
+    /// assert_eq!(list, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn from_sorted_iters<I>(
+        iters: impl IntoIterator<Item = I>,
+        mut cmp: impl FnMut(&T, &T) -> Ordering,
+    ) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iters: Vec<I::IntoIter> = iters.into_iter().map(I::into_iter).collect();
+
+        // each head is tagged with the index of its input, which breaks ties
+        let mut heads: Vec<(T, usize)> = iters
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(source, iter)| Some((iter.next()?, source)))
+            .collect();
+        let mut less = |a: &(T, usize), b: &(T, usize)| cmp(&a.0, &b.0).then(a.1.cmp(&b.1)).is_lt();
+        for i in (0..heads.len() / 2).rev() {
+            sift_down(&mut heads, i, &mut less);
+        }
+
+        Self::from_iter(core::iter::from_fn(|| {
+            let &(_, source) = heads.first()?;
+            let value = match iters[source].next() {
+                Some(next) => core::mem::replace(&mut heads[0], (next, source)).0,
+                None => heads.swap_remove(0).0,
+            };
+
+            sift_down(&mut heads, 0, &mut less);
+            Some(value)
+        }))
+    }
+
+    /// Removes and returns the first element of the `ArrayList`, if any.
+    /// If the list is empty, it returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 4> = ArrayList::new();
     /// list.push_front(10);
     /// list.push_front(20);
     ///
@@ -413,6 +1407,121 @@ where
         value
     }
 
+    /// Removes the first `n` elements of the list, or all of them if there are fewer,
+    /// returning them as a new list.
+    ///
+    /// Whole chunks are moved to the returned list, only the last one may need to be split.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30, 40, 50]);
+    ///
+    /// assert_eq!(list.pop_front_n(3), [10, 20, 30]);
+    /// assert_eq!(list.pop_front_n(3), [40, 50]);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn pop_front_n(&mut self, n: usize) -> Self {
+        self.split_range(0, 0, n)
+    }
+
+    /// Removes the last `n` elements of the list, or all of them if there are fewer,
+    /// returning them as a new list.
+    ///
+    /// Whole chunks are moved to the returned list, only the first one may need to be split.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30, 40, 50]);
+    ///
+    /// assert_eq!(list.pop_back_n(3), [30, 40, 50]);
+    /// assert_eq!(list.pop_back_n(3), [10, 20]);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn pop_back_n(&mut self, n: usize) -> Self {
+        let n = n.min(self.len);
+
+        match self.search_target(self.len - n) {
+            Some(SearchTarget {
+                chunk_index,
+                target_index,
+            }) => self.split_range(chunk_index, target_index, n),
+            None => Self::new(),
+        }
+    }
+
+    /// Detaches the first chunk of the list, returning its elements without moving them
+    /// to a new buffer, or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// assert_eq!(list.pop_front_chunk(), Some(vec![10, 20]));
+    /// assert_eq!(list.pop_front_chunk(), Some(vec![30]));
+    /// assert_eq!(list.pop_front_chunk(), None);
+    /// ```
+    pub fn pop_front_chunk(&mut self) -> Option<Vec<T>> {
+        let chunk = self.chunks.pop_front()?;
+        self.len -= chunk.len();
+        Some(Vec::from(chunk))
+    }
+
+    /// Detaches the last chunk of the list, returning its elements without moving them
+    /// to a new buffer, or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// assert_eq!(list.pop_back_chunk(), Some(vec![30]));
+    /// assert_eq!(list.pop_back_chunk(), Some(vec![10, 20]));
+    /// assert_eq!(list.pop_back_chunk(), None);
+    /// ```
+    pub fn pop_back_chunk(&mut self) -> Option<Vec<T>> {
+        let chunk = self.chunks.pop_back()?;
+        self.len -= chunk.len();
+        Some(Vec::from(chunk))
+    }
+
+    /// Divides the list into `n` parts of nearly equal length, keeping their order.
+    ///
+    /// The first `len % n` parts hold one element more than the others. Whole chunks are moved
+    /// to the parts, only the ones straddling two parts need to be split.
+    ///
+    /// # Panics
+    /// - Panics if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30, 40, 50, 60, 70]);
+    /// let parts = list.split_into(3);
+    ///
+    /// assert_eq!(parts[0], [10, 20, 30]);
+    /// assert_eq!(parts[1], [40, 50]);
+    /// assert_eq!(parts[2], [60, 70]);
+    /// ```
+    #[track_caller]
+    pub fn split_into(mut self, n: usize) -> Vec<Self> {
+        assert!(n != 0, "number of parts must be non-zero");
+
+        let (len, extra) = (self.len / n, self.len % n);
+        let mut parts: Vec<Self> = (0..n - 1)
+            .map(|i| self.pop_front_n(len + usize::from(i < extra)))
+            .collect();
+        parts.push(self);
+        parts
+    }
+
     /// Removes and returns the element at the specified index, shifting subsequent elements left.
     ///
     /// # Examples
@@ -434,81 +1543,679 @@ where
     /// assert_eq!(list.remove(10), None);
     /// ```
     pub fn remove(&mut self, index: usize) -> Option<T> {
-        if index >= self.len {
-            return None;
-        }
-
-        if index == 0 {
-            return self.pop_front();
-        }
-
-        if index >= self.len.saturating_sub(1) {
-            return self.pop_back();
-        }
+        self.remove_with::<DefaultPolicy>(index)
+    }
 
+    fn remove_with<P: ChunkPolicy>(&mut self, index: usize) -> Option<T> {
         let SearchTarget {
             chunk_index,
             target_index,
-        } = self.search_target(index).unwrap();
+        } = self.search_target(index)?;
 
         let chunk = &mut self.chunks[chunk_index];
         let value = chunk.remove(target_index);
         if chunk.is_empty() {
             self.chunks.remove(chunk_index);
+        } else {
+            self.merge_around::<P>(chunk_index);
         }
 
         self.len -= 1;
         value
     }
 
-    /// Removes all elements from the `ArrayList`, effectively making it empty.
+    /// Merges the chunk at `chunk_index` with the next or the previous one,
+    /// if they fit in a single chunk and the policy asks for it.
+    fn merge_around<P: ChunkPolicy>(&mut self, chunk_index: usize) {
+        let len = self.chunks[chunk_index].len();
+        let fits = |neighbor: &VecDeque<T>| {
+            len + neighbor.len() <= N && P::should_merge(len, neighbor.len(), N)
+        };
+
+        let (into, from) = if self.chunks.get(chunk_index + 1).is_some_and(fits) {
+            (chunk_index, chunk_index + 1)
+        } else if chunk_index > 0 && fits(&self.chunks[chunk_index - 1]) {
+            (chunk_index - 1, chunk_index)
+        } else {
+            return;
+        };
+
+        let mut from = self.chunks.remove(from).unwrap();
+        let into = &mut self.chunks[into];
+        into.reserve_exact(from.len());
+        into.append(&mut from);
+        self.counters.merge();
+    }
+
+    /// Removes and returns the element at the specified index, without checking that it exists.
     ///
-    /// # Example
-    /// ```rust
-    /// use array_list::ArrayList;
+    /// For a safe alternative see [`remove`](Self::remove).
     ///
-    /// let mut list: ArrayList<i32, 4> = ArrayList::new();
-    /// list.push_back(1);
-    /// list.push_back(2);
-    /// list.push_back(3);
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*.
     ///
-    /// assert_eq!(list.len(), 3);
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     ///
-    /// list.clear();
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
     ///
-    /// assert_eq!(list.len(), 0);
-    /// assert!(list.is_empty());
-    /// assert_eq!(list.front(), None);
-    /// assert_eq!(list.back(), None);
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// // SAFETY: the list holds three elements
+    /// assert_eq!(unsafe { list.remove_unchecked(1) }, 20);
+    /// assert_eq!(list, [10, 30]);
     /// ```
-    pub fn clear(&mut self) {
-        self.chunks.clear();
-        self.len = 0;
+    pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        debug_assert!(index < self.len);
+
+        // SAFETY: the caller guarantees that `index` is in bounds
+        let SearchTarget {
+            chunk_index,
+            target_index,
+        } = unsafe { self.search_target(index).unwrap_unchecked() };
+
+        let chunk = unsafe { self.chunks.get_mut(chunk_index).unwrap_unchecked() };
+        let value = unsafe { chunk.remove(target_index).unwrap_unchecked() };
+        if chunk.is_empty() {
+            self.chunks.remove(chunk_index);
+        }
+
+        self.len -= 1;
+        value
     }
 
-    /// Returns a reference to the first element of the `ArrayList`, if any.
+    /// Removes the elements at the given indices, returning them as a new list in the same order.
+    ///
+    /// Each chunk holding some of the elements is compacted once,
+    /// so removing `k` elements costs O(n) rather than O(k·n).
+    ///
+    /// # Panics
+    /// - Panics if the `indices` are not sorted in strictly ascending order.
+    /// - Panics if an index is out of bounds (greater than or equal to the list's current length).
     ///
     /// # Examples
     /// ```
     /// use array_list::ArrayList;
     ///
-    /// let mut list: ArrayList<i64, 4> = ArrayList::new();
-    /// list.push_back(10);
-    /// list.push_back(20);
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30, 40, 50]);
     ///
-    /// assert_eq!(list.front(), Some(&10));
+    /// assert_eq!(list.remove_batch(&[0, 2, 3]), [10, 30, 40]);
+    /// assert_eq!(list, [20, 50]);
+    /// ```
+    #[track_caller]
+    pub fn remove_batch(&mut self, indices: &[usize]) -> Self {
+        assert!(indices.is_sorted_by(|a, b| a < b), "indices are not sorted");
+        if let Some(&index) = indices.last() {
+            let len = self.len;
+            assert!(
+                index < len,
+                "removal index (is {index}) should be < len (is {len})"
+            );
+        }
+
+        let mut removed = Self::new();
+        let mut indices = indices.iter().copied().peekable();
+        let mut offset = 0;
+
+        for chunk in self.chunks.iter_mut() {
+            let Some(&next) = indices.peek() else {
+                break;
+            };
+
+            let start = offset;
+            offset += chunk.len();
+            if next >= offset {
+                continue;
+            }
+
+            // rotate the chunk once, putting back the elements that are kept
+            for index in start..offset {
+                let value = chunk.pop_front().unwrap();
+                match indices.next_if_eq(&index) {
+                    Some(_) => removed.push_back(value),
+                    None => chunk.push_back(value),
+                }
+            }
+        }
+
+        self.chunks.retain(|chunk| !chunk.is_empty());
+        self.len -= removed.len;
+        removed
+    }
+
+    /// Removes the elements whose position is set in `mask`, returning how many were removed.
     ///
-    /// list.pop_front();
-    /// assert_eq!(list.front(), Some(&20));
+    /// Each chunk is compacted once, keeping the elements whose position is not set
+    /// in their original order.
     ///
-    /// list.pop_front();
-    /// assert_eq!(list.front(), None);
+    /// # Panics
+    /// - Panics if the length of `mask` differs from the list's current length.
+    ///
+    /// # Examples
     /// ```
-    pub fn front(&self) -> Option<&T> {
-        self.chunks.front().and_then(|chunk| chunk.front())
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30, 40, 50]);
+    ///
+    /// assert_eq!(list.remove_by_mask(&[true, false, true, true, false]), 3);
+    /// assert_eq!(list, [20, 50]);
+    /// ```
+    #[track_caller]
+    pub fn remove_by_mask(&mut self, mask: &[bool]) -> usize {
+        let len = self.len;
+        assert!(
+            mask.len() == len,
+            "mask length (is {}) should be == len (is {len})",
+            mask.len()
+        );
+
+        let mut mask = mask.iter();
+        for chunk in self.chunks.iter_mut() {
+            chunk.retain(|_| !mask.next().unwrap());
+        }
+
+        self.chunks.retain(|chunk| !chunk.is_empty());
+        self.len = self.chunks.iter().map(VecDeque::len).sum();
+        len - self.len
     }
 
-    /// Returns a mutable reference to the first element of the `ArrayList`, if any.
+    /// Retains only the elements for which `f` returns `true`, not preserving their order.
+    ///
+    /// Each removed element is replaced by the last one of its chunk rather than shifting
+    /// the ones after it, so elements may be reordered within their chunk,
+    /// while the chunks themselves keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 4> = ArrayList::from_iter(0..8);
+    /// list.swap_retain(|v| v % 3 != 0);
+    ///
+    /// assert_eq!(list, [2, 1, 4, 5, 7]);
+    /// ```
+    pub fn swap_retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let len = &mut self.len;
+        self.chunks.retain_mut(|chunk| {
+            let mut i = 0;
+            while i < chunk.len() {
+                if f(&chunk[i]) {
+                    i += 1;
+                    continue;
+                }
+
+                chunk.swap_remove_back(i);
+                *len -= 1;
+            }
+
+            !chunk.is_empty()
+        });
+    }
+
+    /// Returns the edits that turn this list into `other`, as few of them as possible.
+    ///
+    /// Takes O((n + m) D) time and O(D²) memory, where D is the number of elements
+    /// that differ, so it's best suited to lists that are mostly alike.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::{ArrayList, Edit};
+    ///
+    /// let list: ArrayList<_, 4> = ArrayList::from([1, 2, 3, 4, 5]);
+    /// let other: ArrayList<_, 4> = ArrayList::from([1, 3, 4, 6, 5]);
+    ///
+    /// let script = list.diff(&other);
+    /// assert_eq!(
+    ///     script.edits(),
+    ///     [
+    ///         Edit::Delete { index: 1, len: 1 },
+    ///         Edit::Insert { index: 4, values: vec![6] },
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Self) -> EditScript<T>
+    where
+        T: PartialEq + Clone,
+    {
+        EditScript::between(self, other)
+    }
+
+    /// Applies the edits of `script` in a single sweep over the list.
+    ///
+    /// Chunks without edits are moved as they are, only the ones around each edit are rebuilt.
+    ///
+    /// # Panics
+    /// - Panics if the edits are not sorted by index, or if they overlap.
+    /// - Panics if an edit reaches past the end of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 4> = ArrayList::from(*b"hello world");
+    /// let mut replica = list.clone();
+    ///
+    /// let mut edited = list.clone();
+    /// edited.insert(5, b',');
+    /// edited.pop_back();
+    ///
+    /// replica.apply_edits(list.diff(&edited));
+    /// assert_eq!(replica, edited);
+    /// ```
+    #[track_caller]
+    pub fn apply_edits(&mut self, script: EditScript<T>) {
+        script.apply(self);
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// # Panics
+    /// - Panics if `a` or `b` are out of bounds (greater than or equal to the list's current length).
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// list.swap(0, 2);
+    /// assert_eq!(list, [30, 20, 10]);
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len;
+        assert!(a < len, "swap index a (is {a}) should be < len (is {len})");
+        assert!(b < len, "swap index b (is {b}) should be < len (is {len})");
+
+        // SAFETY: both indices have just been checked
+        unsafe { self.swap_unchecked(a, b) };
+    }
+
+    /// Swaps the elements at indices `a` and `b`, without checking that they exist.
+    ///
+    /// For a safe alternative see [`swap`](Self::swap).
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// // SAFETY: the list holds three elements
+    /// unsafe { list.swap_unchecked(0, 2) };
+    /// assert_eq!(list, [30, 20, 10]);
+    /// ```
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        debug_assert!(a < self.len && b < self.len);
+
+        if a == b {
+            return;
+        }
+
+        // SAFETY: the caller guarantees that both indices are in bounds,
+        // and being distinct they point to different elements
+        unsafe {
+            let a: *mut T = self.get_unchecked_mut(a);
+            let b: *mut T = self.get_unchecked_mut(b);
+            core::ptr::swap_nonoverlapping(a, b, 1);
+        }
+    }
+
+    /// Swaps the elements in range `a` with the ones in range `b`, a contiguous run at a time.
+    ///
+    /// # Panics
+    /// - Panics if either range is out of bounds, or if its start is greater than its end.
+    /// - Panics if the ranges have different lengths, or if they overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// list.swap_ranges(5.., 0..2);
+    /// assert_eq!(list, [5, 6, 2, 3, 4, 0, 1]);
+    /// ```
+    #[track_caller]
+    pub fn swap_ranges<A, B>(&mut self, a: A, b: B)
+    where
+        A: RangeBounds<usize>,
+        B: RangeBounds<usize>,
+    {
+        let a = self.resolve_range(a);
+        let b = self.resolve_range(b);
+        assert!(
+            a.len() == b.len(),
+            "ranges have different lengths: {} != {}",
+            a.len(),
+            b.len()
+        );
+
+        let (first, second) = if a.start <= b.start { (a, b) } else { (b, a) };
+        assert!(
+            first.is_empty() || first.end <= second.start,
+            "ranges overlap: {first:?} and {second:?}"
+        );
+
+        let len = first.len();
+        let (mut head, mut tail) = self.split_at_mut(second.start);
+        let mut lefts = head.runs_in_mut(first);
+        let mut rights = tail.runs_in_mut(0..len);
+        let (mut left, mut right): (&mut [T], &mut [T]) = (&mut [], &mut []);
+
+        loop {
+            if left.is_empty() {
+                match lefts.next() {
+                    Some(run) => left = run,
+                    None => break,
+                }
+            }
+
+            if right.is_empty() {
+                right = rights.next().unwrap();
+            }
+
+            let n = left.len().min(right.len());
+            let (left_head, left_tail) = core::mem::take(&mut left).split_at_mut(n);
+            let (right_head, right_tail) = core::mem::take(&mut right).split_at_mut(n);
+            left_head.swap_with_slice(right_head);
+            (left, right) = (left_tail, right_tail);
+        }
+    }
+
+    /// # Safety
+    /// `index` must be in bounds.
+    unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        unsafe {
+            let SearchTarget {
+                chunk_index,
+                target_index,
+            } = self.search_target(index).unwrap_unchecked();
+
+            let chunk = self.chunks.get_mut(chunk_index).unwrap_unchecked();
+            chunk.get_mut(target_index).unwrap_unchecked()
+        }
+    }
+
+    /// Copies the elements in the `src` range over the ones starting at `dest`,
+    /// the same way [`slice::copy_within`] does. The two ranges may overlap.
+    ///
+    /// # Panics
+    /// - Panics if `src` is out of bounds, or if its start is greater than its end.
+    /// - Panics if `dest + src.len()` is greater than the list's current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4, 5]);
+    ///
+    /// list.copy_within(1..4, 2);
+    /// assert_eq!(list, [0, 1, 1, 2, 3, 5]);
+    /// ```
+    #[track_caller]
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        T: Copy,
+        R: RangeBounds<usize>,
+    {
+        self.clone_within(src, dest);
+    }
+
+    /// Clones the elements in the `src` range over the ones starting at `dest`,
+    /// like [`copy_within`](Self::copy_within) does for `Copy` elements. The two ranges may overlap.
+    ///
+    /// The source elements are cloned a chunk at a time into a buffer first,
+    /// then the destination is overwritten a chunk at a time.
+    ///
+    /// # Panics
+    /// - Panics if `src` is out of bounds, or if its start is greater than its end.
+    /// - Panics if `dest + src.len()` is greater than the list's current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from(["a", "b", "c", "d"].map(String::from));
+    ///
+    /// list.clone_within(2.., 0);
+    /// assert_eq!(Vec::from_iter(list), ["c", "d", "c", "d"]);
+    /// ```
+    #[track_caller]
+    pub fn clone_within<R>(&mut self, src: R, dest: usize)
+    where
+        T: Clone,
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = self.resolve_range(src);
+        let len = self.len;
+        assert!(
+            dest <= len - (end - start),
+            "dest is out of bounds: {dest} + {} should be <= len (is {len})",
+            end - start
+        );
+
+        let mut values = Vec::with_capacity(end - start);
+        let mut iter = self.iter();
+        if start > 0 {
+            iter.nth(start - 1);
+        }
+        for run in iter.as_chunks() {
+            let n = run.len().min(values.capacity() - values.len());
+            values.extend_from_slice(&run[..n]);
+        }
+
+        let Some(SearchTarget {
+            chunk_index,
+            mut target_index,
+        }) = self.search_target(dest)
+        else {
+            return;
+        };
+
+        let mut values = values.as_slice();
+        for chunk in self.chunks.range_mut(chunk_index..) {
+            let (head, tail) = chunk.as_mut_slices();
+            for run in [head, tail] {
+                let skip = target_index.min(run.len());
+                target_index -= skip;
+
+                let n = (run.len() - skip).min(values.len());
+                run[skip..skip + n].clone_from_slice(&values[..n]);
+                values = &values[n..];
+            }
+
+            if values.is_empty() {
+                break;
+            }
+        }
+    }
+
+    /// Overwrites the elements in `range` with clones of `value`.
+    ///
+    /// # Panics
+    /// - Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// list.fill_range(1..4, 9);
+    /// assert_eq!(list, [0, 9, 9, 9, 4]);
+    /// ```
+    #[track_caller]
+    pub fn fill_range(&mut self, range: impl RangeBounds<usize>, value: T)
+    where
+        T: Clone,
+    {
+        self.view_mut(range)
+            .iter_mut()
+            .for_each(|slot| slot.clone_from(&value));
+    }
+
+    /// Reverses the order of the elements in `range`, leaving the other ones in place.
+    ///
+    /// # Panics
+    /// - Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// list.reverse_range(1..);
+    /// assert_eq!(list, [0, 4, 3, 2, 1]);
+    /// ```
+    #[track_caller]
+    pub fn reverse_range(&mut self, range: impl RangeBounds<usize>) {
+        let mut values = self.view_mut(range).into_iter();
+        while let (Some(a), Some(b)) = (values.next(), values.next_back()) {
+            core::mem::swap(a, b);
+        }
+    }
+
+    /// Sorts the elements in `range`, leaving the other ones in place.
+    ///
+    /// The sort is stable, and the elements are swapped into place along the cycles
+    /// of their sorted order, so the chunks layout is left untouched.
+    ///
+    /// # Panics
+    /// - Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([5, 3, 4, 1, 2, 0]);
+    ///
+    /// list.sort_range(1..5);
+    /// assert_eq!(list, [5, 1, 2, 3, 4, 0]);
+    /// ```
+    #[track_caller]
+    pub fn sort_range(&mut self, range: impl RangeBounds<usize>)
+    where
+        T: Ord,
+    {
+        let mut values: Vec<&mut T> = self.view_mut(range).into_iter().collect();
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&a, &b| values[a].cmp(&values[b]));
+
+        let mut visited = vec![false; values.len()];
+        permute_values(&mut values, &order, &mut visited);
+    }
+
+    /// Turns any range of indices into a half-open one, checking it against the list's bounds.
+    #[track_caller]
+    fn resolve_range(&self, range: impl RangeBounds<usize>) -> Range<usize> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows"),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflows"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        let len = self.len;
+        assert!(start <= end, "range starts at {start} but ends at {end}");
+        assert!(
+            end <= len,
+            "range end (is {end}) should be <= len (is {len})"
+        );
+        start..end
+    }
+
+    /// Removes all elements from the `ArrayList`, effectively making it empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i32, 4> = ArrayList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// list.clear();
+    ///
+    /// assert_eq!(list.len(), 0);
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.front(), None);
+    /// assert_eq!(list.back(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+    }
+
+    /// Reserves room in the chunks table for at least `additional` more elements at the back.
+    ///
+    /// Chunks themselves are still allocated lazily, as they are filled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity of the chunks table overflows `usize`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i32, 4> = ArrayList::new();
+    /// list.reserve(10);
+    /// list.extend(0..10);
+    ///
+    /// assert_eq!(list.len(), 10);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.chunks.reserve(self.chunks_needed(additional));
+    }
+
+    /// Returns how many new chunks `additional` elements pushed at the back would take.
+    fn chunks_needed(&self, additional: usize) -> usize {
+        let room = self.chunks.back().map_or(0, |chunk| N - chunk.len());
+        additional.saturating_sub(room).div_ceil(N)
+    }
+
+    /// Returns a reference to the first element of the `ArrayList`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 4> = ArrayList::new();
+    /// list.push_back(10);
+    /// list.push_back(20);
+    ///
+    /// assert_eq!(list.front(), Some(&10));
+    ///
+    /// list.pop_front();
+    /// assert_eq!(list.front(), Some(&20));
+    ///
+    /// list.pop_front();
+    /// assert_eq!(list.front(), None);
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.chunks.front().and_then(|chunk| chunk.front())
+    }
+
+    /// Returns a mutable reference to the first element of the `ArrayList`, if any.
     ///
     /// # Examples
     /// ```
@@ -674,6 +2381,52 @@ where
             .map(|chunk| &mut chunk[index])
     }
 
+    /// Returns a reference to the element at the specified index counting from the back,
+    /// `0` being the back element, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// assert_eq!(list.get_back(0), Some(&30));
+    /// assert_eq!(list.get_back(2), Some(&10));
+    /// assert_eq!(list.get_back(3), None); // Out of bounds
+    /// assert_eq!(ArrayList::<i64, 2>::new().get_back(0), None);
+    /// ```
+    pub fn get_back(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.get(self.len() - index - 1)
+    }
+
+    /// Returns a mutable reference to the element at the specified index counting from the back,
+    /// `0` being the back element, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<i64, 2> = ArrayList::from([10, 20, 30]);
+    ///
+    /// if let Some(value) = list.get_back_mut(1) {
+    ///     *value = 42;
+    /// }
+    ///
+    /// assert_eq!(list, [10, 42, 30]);
+    /// assert_eq!(list.get_back_mut(3), None); // Out of bounds
+    /// ```
+    pub fn get_back_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.get_mut(self.len() - index - 1)
+    }
+
     /// Returns the number of elements currently stored in the `ArrayList`.
     ///
     /// # Example
@@ -708,326 +2461,2239 @@ where
         self.len == 0
     }
 
-    /// Provides an iterator over list's elements.
+    /// Checks if all the elements are stored contiguously, in a single chunk that doesn't wrap
+    /// around its buffer. An empty list is contiguous.
     ///
     /// # Examples
     /// ```
     /// use array_list::ArrayList;
     ///
-    /// let mut list: ArrayList<_, 2> = ArrayList::new();
-    /// list.push_back(0);
-    /// list.push_back(1);
-    /// list.push_back(2);
+    /// let mut list: ArrayList<_, 4> = ArrayList::from([1, 2]);
+    /// assert!(list.is_contiguous());
     ///
-    /// let mut iter = list.iter();
-    /// assert_eq!(iter.next(), Some(&0));
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), None);
+    /// list.extend([3, 4, 5]);
+    /// assert!(!list.is_contiguous());
     /// ```
-    #[inline]
-    pub fn iter(&self) -> Iter<'_, T, N> {
-        Iter::from_list(self)
+    pub fn is_contiguous(&self) -> bool {
+        self.as_single_slice().is_some()
     }
 
-    /// Provides a mutable iterator over list's elements.
+    /// Returns all the elements as a single slice, if they are stored contiguously.
     ///
     /// # Examples
     /// ```
     /// use array_list::ArrayList;
     ///
-    /// let mut list: ArrayList<_, 2> = ArrayList::new();
-    /// list.push_back(0);
-    /// list.push_back(1);
-    /// list.push_back(2);
+    /// let list: ArrayList<_, 4> = ArrayList::from([1, 2, 3]);
+    /// assert_eq!(list.as_single_slice(), Some(&[1, 2, 3][..]));
     ///
-    /// let mut iter = list.iter();
-    /// assert_eq!(iter.next(), Some(&0));
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), None);
+    /// let list: ArrayList<_, 2> = ArrayList::from([1, 2, 3]);
+    /// assert_eq!(list.as_single_slice(), None);
     /// ```
-    #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
-        IterMut::from_list(self)
+    pub fn as_single_slice(&self) -> Option<&[T]> {
+        match self.chunks.len() {
+            0 => Some(&[]),
+            1 => match self.chunks[0].as_slices() {
+                (head, []) => Some(head),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
-    /// Provides a cursor at the front element.
+    /// Returns all the elements as a single mutable slice, if they are stored contiguously.
     ///
-    /// The cursor is pointing to the “ghost” non-element if the list is empty.
-    #[inline]
-    pub fn cursor_front(&self) -> Cursor<'_, T, N> {
-        Cursor::from_front(self)
-    }
-
-    /// Provides a cursor at the back element.
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
     ///
-    /// The cursor is pointing to the “ghost” non-element if the list is empty.
-    #[inline]
-    pub fn cursor_back(&self) -> Cursor<'_, T, N> {
-        Cursor::from_back(self)
-    }
-
-    /// Provides a cursor at the front element.
+    /// let mut list: ArrayList<_, 4> = ArrayList::from([3, 1, 2]);
+    /// if let Some(values) = list.as_single_slice_mut() {
+    ///     values.sort();
+    /// }
     ///
-    /// The cursor is pointing to the “ghost” non-element if the list is empty.
-    #[inline]
-    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N> {
-        CursorMut::from_front(self)
+    /// assert_eq!(list, [1, 2, 3]);
+    /// ```
+    pub fn as_single_slice_mut(&mut self) -> Option<&mut [T]> {
+        match self.chunks.len() {
+            0 => Some(&mut []),
+            1 => match self.chunks[0].as_mut_slices() {
+                (head, []) => Some(head),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
-    /// Provides a cursor at the back element.
+    /// Creates a list by repeating the elements of this one `n` times, like [`slice::repeat`].
     ///
-    /// The cursor is pointing to the “ghost” non-element if the list is empty.
-    #[inline]
-    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N> {
-        CursorMut::from_back(self)
-    }
-
-    fn search_target(&mut self, mut index: usize) -> Option<SearchTarget> {
-        if index >= self.len() {
-            return None;
+    /// The copies are cloned a contiguous run at a time into packed chunks,
+    /// whatever the layout of the chunks of this list.
+    ///
+    /// # Panics
+    /// - Panics if the length of the result would overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let pattern: ArrayList<_, 4> = ArrayList::from(['.', '#', '#']);
+    ///
+    /// assert_eq!(pattern.repeat(3), ['.', '#', '#', '.', '#', '#', '.', '#', '#']);
+    /// assert!(pattern.repeat(0).is_empty());
+    /// ```
+    #[track_caller]
+    pub fn repeat(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let len = self.len.checked_mul(n).expect("capacity overflow");
+
+        let mut list = Self::new();
+        if len == 0 {
+            return list;
         }
 
-        if index <= self.len() / 2 {
-            return self
-                .chunks
-                .iter()
-                .position(|chunk| {
-                    if index < chunk.len() {
-                        return true;
-                    }
+        list.chunks.reserve_exact(len.div_ceil(N));
 
-                    index -= chunk.len();
-                    false
-                })
-                .map(|chunk_index| SearchTarget {
-                    chunk_index,
-                    target_index: index,
-                });
-        }
+        let mut chunk = VecDeque::new();
+        for mut run in (0..n).flat_map(|_| self.iter().as_chunks()) {
+            while !run.is_empty() {
+                if chunk.capacity() == 0 {
+                    chunk.reserve_exact(N);
+                }
 
-        let mut remaining_len = self.len();
-        self.chunks
-            .iter_mut()
-            .rposition(|chunk| {
-                remaining_len -= chunk.len();
+                let (head, tail) = run.split_at((N - chunk.len()).min(run.len()));
+                chunk.extend(head.iter().cloned());
+                run = tail;
 
-                if index + 1 > remaining_len {
-                    index -= remaining_len;
-                    return true;
+                if chunk.len() == N {
+                    list.chunks.push_back(core::mem::take(&mut chunk));
+                    list.counters.allocation();
                 }
+            }
+        }
 
-                false
-            })
-            .map(|chunk_index| SearchTarget {
-                chunk_index,
-                target_index: index,
-            })
-    }
-}
-
-#[derive(Debug, Default)]
-struct SearchTarget {
-    chunk_index: usize,
-    target_index: usize,
-}
+        if !chunk.is_empty() {
+            list.chunks.push_back(chunk);
+            list.counters.allocation();
+        }
 
-impl<T: Clone, const N: usize> Clone for ArrayList<T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
-    fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        list.len = len;
+        list
     }
-}
 
-impl<T, const N: usize, const M: usize> PartialEq<[T; M]> for ArrayList<T, N>
-where
-    T: PartialEq,
-    Usize<N>: ChunkCapacity,
-{
-    fn eq(&self, other: &[T; M]) -> bool {
-        self.len() == other.len() && self.iter().eq(other)
-    }
-}
+    /// Clones the elements into an array, if the list holds exactly `M` of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 2> = ArrayList::new();
+    /// list.extend([0xca, 0xfe]);
+    /// assert_eq!(list.to_array::<4>(), None);
+    ///
+    /// list.extend([0xba, 0xbe]);
+    /// assert_eq!(list.to_array(), Some([0xca, 0xfe, 0xba, 0xbe]));
+    /// assert_eq!(<[u8; 4]>::try_from(list), Ok([0xca, 0xfe, 0xba, 0xbe]));
+    /// ```
+    pub fn to_array<const M: usize>(&self) -> Option<[T; M]>
+    where
+        T: Clone,
+    {
+        if self.len != M {
+            return None;
+        }
 
-impl<T, const N: usize> PartialEq<&[T]> for ArrayList<T, N>
-where
-    T: PartialEq,
-    Usize<N>: ChunkCapacity,
-{
-    fn eq(&self, other: &&[T]) -> bool {
-        self.len() == other.len() && self.iter().eq(other.iter())
+        let mut values = self.iter();
+        Some(core::array::from_fn(|_| values.next().unwrap().clone()))
     }
-}
 
-impl<T, const N: usize> PartialEq<[T]> for ArrayList<T, N>
-where
-    T: PartialEq,
-    Usize<N>: ChunkCapacity,
-{
-    fn eq(&self, other: &[T]) -> bool {
-        self.len() == other.len() && self.iter().eq(other)
+    /// Copies the elements from the front into `dst`, a contiguous run at a time,
+    /// until either runs out, and returns how many were written.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<u8, 2> = ArrayList::from([1, 2, 3, 4, 5]);
+    ///
+    /// let mut buffer = [0; 3];
+    /// assert_eq!(list.copy_to_slice(&mut buffer), 3);
+    /// assert_eq!(buffer, [1, 2, 3]);
+    ///
+    /// let mut buffer = [0; 8];
+    /// assert_eq!(list.copy_to_slice(&mut buffer), 5);
+    /// assert_eq!(buffer, [1, 2, 3, 4, 5, 0, 0, 0]);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        self.write_runs(dst, <[T]>::copy_from_slice)
     }
-}
 
-impl<T, const N: usize> PartialEq for ArrayList<T, N>
-where
-    T: PartialEq,
-    Usize<N>: ChunkCapacity,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.len() == other.len() && self.iter().eq(other)
+    /// Clones the elements from the front into `dst`, a contiguous run at a time,
+    /// until either runs out, and returns how many were written.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<String, 2> = ArrayList::from(["a".into(), "b".into(), "c".into()]);
+    ///
+    /// let mut buffer = vec![String::new(); 2];
+    /// assert_eq!(list.clone_into_slice(&mut buffer), 2);
+    /// assert_eq!(buffer, ["a", "b"]);
+    /// ```
+    pub fn clone_into_slice(&self, dst: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        self.write_runs(dst, <[T]>::clone_from_slice)
     }
-}
 
-impl<T, const N: usize> Eq for ArrayList<T, N>
-where
-    T: Eq,
-    Usize<N>: ChunkCapacity,
-{
-}
+    fn write_runs(&self, dst: &mut [T], write: impl Fn(&mut [T], &[T])) -> usize {
+        let mut written = 0;
+        for run in self.iter().as_chunks() {
+            let n = run.len().min(dst.len() - written);
+            write(&mut dst[written..written + n], &run[..n]);
+            written += n;
 
-impl<T, const N: usize> PartialOrd for ArrayList<T, N>
-where
-    T: PartialOrd,
-    Usize<N>: ChunkCapacity,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.iter().partial_cmp(other)
-    }
-}
+            if written == dst.len() {
+                break;
+            }
+        }
 
-impl<T, const N: usize> Ord for ArrayList<T, N>
-where
-    T: Ord,
-    Usize<N>: ChunkCapacity,
-{
-    #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.iter().cmp(other)
+        written
     }
-}
 
-impl<T, const N: usize> Hash for ArrayList<T, N>
-where
-    T: Hash,
-    Usize<N>: ChunkCapacity,
-{
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        state.write_usize(self.len());
-        self.iter().for_each(|v| v.hash(state));
+    /// Returns the index of the first occurrence of `pattern` as a contiguous run of elements,
+    /// wherever it falls across chunk boundaries, or `0` if `pattern` is empty.
+    ///
+    /// The search follows Knuth-Morris-Pratt, taking O(len + pattern length) comparisons.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<u8, 4> = ArrayList::from(*b"GET / HTTP/1.1\r\nHost: x\r\n\r\n");
+    ///
+    /// assert_eq!(list.find_subsequence(b"\r\n"), Some(14));
+    /// assert_eq!(list.find_subsequence(b"\r\n\r\n"), Some(23));
+    /// assert_eq!(list.find_subsequence(b"POST"), None);
+    /// ```
+    pub fn find_subsequence(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let pattern: Vec<&T> = pattern.iter().collect();
+        find_pattern(self.iter(), &pattern)
     }
-}
 
-impl<T, const N: usize> std::fmt::Debug for ArrayList<T, N>
-where
-    T: std::fmt::Debug,
-    Usize<N>: ChunkCapacity,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(self.chunks.iter()).finish()
+    /// Returns the index of the last occurrence of `pattern` as a contiguous run of elements,
+    /// wherever it falls across chunk boundaries, or the length of the list if `pattern` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<u8, 4> = ArrayList::from(*b"a/b/c");
+    ///
+    /// assert_eq!(list.rfind_subsequence(b"/"), Some(3));
+    /// assert_eq!(list.rfind_subsequence(b""), Some(5));
+    /// ```
+    pub fn rfind_subsequence(&self, pattern: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let reversed: Vec<&T> = pattern.iter().rev().collect();
+        let start = find_pattern(self.iter().rev(), &reversed)?;
+        Some(self.len - start - pattern.len())
     }
-}
-
-impl<T, const N: usize> IntoIterator for ArrayList<T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
-    type Item = T;
-    type IntoIter = IntoIter<T, N>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::from_list(self)
+    /// Checks if this list and `other` have the same length and their elements are pairwise
+    /// related by `eq`, whatever their types and chunk capacities.
+    ///
+    /// The runs of the two lists are aligned, so that `eq` is called over pairs of slices
+    /// rather than through two element-wise iterators.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let records: ArrayList<_, 2> = ArrayList::from([(1, "one"), (2, "two"), (3, "three")]);
+    /// let keys: ArrayList<_, 4> = ArrayList::from([1, 2, 3]);
+    ///
+    /// assert!(records.eq_by(&keys, |(id, _), key| id == key));
+    /// ```
+    pub fn eq_by<U, const M: usize>(
+        &self,
+        other: &ArrayList<U, M>,
+        mut eq: impl FnMut(&T, &U) -> bool,
+    ) -> bool
+    where
+        Usize<M>: ChunkCapacity,
+    {
+        self.len() == other.len()
+            && zip_runs(self.iter().as_chunks(), other.iter().as_chunks(), |a, b| {
+                (!a.iter().zip(b).all(|(a, b)| eq(a, b))).then_some(())
+            })
+            .is_none()
     }
-}
-
-impl<'a, T, const N: usize> IntoIterator for &'a ArrayList<T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T, N>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        Iter::from_list(self)
+    /// Compares this list and `other` lexicographically, ordering their elements pairwise
+    /// with `cmp`, whatever their types and chunk capacities.
+    ///
+    /// When one list is a prefix of the other, the shorter one is the lesser.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let names: ArrayList<_, 2> = ArrayList::from(["ada", "Bob", "cy"]);
+    /// let upper: ArrayList<_, 4> = ArrayList::from(["ADA", "BOB"]);
+    ///
+    /// let ordering = names.cmp_by(&upper, |a, b| a.to_uppercase().as_str().cmp(b));
+    /// assert_eq!(ordering, Ordering::Greater);
+    /// ```
+    pub fn cmp_by<U, const M: usize>(
+        &self,
+        other: &ArrayList<U, M>,
+        mut cmp: impl FnMut(&T, &U) -> Ordering,
+    ) -> Ordering
+    where
+        Usize<M>: ChunkCapacity,
+    {
+        zip_runs(self.iter().as_chunks(), other.iter().as_chunks(), |a, b| {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| cmp(a, b))
+                .find(|ordering| ordering.is_ne())
+        })
+        .unwrap_or_else(|| self.len().cmp(&other.len()))
     }
-}
 
-impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayList<T, N>
-where
-    Usize<N>: ChunkCapacity,
-{
+    /// Provides an iterator over list's elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::new();
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter::from_list(self)
+    }
+
+    /// Provides a mutable iterator over list's elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::new();
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut::from_list(self)
+    }
+
+    /// Replaces every element with the value `f` computes from it, in order.
+    ///
+    /// The elements are rewritten a contiguous run at a time, with a plain loop over each slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([1, 2, 3]);
+    ///
+    /// list.map_in_place(|v| v * 10);
+    /// assert_eq!(list, [10, 20, 30]);
+    /// ```
+    pub fn map_in_place(&mut self, mut f: impl FnMut(&T) -> T) {
+        for chunk in self.chunks.iter_mut() {
+            let (head, tail) = chunk.as_mut_slices();
+            for run in [head, tail] {
+                for value in run {
+                    *value = f(value);
+                }
+            }
+        }
+    }
+
+    /// Replaces every element with the value `f` computes from it, in order,
+    /// stopping at the first error.
+    ///
+    /// # Errors
+    /// Returns the first error `f` returns. The elements before the failing one
+    /// keep their new value, the others are left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 2> = ArrayList::from([100, 120, 140]);
+    ///
+    /// let result = list.try_map_in_place(|v| v.checked_add(120).ok_or(*v));
+    /// assert_eq!(result, Err(140));
+    /// assert_eq!(list, [220, 240, 140]);
+    /// ```
+    pub fn try_map_in_place<E>(&mut self, mut f: impl FnMut(&T) -> Result<T, E>) -> Result<(), E> {
+        for chunk in self.chunks.iter_mut() {
+            let (head, tail) = chunk.as_mut_slices();
+            for run in [head, tail] {
+                for value in run {
+                    *value = f(value)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the list, turning each element into the value `f` computes from it.
+    ///
+    /// The new list has the same chunks layout, each chunk is mapped into one of the same length,
+    /// so no element is moved between chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([1, 2, 3]);
+    /// let list = list.map(|v| v.to_string());
+    ///
+    /// assert_eq!(list, ["1", "2", "3"].map(String::from));
+    /// ```
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> ArrayList<U, N> {
+        let mut out = ArrayList::<U, N>::new();
+        out.chunks.reserve_exact(self.chunks.len());
+
+        for chunk in self.chunks {
+            let mut mapped = VecDeque::with_capacity(N);
+            mapped.extend(chunk.into_iter().map(&mut f));
+            out.chunks.push_back(mapped);
+        }
+
+        out.len = self.len;
+        out
+    }
+
+    /// Returns an iterator over the elements in batches of exactly `k` elements,
+    /// regardless of the chunk size. The last elements are left out if the length of the list
+    /// is not a multiple of `k`, they can be retrieved from [`Batches::remainder`].
+    ///
+    /// # Panics
+    /// - Panics if `k` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let samples: ArrayList<_, 3> = ArrayList::from_iter(0..10);
+    ///
+    /// let mut block = [0; 4];
+    /// let mut sums = Vec::new();
+    /// for batch in samples.batches(4) {
+    ///     batch.copy_to_slice(&mut block);
+    ///     sums.push(block.iter().sum::<i32>());
+    /// }
+    ///
+    /// assert_eq!(sums, [6, 22]);
+    /// assert!(samples.batches(4).remainder().eq(&[8, 9]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn batches(&self, k: usize) -> Batches<'_, T, N> {
+        Batches::new(self, k)
+    }
+
+    /// Returns a parallel iterator over the contiguous runs of elements, one or two per chunk,
+    /// so that each worker can hand whole slices to slice-based code.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let list: ArrayList<i64, 4> = ArrayList::from_iter(0..10);
+    ///
+    /// let sum: i64 = list.par_chunks().map(|run| run.iter().sum::<i64>()).sum();
+    /// assert_eq!(sum, 45);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_chunks(&self) -> impl rayon::iter::ParallelIterator<Item = &[T]>
+    where
+        T: Sync,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        (&self.chunks).into_par_iter().flat_map_iter(|chunk| {
+            let (head, tail) = chunk.as_slices();
+            [head, tail].into_iter().filter(|run| !run.is_empty())
+        })
+    }
+
+    /// Returns a parallel iterator over the contiguous runs of elements, one or two per chunk,
+    /// that allows modifying each of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let mut list: ArrayList<i64, 4> = ArrayList::from_iter(0..10);
+    ///
+    /// list.par_chunks_mut().for_each(|run| run.iter_mut().for_each(|v| *v *= 2));
+    /// assert!(list.iter().eq(&[0, 2, 4, 6, 8, 10, 12, 14, 16, 18]));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_chunks_mut(&mut self) -> impl rayon::iter::ParallelIterator<Item = &mut [T]>
+    where
+        T: Send,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        (&mut self.chunks).into_par_iter().flat_map_iter(|chunk| {
+            let (head, tail) = chunk.as_mut_slices();
+            [head, tail].into_iter().filter(|run| !run.is_empty())
+        })
+    }
+
+    /// Divides the list into two mutable views, one before the element at `index` and one from it on.
+    ///
+    /// No chunk is moved or split: the views borrow the elements in place,
+    /// so they can be handed to different scoped threads.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    ///
+    /// # Examples
+    /// ```
+    /// use std::thread;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let (mut head, mut tail) = list.split_at_mut(3);
+    /// thread::scope(|scope| {
+    ///     scope.spawn(|| head.iter_mut().for_each(|value| *value += 10));
+    ///     scope.spawn(|| tail.iter_mut().for_each(|value| *value *= 10));
+    /// });
+    ///
+    /// assert_eq!(list, [10, 11, 12, 30, 40]);
+    /// ```
+    #[track_caller]
+    pub fn split_at_mut(&mut self, index: usize) -> (ViewMut<'_, T, N>, ViewMut<'_, T, N>) {
+        ViewMut::split(self, index)
+    }
+
+    /// Provides a view over the elements in `range`, indexed from the start of the range.
+    ///
+    /// # Panics
+    /// - Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let view = list.view(1..4);
+    /// assert_eq!(view.len(), 3);
+    /// assert_eq!(view.get(0), Some(&1));
+    /// assert_eq!(view.back(), Some(&3));
+    /// assert!(view.iter().eq(&[1, 2, 3]));
+    /// ```
+    #[track_caller]
+    pub fn view(&self, range: impl RangeBounds<usize>) -> SubListView<'_, T, N> {
+        let range = self.resolve_range(range);
+        SubListView::new(self, range)
+    }
+
+    /// Provides a mutable view over the elements in `range`, indexed from the start of the range.
+    ///
+    /// # Panics
+    /// - Panics if the starting point is greater than the end point or if the end point
+    ///   is greater than the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// let mut view = list.view_mut(2..);
+    /// *view.front_mut().unwrap() = 20;
+    /// view.iter_mut().skip(1).for_each(|value| *value *= 10);
+    ///
+    /// assert_eq!(list, [0, 1, 20, 30, 40]);
+    /// ```
+    #[track_caller]
+    pub fn view_mut(&mut self, range: impl RangeBounds<usize>) -> ViewMut<'_, T, N> {
+        let range = self.resolve_range(range);
+        ViewMut::range(self, range)
+    }
+
+    /// Moves the elements into a list with chunks of a different capacity, keeping their order.
+    ///
+    /// Elements are moved in bulk, a run at a time, and the new chunks are packed full.
+    /// When `M` equals `N` the chunks are taken over as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::new();
+    /// for value in (0..10).rev() {
+    ///     list.insert(0, value);
+    /// }
+    ///
+    /// let list: ArrayList<_, 8> = list.rechunk();
+    /// assert_eq!(list, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn rechunk<const M: usize>(self) -> ArrayList<T, M>
+    where
+        Usize<M>: ChunkCapacity,
+    {
+        let len = self.len;
+        let mut out = ArrayList::<T, M>::new();
+
+        if M == N {
+            out.chunks = self.chunks;
+            out.len = len;
+            return out;
+        }
+
+        out.chunks.reserve_exact(len.div_ceil(M));
+        for mut chunk in self.chunks {
+            while !chunk.is_empty() {
+                if out.chunks.back().is_none_or(|back| back.len() >= M) {
+                    out.chunks.push_back(VecDeque::with_capacity(M));
+                }
+
+                let back = out.chunks.back_mut().unwrap();
+                let n = (M - back.len()).min(chunk.len());
+                back.extend(chunk.drain(..n));
+            }
+        }
+
+        out.len = len;
+        out
+    }
+
+    /// Splits the list into runs of adjacent elements for which `same` returns `true`,
+    /// yielding each run as a new list.
+    ///
+    /// `same` is called with each pair of consecutive elements of a run.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let events: ArrayList<_, 4> = ArrayList::from([1, 2, 3, 10, 11, 30]);
+    ///
+    /// let sessions: Vec<ArrayList<_, 4>> = events.group_adjacent_by(|a, b| b - a < 5).collect();
+    /// assert_eq!(sessions, [&[1, 2, 3][..], &[10, 11], &[30]]);
+    /// ```
+    pub fn group_adjacent_by<F>(self, same: F) -> GroupAdjacentBy<T, N, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        GroupAdjacentBy::new(self, same)
+    }
+
+    /// Reorders the elements of the list so that the element at index `i` is the one
+    /// that was at index `permutation[i]`, in O(n).
+    ///
+    /// This is the order produced by an argsort, so a list can be sorted by keys stored elsewhere.
+    /// The chunks layout is left untouched, only elements are swapped.
+    ///
+    /// # Panics
+    /// - Panics if `permutation` is not a permutation of `0..self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from(['a', 'b', 'c', 'd']);
+    /// let keys = [3, 1, 4, 2];
+    ///
+    /// let mut order: Vec<usize> = (0..keys.len()).collect();
+    /// order.sort_by_key(|&i| keys[i]);
+    ///
+    /// list.apply_permutation(&order);
+    /// assert_eq!(list, ['b', 'd', 'a', 'c']);
+    /// ```
+    #[track_caller]
+    pub fn apply_permutation(&mut self, permutation: &[usize]) {
+        let mut visited = self.check_permutation(permutation);
+        let mut values: Vec<&mut T> = self.iter_mut().collect();
+        permute_values(&mut values, permutation, &mut visited);
+    }
+
+    /// Reorders the elements of the list so that the element at index `i` is moved
+    /// to index `permutation[i]`, in O(n).
+    ///
+    /// This undoes [`apply_permutation`](Self::apply_permutation) with the same `permutation`.
+    /// The chunks layout is left untouched, only elements are swapped.
+    ///
+    /// # Panics
+    /// - Panics if `permutation` is not a permutation of `0..self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from(['a', 'b', 'c', 'd']);
+    ///
+    /// list.apply_inverse_permutation(&[2, 0, 3, 1]);
+    /// assert_eq!(list, ['b', 'd', 'a', 'c']);
+    /// ```
+    #[track_caller]
+    pub fn apply_inverse_permutation(&mut self, permutation: &[usize]) {
+        let mut visited = self.check_permutation(permutation);
+        let mut values: Vec<&mut T> = self.iter_mut().collect();
+
+        for start in 0..values.len() {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            let mut j = permutation[start];
+            while j != start {
+                swap_values(&mut values, start, j);
+                visited[j] = true;
+                j = permutation[j];
+            }
+        }
+    }
+
+    /// Panics unless `permutation` is a permutation of `0..self.len()`,
+    /// returning a cleared table of visited indices to follow its cycles with.
+    #[track_caller]
+    fn check_permutation(&self, permutation: &[usize]) -> Vec<bool> {
+        let len = self.len;
+        assert_eq!(
+            permutation.len(),
+            len,
+            "permutation length mismatch: {} should be == len (is {len})",
+            permutation.len()
+        );
+
+        let mut seen = vec![false; len];
+        for &index in permutation {
+            assert!(
+                index < len,
+                "not a permutation: index {index} should be < len (is {len})"
+            );
+            assert!(
+                !core::mem::replace(&mut seen[index], true),
+                "not a permutation: index {index} is repeated"
+            );
+        }
+
+        seen.fill(false);
+        seen
+    }
+
+    /// Shuffles the elements of the list in place using the Fisher–Yates algorithm.
+    ///
+    /// The chunks layout is left untouched, only elements are swapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    /// list.shuffle(&mut rand::rng());
+    ///
+    /// let mut values: Vec<_> = list.into_iter().collect();
+    /// values.sort();
+    /// assert_eq!(values, [0, 1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let offsets: Vec<usize> = self
+            .chunks
+            .iter()
+            .scan(0, |offset, chunk| {
+                let start = *offset;
+                *offset += chunk.len();
+                Some(start)
+            })
+            .collect();
+
+        let locate = |index: usize| {
+            let chunk_index = offsets.partition_point(|&offset| offset <= index) - 1;
+            SearchTarget {
+                chunk_index,
+                target_index: index - offsets[chunk_index],
+            }
+        };
+
+        for i in (1..self.len()).rev() {
+            let j = rng.random_range(0..=i);
+            self.swap_targets(locate(i), locate(j));
+        }
+    }
+
+    /// Returns a reference to a random element of the list, or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    /// assert!(list.choose(&mut rand::rng()).is_some_and(|v| (0..5).contains(v)));
+    ///
+    /// let list: ArrayList<i32, 2> = ArrayList::new();
+    /// assert_eq!(list.choose(&mut rand::rng()), None);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn choose<R>(&self, rng: &mut R) -> Option<&T>
+    where
+        R: rand::Rng + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.get(rng.random_range(0..self.len()))
+    }
+
+    /// Returns a mutable reference to a random element of the list, or `None` if the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([0, 0, 0]);
+    /// if let Some(value) = list.choose_mut(&mut rand::rng()) {
+    ///     *value = 1;
+    /// }
+    ///
+    /// assert_eq!(list.iter().sum::<i32>(), 1);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn choose_mut<R>(&mut self, rng: &mut R) -> Option<&mut T>
+    where
+        R: rand::Rng + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.get_mut(rng.random_range(0..self.len()))
+    }
+
+    /// Provides a `Debug` view of the list's chunks.
+    ///
+    /// The `Debug` implementation of `ArrayList` prints the elements as a flat list,
+    /// use this adapter to inspect how the elements are laid out in chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2, 3, 4]);
+    ///
+    /// assert_eq!(format!("{list:?}"), "[0, 1, 2, 3, 4]");
+    /// assert_eq!(format!("{:?}", list.debug_chunks()), "[[0, 1], [2, 3], [4]]");
+    /// ```
+    #[inline]
+    pub fn debug_chunks(&self) -> DebugChunks<'_, T, N> {
+        DebugChunks::from_list(self)
+    }
+
+    /// Returns how many chunks the list allocated, split, merged and spilled since it was created
+    /// or since [`reset_stats`](Self::reset_stats) was last called, along with how its chunks
+    /// are filled right now.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 4> = ArrayList::from([0, 1, 2, 3]);
+    /// list.insert(1, 42);
+    ///
+    /// let stats = list.stats();
+    /// assert_eq!((stats.allocations, stats.spills), (2, 1));
+    /// assert_eq!(stats.average_fill(), 5.0 / 8.0);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        self.counters
+            .snapshot(self.chunks.len(), self.len, self.chunks.len() * N)
+    }
+
+    /// Sets the counters returned by [`stats`](Self::stats) back to zero.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.counters = Counters::new();
+    }
+
+    /// Provides a `Display` view of the list's elements separated by `", "`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    ///
+    /// assert_eq!(list.display().to_string(), "0, 1, 2");
+    /// ```
+    #[inline]
+    pub fn display(&self) -> Display<'_, T, N> {
+        Display::from_list(self, ", ")
+    }
+
+    /// Provides a `Display` view of the list's elements separated by `separator`.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    ///
+    /// assert_eq!(list.display_with(" -> ").to_string(), "0 -> 1 -> 2");
+    /// ```
+    #[inline]
+    pub fn display_with<'a>(&'a self, separator: &'a str) -> Display<'a, T, N> {
+        Display::from_list(self, separator)
+    }
+
+    /// Provides a cursor at the front element.
+    ///
+    /// The cursor is pointing to the “ghost” non-element if the list is empty.
+    #[inline]
+    pub fn cursor_front(&self) -> Cursor<'_, T, N> {
+        Cursor::from_front(self)
+    }
+
+    /// Provides a cursor at the back element.
+    ///
+    /// The cursor is pointing to the “ghost” non-element if the list is empty.
+    #[inline]
+    pub fn cursor_back(&self) -> Cursor<'_, T, N> {
+        Cursor::from_back(self)
+    }
+
+    /// Provides a cursor at the front element.
+    ///
+    /// The cursor is pointing to the “ghost” non-element if the list is empty.
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut::from_front(self)
+    }
+
+    /// Provides a cursor at the back element.
+    ///
+    /// The cursor is pointing to the “ghost” non-element if the list is empty.
+    #[inline]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut::from_back(self)
+    }
+
+    /// Provides a cursor at a position taken from another cursor.
+    ///
+    /// See [`CursorPosition`] for how positions taken before the list was edited are restored.
+    pub fn cursor_from_position(&self, position: CursorPosition) -> Cursor<'_, T, N> {
+        let mut cursor = self.cursor_front();
+        cursor.seek_to(position.index().unwrap_or(self.len()));
+        cursor
+    }
+
+    /// Provides a mutable cursor at a position taken from another cursor.
+    ///
+    /// See [`CursorPosition`] for how positions taken before the list was edited are restored.
+    pub fn cursor_mut_from_position(&mut self, position: CursorPosition) -> CursorMut<'_, T, N> {
+        let len = self.len();
+        let mut cursor = self.cursor_front_mut();
+        cursor.seek_to(position.index().unwrap_or(len));
+        cursor
+    }
+
+    /// Provides a cursor at the first element that satisfies `predicate`,
+    /// or at the “ghost” non-element if there is none.
+    ///
+    /// The elements are scanned a chunk at a time, see [`Cursor::seek_while`].
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([1, 3, 4, 5, 6]);
+    ///
+    /// let cursor = list.cursor_find(|v| v % 2 == 0);
+    /// assert_eq!(cursor.index(), Some(2));
+    /// assert_eq!(cursor.peek_next(), Some(&5));
+    ///
+    /// assert_eq!(list.cursor_find(|&v| v > 6).index(), None);
+    /// ```
+    pub fn cursor_find(&self, mut predicate: impl FnMut(&T) -> bool) -> Cursor<'_, T, N> {
+        let mut cursor = self.cursor_front();
+        cursor.seek_while(|value| !predicate(value));
+        cursor
+    }
+
+    /// Provides a mutable cursor at the first element that satisfies `predicate`,
+    /// or at the “ghost” non-element if there is none.
+    ///
+    /// The elements are scanned a chunk at a time, see [`CursorMut::seek_while`].
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([1, 3, 4, 5, 6]);
+    ///
+    /// let mut cursor = list.cursor_find_mut(|v| v % 2 == 0);
+    /// cursor.insert_before(0);
+    ///
+    /// assert_eq!(list, [1, 3, 0, 4, 5, 6]);
+    /// ```
+    pub fn cursor_find_mut(
+        &mut self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> CursorMut<'_, T, N> {
+        let mut cursor = self.cursor_front_mut();
+        cursor.seek_while(|value| !predicate(value));
+        cursor
+    }
+
+    /// Provides a cursor resting in the gap right before the element at `index`,
+    /// or at the back of the list if `index` equals its length.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[deprecated(note = "use `edit_at`, whose `GapEditor` is the gap-based cursor")]
+    #[track_caller]
+    pub fn cursor_gap(&mut self, index: usize) -> GapEditor<'_, T, N> {
+        self.edit_at(index)
+    }
+
+    /// Provides two cursors at the front of the elements before `split_index` and of the ones
+    /// from it on, which can change the elements on their side at the same time.
+    ///
+    /// Like the views of [`split_at_mut`](Self::split_at_mut) they are built on, the cursors
+    /// can't insert or remove elements.
+    ///
+    /// # Panics
+    /// - Panics if `split_index` is out of bounds (greater than the list's current length).
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<_, 2> = ArrayList::from([1, 5, 3, 4, 2, 6]);
+    ///
+    /// // moves the smaller of each pair of elements to the left side
+    /// let (mut left, mut right) = list.cursor_pair_mut(3);
+    /// while let (Some(a), Some(b)) = (left.current(), right.current()) {
+    ///     if a > b {
+    ///         std::mem::swap(a, b);
+    ///     }
+    ///     left.move_next();
+    ///     right.move_next();
+    /// }
+    ///
+    /// assert_eq!(list, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[track_caller]
+    pub fn cursor_pair_mut(
+        &mut self,
+        split_index: usize,
+    ) -> (ViewCursorMut<'_, T, N>, ViewCursorMut<'_, T, N>) {
+        let (head, tail) = ViewMut::split(self, split_index);
+        (
+            ViewCursorMut::from_front(head),
+            ViewCursorMut::from_front(tail),
+        )
+    }
+
+    /// Provides a gap-buffer-like editor resting right before the element at `index`,
+    /// or at the back of the list if `index` equals its length.
+    ///
+    /// The editor is also the gap-based cursor of the list: it rests strictly between
+    /// two elements, so unlike [`CursorMut`] it has no “ghost” position to insert around.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut text: ArrayList<char, 8> = "hello world".chars().collect();
+    ///
+    /// let mut editor = text.edit_at(5);
+    /// for c in ", dear".chars() {
+    ///     editor.insert(c);
+    /// }
+    /// drop(editor);
+    ///
+    /// assert_eq!(String::from_iter(text), "hello, dear world");
+    /// ```
+    #[track_caller]
+    pub fn edit_at(&mut self, index: usize) -> GapEditor<'_, T, N> {
+        GapEditor::new(self, index)
+    }
+
+    /// Provides a cloneable handle that appends to the back of the list,
+    /// meant to be shared among producer threads.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::thread;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<usize, 4> = ArrayList::new();
+    ///
+    /// thread::scope(|scope| {
+    ///     let appender = list.appender();
+    ///     for producer in 0..4 {
+    ///         let mut appender = appender.clone();
+    ///         scope.spawn(move || appender.extend(producer * 10..producer * 10 + 10));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(list.len(), 40);
+    /// ```
+    pub fn appender(&mut self) -> Appender<'_, T, N> {
+        Appender::new(self)
+    }
+
+    /// Turns the list into an immutable snapshot that can be cheaply cloned and shared across threads.
+    ///
+    /// Every chunk is shared on its own: use [`FrozenArrayList::thaw`] to get a mutable list back,
+    /// which copies each chunk only once it changes it.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::thread;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from([0, 1, 2]);
+    /// let frozen = list.freeze();
+    ///
+    /// let reader = frozen.clone();
+    /// let sum = thread::spawn(move || reader.iter().sum::<i32>());
+    ///
+    /// assert_eq!(sum.join().unwrap(), 3);
+    /// assert_eq!(frozen.len(), 3);
+    /// ```
+    pub fn freeze(self) -> FrozenArrayList<T, N> {
+        FrozenArrayList::from_list(self)
+    }
+
+    /// Wraps the list so that every change made to it is reported to `observer`.
+    ///
+    /// See [`ListObserver`] for an example.
+    pub fn observe(self, observer: impl ListObserver + 'static) -> ObservedArrayList<T, N> {
+        let mut observed = ObservedArrayList::from_list(self);
+        observed.set_observer(observer);
+        observed
+    }
+
+    /// Wraps the list along with a hash index of its values, making
+    /// [`contains`](IndexedArrayList::contains) and [`position`](IndexedArrayList::position) O(1).
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut seen = ArrayList::<String, 4>::new().indexed();
+    /// for word in ["to", "be", "or", "not", "to", "be"] {
+    ///     if !seen.contains(word) {
+    ///         seen.push_back(word.to_string());
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(seen.len(), 4);
+    /// assert_eq!(seen.position("not"), Some(3));
+    /// ```
+    #[cfg(feature = "value_index")]
+    pub fn indexed(self) -> IndexedArrayList<T, N>
+    where
+        T: Clone + Eq + Hash,
+    {
+        IndexedArrayList::from_list(self)
+    }
+
+    /// Wraps the list so that every chunk is wiped with [`Zeroize`](::zeroize::Zeroize)
+    /// before it's freed, all of them once dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut key = ArrayList::<u8, 16>::from_iter(0..32).secret();
+    /// key.pop_back();
+    /// assert_eq!(key.len(), 31);
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn secret(self) -> SecretArrayList<T, N>
+    where
+        T: ::zeroize::Zeroize,
+    {
+        SecretArrayList::from_list(self)
+    }
+
+    fn search_target(&self, mut index: usize) -> Option<SearchTarget> {
+        if index >= self.len() {
+            return None;
+        }
+
+        if index <= self.len() / 2 {
+            return self
+                .chunks
+                .iter()
+                .position(|chunk| {
+                    if index < chunk.len() {
+                        return true;
+                    }
+
+                    index -= chunk.len();
+                    false
+                })
+                .map(|chunk_index| SearchTarget {
+                    chunk_index,
+                    target_index: index,
+                });
+        }
+
+        let mut remaining_len = self.len();
+        self.chunks
+            .iter()
+            .rposition(|chunk| {
+                remaining_len -= chunk.len();
+
+                if index + 1 > remaining_len {
+                    index -= remaining_len;
+                    return true;
+                }
+
+                false
+            })
+            .map(|chunk_index| SearchTarget {
+                chunk_index,
+                target_index: index,
+            })
+    }
+
+    /// Like [`Self::search_target`], but for a cursor resting at `index` the chunks are walked
+    /// starting from the cursor's own chunk, whenever that is closer than either end of the list.
+    fn search_target_from(
+        &self,
+        index: usize,
+        chunk_index: usize,
+        inner_index: usize,
+        target: usize,
+    ) -> Option<SearchTarget> {
+        if target >= self.len() {
+            return None;
+        }
+
+        let (mut chunk_index, mut chunk_start) = if index < self.len() {
+            (chunk_index, index - inner_index)
+        } else {
+            (self.chunks.len(), self.len())
+        };
+
+        if target.abs_diff(chunk_start) > target.min(self.len() - target) {
+            return self.search_target(target);
+        }
+
+        while target < chunk_start {
+            chunk_index -= 1;
+            chunk_start -= self.chunks[chunk_index].len();
+        }
+
+        while target >= chunk_start + self.chunks[chunk_index].len() {
+            chunk_start += self.chunks[chunk_index].len();
+            chunk_index += 1;
+        }
+
+        Some(SearchTarget {
+            chunk_index,
+            target_index: target - chunk_start,
+        })
+    }
+
+    /// Counts the elements satisfying `predicate` from the one at `inner_index` of the chunk
+    /// at `chunk_index` onward, scanning a chunk at a time. Returns the count along with
+    /// where the first element that doesn't satisfy it lies, or the end of the chunks.
+    fn scan_forward(
+        &self,
+        mut chunk_index: usize,
+        mut inner_index: usize,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> (usize, SearchTarget) {
+        let mut count = 0;
+        while let Some(chunk) = self.chunks.get(chunk_index) {
+            if let Some(n) = chunk
+                .range(inner_index..)
+                .position(|value| !predicate(value))
+            {
+                let target = SearchTarget {
+                    chunk_index,
+                    target_index: inner_index + n,
+                };
+                return (count + n, target);
+            }
+
+            count += chunk.len() - inner_index;
+            chunk_index += 1;
+            inner_index = 0;
+        }
+
+        let target = SearchTarget {
+            chunk_index,
+            target_index: 0,
+        };
+        (count, target)
+    }
+
+    /// Like [`Self::scan_forward`], but going backward from the element at `inner_index`
+    /// of the chunk at `chunk_index`. There is no target if all the elements satisfy `predicate`.
+    fn scan_backward(
+        &self,
+        mut chunk_index: usize,
+        mut inner_index: usize,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> (usize, Option<SearchTarget>) {
+        let mut count = 0;
+        loop {
+            let chunk = &self.chunks[chunk_index];
+            if let Some(n) = chunk
+                .range(..=inner_index)
+                .rev()
+                .position(|value| !predicate(value))
+            {
+                let target = SearchTarget {
+                    chunk_index,
+                    target_index: inner_index - n,
+                };
+                return (count + n, Some(target));
+            }
+
+            count += inner_index + 1;
+            if chunk_index == 0 {
+                return (count, None);
+            }
+
+            chunk_index -= 1;
+            inner_index = self.chunks[chunk_index].len() - 1;
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    fn swap_targets(&mut self, a: SearchTarget, b: SearchTarget) {
+        let (lo, hi) = if a.chunk_index <= b.chunk_index {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        if lo.chunk_index == hi.chunk_index {
+            self.chunks[lo.chunk_index].swap(lo.target_index, hi.target_index);
+            return;
+        }
+
+        let mut chunks = self.chunks.range_mut(lo.chunk_index..=hi.chunk_index);
+        let (Some(lo_chunk), Some(hi_chunk)) = (chunks.next(), chunks.next_back()) else {
+            unreachable!()
+        };
+
+        core::mem::swap(
+            &mut lo_chunk[lo.target_index],
+            &mut hi_chunk[hi.target_index],
+        );
+    }
+}
+
+/// Moves the value behind `values[permutation[i]]` to `values[i]`, following the cycles
+/// of the permutation with a cleared table of visited indices.
+fn permute_values<T>(values: &mut [&mut T], permutation: &[usize], visited: &mut [bool]) {
+    for start in 0..values.len() {
+        if visited[start] {
+            continue;
+        }
+
+        visited[start] = true;
+        let mut i = start;
+        while permutation[i] != start {
+            let j = permutation[i];
+            swap_values(values, i, j);
+            visited[j] = true;
+            i = j;
+        }
+    }
+}
+
+/// Swaps the values behind two distinct references of the table.
+fn swap_values<T>(values: &mut [&mut T], a: usize, b: usize) {
+    let (lo, hi) = (a.min(b), a.max(b));
+    let (head, tail) = values.split_at_mut(hi);
+    core::mem::swap(&mut *head[lo], &mut *tail[0]);
+}
+
+/// Moves the element at `i` down the binary heap until both its children are not `less` than it.
+fn sift_down<T>(heap: &mut [T], mut i: usize, less: &mut impl FnMut(&T, &T) -> bool) {
+    loop {
+        let mut smallest = i;
+        for child in [2 * i + 1, 2 * i + 2] {
+            if child < heap.len() && less(&heap[child], &heap[smallest]) {
+                smallest = child;
+            }
+        }
+
+        if smallest == i {
+            return;
+        }
+
+        heap.swap(i, smallest);
+        i = smallest;
+    }
+}
+
+/// Returns the position in `values` where `pattern` first shows up, following Knuth-Morris-Pratt
+/// so that the values are walked once, without ever going back.
+fn find_pattern<'a, T>(values: impl Iterator<Item = &'a T>, pattern: &[&T]) -> Option<usize>
+where
+    T: PartialEq + 'a,
+{
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    // fallback[i] is the length of the longest proper prefix of pattern[..=i] that also ends it
+    let mut fallback = vec![0; pattern.len()];
+    let mut matched = 0;
+    for i in 1..pattern.len() {
+        while matched > 0 && pattern[i] != pattern[matched] {
+            matched = fallback[matched - 1];
+        }
+
+        if pattern[i] == pattern[matched] {
+            matched += 1;
+        }
+
+        fallback[i] = matched;
+    }
+
+    matched = 0;
+    for (index, value) in values.enumerate() {
+        while matched > 0 && pattern[matched] != value {
+            matched = fallback[matched - 1];
+        }
+
+        if pattern[matched] == value {
+            matched += 1;
+        }
+
+        if matched == pattern.len() {
+            return Some(index + 1 - matched);
+        }
+    }
+
+    None
+}
+
+/// Walks the contiguous runs of two sequences side by side, handing `f` slices of the same length
+/// and splitting the longer run of each pair where the shorter one ends.
+/// Stops at the first pair for which `f` returns `Some`, or when either sequence runs out.
+fn zip_runs<'a, 'b, T, U, R>(
+    mut left: impl Iterator<Item = &'a [T]>,
+    mut right: impl Iterator<Item = &'b [U]>,
+    mut f: impl FnMut(&'a [T], &'b [U]) -> Option<R>,
+) -> Option<R>
+where
+    T: 'a,
+    U: 'b,
+{
+    let (mut left_run, mut right_run): (&[T], &[U]) = (&[], &[]);
+    loop {
+        if left_run.is_empty() {
+            left_run = left.next()?;
+        }
+
+        if right_run.is_empty() {
+            right_run = right.next()?;
+        }
+
+        let len = left_run.len().min(right_run.len());
+        let (left_head, left_tail) = left_run.split_at(len);
+        let (right_head, right_tail) = right_run.split_at(len);
+        if let Some(found) = f(left_head, right_head) {
+            return Some(found);
+        }
+
+        (left_run, right_run) = (left_tail, right_tail);
+    }
+}
+
+#[derive(Debug, Default)]
+struct SearchTarget {
+    chunk_index: usize,
+    target_index: usize,
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize, const M: usize> PartialEq<[T; M]> for ArrayList<T, N>
+where
+    T: PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn eq(&self, other: &[T; M]) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+
+impl<T, const N: usize> PartialEq<&[T]> for ArrayList<T, N>
+where
+    T: PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn eq(&self, other: &&[T]) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl<T, const N: usize> PartialEq<[T]> for ArrayList<T, N>
+where
+    T: PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    /// Compares contiguous runs with slice equality, which lowers to `memcmp` for primitives.
+    fn eq(&self, other: &[T]) -> bool {
+        self.len() == other.len()
+            && zip_runs(self.iter().as_chunks(), [other].into_iter(), |a, b| {
+                (a != b).then_some(())
+            })
+            .is_none()
+    }
+}
+
+/// Lists are equal when they hold equal elements in the same order, whatever their chunk capacity.
+///
+/// # Examples
+/// ```
+/// use array_list::ArrayList;
+///
+/// let small: ArrayList<_, 2> = ArrayList::from([1, 2, 3]);
+/// let large: ArrayList<_, 64> = ArrayList::from([1, 2, 3]);
+///
+/// assert_eq!(small, large);
+/// ```
+impl<T, const N: usize, const M: usize> PartialEq<ArrayList<T, M>> for ArrayList<T, N>
+where
+    T: PartialEq,
+    Usize<N>: ChunkCapacity,
+    Usize<M>: ChunkCapacity,
+{
+    /// Compares contiguous runs with slice equality, aligning the runs of the two lists.
+    fn eq(&self, other: &ArrayList<T, M>) -> bool {
+        self.len() == other.len()
+            && zip_runs(self.iter().as_chunks(), other.iter().as_chunks(), |a, b| {
+                (a != b).then_some(())
+            })
+            .is_none()
+    }
+}
+
+impl<T, const N: usize> Eq for ArrayList<T, N>
+where
+    T: Eq,
+    Usize<N>: ChunkCapacity,
+{
+}
+
+impl<T, const N: usize> PartialOrd for ArrayList<T, N>
+where
+    T: PartialOrd,
+    Usize<N>: ChunkCapacity,
+{
+    /// Compares contiguous runs with slice ordering, aligning the runs of the two lists.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        zip_runs(
+            self.iter().as_chunks(),
+            other.iter().as_chunks(),
+            |a, b| match a.partial_cmp(b) {
+                Some(Ordering::Equal) => None,
+                ordering => Some(ordering),
+            },
+        )
+        .unwrap_or_else(|| Some(self.len().cmp(&other.len())))
+    }
+}
+
+impl<T, const N: usize> Ord for ArrayList<T, N>
+where
+    T: Ord,
+    Usize<N>: ChunkCapacity,
+{
+    /// Compares contiguous runs with slice ordering, which lowers to `memcmp` for bytes.
+    fn cmp(&self, other: &Self) -> Ordering {
+        zip_runs(
+            self.iter().as_chunks(),
+            other.iter().as_chunks(),
+            |a, b| match a.cmp(b) {
+                Ordering::Equal => None,
+                ordering => Some(ordering),
+            },
+        )
+        .unwrap_or_else(|| self.len().cmp(&other.len()))
+    }
+}
+
+/// Hashes like `[T]`: a length prefix followed by [`Hash::hash_slice`] over each contiguous run.
+///
+/// With a streaming hasher such as [`std::hash::DefaultHasher`], where writing bytes in pieces is
+/// the same as writing them at once, the hash matches that of a `Vec<T>` or `[T]` with equal content
+/// regardless of the chunk size.
+impl<T, const N: usize> Hash for ArrayList<T, N>
+where
+    T: Hash,
+    Usize<N>: ChunkCapacity,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `Hasher::write_length_prefix` is unstable, its default implementation is `write_usize`.
+        state.write_usize(self.len());
+
+        for chunk in &self.chunks {
+            let (head, tail) = chunk.as_slices();
+            T::hash_slice(head, state);
+            T::hash_slice(tail, state);
+        }
+    }
+}
+
+impl<T, const N: usize> std::fmt::Debug for ArrayList<T, N>
+where
+    T: std::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::from_list(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::from_list(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
     type Item = &'a mut T;
     type IntoIter = IterMut<'a, T, N>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        IterMut::from_list(self)
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::from_list(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::{LinkedList, VecDeque};
+    use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[cfg(not(feature = "stats"))]
+    const _: () = assert!(
+        size_of::<ArrayList<usize, 32>>() == size_of::<usize>() * 5,
+        "unexpected memory layout"
+    );
+
+    #[test]
+    fn test_new_creates_empty_array_list() {
+        let sut: ArrayList<i64, 2> = ArrayList::new();
+        assert!(sut.is_empty());
+        assert_eq!(sut.len(), 0);
+    }
+
+    #[test]
+    fn test_default_creates_empty_array_list() {
+        let sut: ArrayList<i64, 2> = ArrayList::default();
+        assert!(sut.is_empty());
+        assert_eq!(sut.len(), 0);
+    }
+
+    #[test]
+    fn test_push_front_adds_element_to_front() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+
+        sut.push_front(10);
+        assert_eq!(sut.len(), 1);
+        assert!(!sut.is_empty());
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+
+        sut.push_front(40);
+        sut.push_front(30);
+        sut.push_front(20);
+        sut.push_front(10);
+        assert_eq!(sut.len(), 4);
+        assert!(!sut.is_empty());
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.pop_front(), Some(20));
+        assert_eq!(sut.pop_front(), Some(30));
+        assert_eq!(sut.pop_front(), Some(40));
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn test_push_back_adds_element_to_back() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+
+        sut.push_back(10);
+        assert_eq!(sut.len(), 1);
+        assert!(!sut.is_empty());
+
+        assert_eq!(sut.pop_back(), Some(10));
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        sut.push_back(40);
+        assert_eq!(sut.len(), 4);
+        assert!(!sut.is_empty());
+
+        assert_eq!(sut.pop_back(), Some(40));
+        assert_eq!(sut.pop_back(), Some(30));
+        assert_eq!(sut.pop_back(), Some(20));
+        assert_eq!(sut.pop_back(), Some(10));
+        assert_eq!(sut.len(), 0);
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn test_insert_inserts_element_at_correct_index() {
+        let mut sut: ArrayList<i64, 4> = ArrayList::new();
+
+        // Insert into an empty list
+        sut.insert(0, 10); // List: [10]
+        assert_eq!(sut.len(), 1);
+        assert_eq!(sut, [10]);
+        assert_eq!(sut.get(0), Some(&10));
+
+        // Insert at the beginning
+        sut.insert(0, 5); // List: [5, 10]
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut, [5, 10]);
+        assert_eq!(sut.get(0), Some(&5));
+        assert_eq!(sut.get(1), Some(&10));
+
+        // Insert at the end
+        sut.insert(2, 20); // List: [5, 10, 20]
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut, [5, 10, 20]);
+        assert_eq!(sut.get(2), Some(&20));
+
+        // Insert in the middle
+        sut.insert(1, 7); // List: [5, 7, 10, 20]
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut, [5, 7, 10, 20]);
+        assert_eq!(sut.get(1), Some(&7));
+        assert_eq!(sut.get(2), Some(&10));
+
+        // Fill the chunk
+        sut.insert(4, 25); // List: [5, 7, 10, 20, 25]
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut, [5, 7, 10, 20, 25]);
+        assert_eq!(sut.get(4), Some(&25));
+
+        sut.insert(5, 30); // List: [5, 7, 10, 20, 25, 30]
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut, [5, 7, 10, 20, 25, 30]);
+        assert_eq!(sut.get(5), Some(&30));
+
+        // Force a chunk split
+        sut.insert(3, 15); // List: [5, 7, 10, 15, 20, 25, 30]
+        assert_eq!(sut.len(), 7);
+        assert_eq!(sut, [5, 7, 10, 15, 20, 25, 30]);
+        assert_eq!(sut.get(3), Some(&15));
+        assert_eq!(sut.get(4), Some(&20));
+
+        // Insert at the new chunk boundary
+        sut.insert(6, 27); // List: [5, 7, 10, 15, 20, 25, 27, 30]
+        assert_eq!(sut.len(), 8);
+        assert_eq!(sut, [5, 7, 10, 15, 20, 25, 27, 30]);
+        assert_eq!(sut.get(6), Some(&27));
+        assert_eq!(sut.get(7), Some(&30));
+
+        // Check all elements
+        assert_eq!(sut.get(0), Some(&5));
+        assert_eq!(sut.get(1), Some(&7));
+        assert_eq!(sut.get(2), Some(&10));
+        assert_eq!(sut.get(3), Some(&15));
+        assert_eq!(sut.get(4), Some(&20));
+        assert_eq!(sut.get(5), Some(&25));
+        assert_eq!(sut.get(6), Some(&27));
+        assert_eq!(sut.get(7), Some(&30));
+
+        // Insert out-of-bounds (should panic)
+        let result = std::panic::catch_unwind(move || sut.insert(10, 100));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+    fn test_insert_panics_with_index_and_len() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.insert(4, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap index b (is 3) should be < len (is 3)")]
+    fn test_swap_panics_with_index_and_len() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.swap(0, 3);
+    }
+
+    #[test]
+    fn test_as_single_slice_requires_a_single_unwrapped_chunk() {
+        let mut sut = ArrayList::<_, 4>::new();
+        assert_eq!(sut.as_single_slice(), Some(&[][..]));
+
+        sut.push_back(1);
+        sut.push_back(2);
+        assert_eq!(sut.as_single_slice_mut(), Some(&mut [1, 2][..]));
+
+        // the chunk now wraps around its buffer
+        sut.push_front(0);
+        assert!(!sut.is_contiguous());
+        assert_eq!(sut.as_single_slice(), None);
+
+        sut.chunks[0].make_contiguous();
+        assert_eq!(sut.as_single_slice(), Some(&[0, 1, 2][..]));
+
+        sut.extend([3, 4]);
+        assert_eq!(sut.as_single_slice_mut(), None);
+    }
+
+    #[test]
+    fn test_pop_front_removes_and_returns_the_first_element() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        sut.push_front(30);
+        sut.push_front(20);
+        sut.push_front(10);
+        assert_eq!(sut.len(), 3);
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.pop_front(), Some(20));
+        assert_eq!(sut.len(), 1);
+        assert_eq!(sut.pop_front(), Some(30));
+        assert_eq!(sut.len(), 0);
+
+        assert!(sut.is_empty());
+        assert_eq!(sut.pop_front(), None);
+    }
+
+    #[test]
+    fn test_pop_back_removes_and_returns_the_last_element() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        assert_eq!(sut.len(), 3);
+
+        assert_eq!(sut.pop_back(), Some(30));
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.pop_back(), Some(20));
+        assert_eq!(sut.len(), 1);
+        assert_eq!(sut.pop_back(), Some(10));
+        assert_eq!(sut.len(), 0);
+
+        assert!(sut.is_empty());
+        assert_eq!(sut.pop_back(), None);
+    }
+
+    #[test]
+    fn test_remove_removes_element_at_index() {
+        let mut sut: ArrayList<i64, 3> = ArrayList::new();
+
+        // Fill the list with elements
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        sut.push_back(40);
+        sut.push_back(50);
+        sut.push_back(60);
+        assert_eq!(sut.len(), 6);
+
+        // Test removal of elements at various indices
+        assert_eq!(sut.remove(0).unwrap(), 10); // Removes 10, shifts 20 to index 0
+        assert_eq!(sut.get(0), Some(&20));
+        assert_eq!(sut.len(), 5);
+
+        assert_eq!(sut.remove(2).unwrap(), 40); // Removes 40, shifts 50 to index 2
+        assert_eq!(sut.get(2), Some(&50));
+        assert_eq!(sut.len(), 4);
+
+        assert_eq!(sut.remove(3).unwrap(), 60); // Removes 60, second chunk becomes empty
+        assert_eq!(sut.get(3), None); // No more elements at index 3
+        assert_eq!(sut.len(), 3);
+
+        // Test removal of remaining elements
+        assert_eq!(sut.remove(1).unwrap(), 30); // Removes 30
+        assert_eq!(sut.get(1), Some(&50));
+        assert_eq!(sut.len(), 2);
+
+        assert_eq!(sut.remove(1).unwrap(), 50); // Removes 50
+        assert_eq!(sut.get(1), None);
+        assert_eq!(sut.len(), 1);
+
+        assert_eq!(sut.remove(0).unwrap(), 20); // Removes 20, list becomes empty
+        assert_eq!(sut.get(0), None);
+        assert_eq!(sut.len(), 0);
+
+        assert_eq!(sut.remove(0), None);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::cmp::Ordering;
-    use std::collections::VecDeque;
-    use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
-    use std::mem::size_of;
+    #[test]
+    fn test_remove_element_in_middle_chunk() {
+        let mut sut: ArrayList<i64, 3> = ArrayList::new();
+        assert!(sut.is_empty());
+        assert_eq!(sut.len(), 0);
 
-    use quickcheck_macros::quickcheck;
+        sut.push_back(0);
+        sut.push_back(1);
+        sut.push_back(2);
+        sut.push_back(3);
+        sut.push_back(4);
+        sut.push_back(5);
+        sut.push_back(6);
+        sut.push_back(7);
+        sut.push_back(8);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 9);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&1));
+        assert_eq!(sut.get(2), Some(&2));
+        assert_eq!(sut.get(3), Some(&3));
+        assert_eq!(sut.get(4), Some(&4));
+        assert_eq!(sut.get(5), Some(&5));
+        assert_eq!(sut.get(6), Some(&6));
+        assert_eq!(sut.get(7), Some(&7));
+        assert_eq!(sut.get(8), Some(&8));
 
-    use crate::{ArrayList, ChunkCapacity, Usize};
+        assert_eq!(sut.remove(5).unwrap(), 5);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 8);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&1));
+        assert_eq!(sut.get(2), Some(&2));
+        assert_eq!(sut.get(3), Some(&3));
+        assert_eq!(sut.get(4), Some(&4));
+        assert_eq!(sut.get(5), Some(&6));
+        assert_eq!(sut.get(6), Some(&7));
+        assert_eq!(sut.get(7), Some(&8));
 
-    const _: () = assert!(
-        size_of::<ArrayList<usize, 32>>() == size_of::<usize>() * 5,
-        "unexpected memory layout"
-    );
+        assert_eq!(sut.remove(4).unwrap(), 4);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 7);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&1));
+        assert_eq!(sut.get(2), Some(&2));
+        assert_eq!(sut.get(3), Some(&3));
+        assert_eq!(sut.get(4), Some(&6));
+        assert_eq!(sut.get(5), Some(&7));
+        assert_eq!(sut.get(6), Some(&8));
+
+        assert_eq!(sut.remove(3).unwrap(), 3);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&1));
+        assert_eq!(sut.get(2), Some(&2));
+        assert_eq!(sut.get(3), Some(&6));
+        assert_eq!(sut.get(4), Some(&7));
+        assert_eq!(sut.get(5), Some(&8));
+    }
 
     #[test]
-    fn test_new_creates_empty_array_list() {
-        let sut: ArrayList<i64, 2> = ArrayList::new();
+    fn test_clear_resets_the_list() {
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 3);
+
+        sut.clear();
+
         assert!(sut.is_empty());
         assert_eq!(sut.len(), 0);
+
+        assert_eq!(sut.front(), None);
+        assert_eq!(sut.back(), None);
+
+        // Verify the list is still functional after clearing
+        sut.push_back(40);
+        assert!(!sut.is_empty());
+        assert_eq!(sut.len(), 1);
+        assert_eq!(sut.front(), Some(&40));
+        assert_eq!(sut.back(), Some(&40));
     }
 
     #[test]
-    fn test_default_creates_empty_array_list() {
-        let sut: ArrayList<i64, 2> = ArrayList::default();
-        assert!(sut.is_empty());
-        assert_eq!(sut.len(), 0);
+    fn test_front_returns_the_first_element() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        assert_eq!(sut.front(), None);
+
+        sut.push_back(10);
+        assert_eq!(sut.front(), Some(&10));
+
+        sut.push_back(20);
+        assert_eq!(sut.front(), Some(&10));
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.front(), Some(&20));
+
+        sut.push_front(10);
+        assert_eq!(sut.front(), Some(&10));
+
+        assert_eq!(sut.pop_back(), Some(20));
+        assert_eq!(sut.front(), Some(&10));
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.front(), None);
     }
 
     #[test]
-    fn test_push_front_adds_element_to_front() {
+    fn test_front_mut_returns_the_first_element() {
         let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.len(), 0);
-        assert!(sut.is_empty());
+        assert_eq!(sut.front_mut(), None);
+
+        sut.push_back(10);
+        assert_eq!(sut.front_mut(), Some(&mut 10));
+
+        sut.push_back(20);
+        assert_eq!(sut.front_mut(), Some(&mut 10));
+
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.front_mut(), Some(&mut 20));
 
         sut.push_front(10);
-        assert_eq!(sut.len(), 1);
-        assert!(!sut.is_empty());
+        assert_eq!(sut.front_mut(), Some(&mut 10));
+
+        assert_eq!(sut.pop_back(), Some(20));
+        assert_eq!(sut.front_mut(), Some(&mut 10));
 
         assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.len(), 0);
-        assert!(sut.is_empty());
+        assert_eq!(sut.front_mut(), None);
+    }
+
+    #[test]
+    fn test_back_returns_the_last_element() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        assert_eq!(sut.back(), None);
+
+        sut.push_front(10);
+        assert_eq!(sut.back(), Some(&10));
+
+        sut.push_front(20);
+        assert_eq!(sut.back(), Some(&10));
+
+        assert_eq!(sut.pop_front(), Some(20));
+        assert_eq!(sut.back(), Some(&10));
 
-        sut.push_front(40);
-        sut.push_front(30);
         sut.push_front(20);
+        assert_eq!(sut.back(), Some(&10));
+
+        assert_eq!(sut.pop_back(), Some(10));
+        assert_eq!(sut.back(), Some(&20));
+
+        assert_eq!(sut.pop_front(), Some(20));
+        assert_eq!(sut.back(), None);
+    }
+
+    #[test]
+    fn test_back_mut_returns_the_last_element() {
+        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+        assert_eq!(sut.back_mut(), None);
+
         sut.push_front(10);
-        assert_eq!(sut.len(), 4);
-        assert!(!sut.is_empty());
+        assert_eq!(sut.back_mut(), Some(&mut 10));
+
+        sut.push_front(20);
+        assert_eq!(sut.back_mut(), Some(&mut 10));
 
-        assert_eq!(sut.pop_front(), Some(10));
         assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.pop_front(), Some(30));
-        assert_eq!(sut.pop_front(), Some(40));
-        assert_eq!(sut.len(), 0);
+        assert_eq!(sut.back_mut(), Some(&mut 10));
+
+        sut.push_front(20);
+        assert_eq!(sut.back_mut(), Some(&mut 10));
+
+        assert_eq!(sut.pop_back(), Some(10));
+        assert_eq!(sut.back_mut(), Some(&mut 20));
+
+        assert_eq!(sut.pop_front(), Some(20));
+        assert_eq!(sut.back_mut(), None);
+    }
+
+    #[test]
+    fn test_get_retrieves_correct_element() {
+        let mut sut: ArrayList<i64, 3> = ArrayList::new();
+        assert!(sut.is_empty());
+
+        assert_eq!(sut.get(0), None);
+        assert_eq!(sut.get(5), None);
+
+        // Ensure to allocate at leat 2 chunks
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        sut.push_back(40);
+        sut.push_back(50);
+        sut.push_back(60);
+
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
+        assert_eq!(sut.get(3), Some(&40));
+        assert_eq!(sut.get(4), Some(&50));
+        assert_eq!(sut.get(5), Some(&60));
+
+        // Out-of-bounds indices, should return None
+        assert_eq!(sut.get(6), None);
+        assert_eq!(sut.get(10), None);
+    }
+
+    #[test]
+    fn test_get_mut_retrieves_correct_element() {
+        let mut sut: ArrayList<i64, 3> = ArrayList::new();
         assert!(sut.is_empty());
+
+        assert_eq!(sut.get_mut(0), None);
+        assert_eq!(sut.get_mut(5), None);
+
+        // Ensure to allocate at leat 2 chunks
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        sut.push_back(40);
+        sut.push_back(50);
+        sut.push_back(60);
+
+        assert_eq!(sut.get_mut(0), Some(&mut 10));
+        assert_eq!(sut.get_mut(1), Some(&mut 20));
+        assert_eq!(sut.get_mut(2), Some(&mut 30));
+        assert_eq!(sut.get_mut(3), Some(&mut 40));
+        assert_eq!(sut.get_mut(4), Some(&mut 50));
+        assert_eq!(sut.get_mut(5), Some(&mut 60));
+
+        // Out-of-bounds indices, should return None
+        assert_eq!(sut.get_mut(6), None);
+        assert_eq!(sut.get_mut(10), None);
+    }
+
+    #[quickcheck]
+    fn test_get_back_matches_reversed_vec(seed: Vec<i32>, index: usize) {
+        fn _test<const N: usize>(seed: &[i32], index: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected: Vec<_> = seed.iter().rev().copied().collect();
+
+            for index in [0, index % (seed.len() + 1), seed.len(), index] {
+                assert_eq!(sut.get_back(index), expected.get(index));
+                assert_eq!(sut.get_back_mut(index), expected.get_mut(index));
+            }
+        }
+
+        _test::<1>(&seed, index);
+        _test::<2>(&seed, index);
+        _test::<3>(&seed, index);
+        _test::<4>(&seed, index);
+        _test::<5>(&seed, index);
+        _test::<8>(&seed, index);
+        _test::<16>(&seed, index);
+        _test::<32>(&seed, index);
     }
 
     #[test]
-    fn test_push_back_adds_element_to_back() {
+    fn test_len_returns_correct_length() {
         let mut sut: ArrayList<i64, 2> = ArrayList::new();
         assert_eq!(sut.len(), 0);
         assert!(sut.is_empty());
@@ -1036,666 +4702,1433 @@ mod tests {
         assert_eq!(sut.len(), 1);
         assert!(!sut.is_empty());
 
-        assert_eq!(sut.pop_back(), Some(10));
-        assert_eq!(sut.len(), 0);
-        assert!(sut.is_empty());
-
-        sut.push_back(10);
         sut.push_back(20);
-        sut.push_back(30);
-        sut.push_back(40);
-        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.len(), 2);
         assert!(!sut.is_empty());
 
-        assert_eq!(sut.pop_back(), Some(40));
-        assert_eq!(sut.pop_back(), Some(30));
-        assert_eq!(sut.pop_back(), Some(20));
-        assert_eq!(sut.pop_back(), Some(10));
-        assert_eq!(sut.len(), 0);
-        assert!(sut.is_empty());
+        sut.push_back(30);
+        assert_eq!(sut.len(), 3);
+        assert!(!sut.is_empty());
     }
 
     #[test]
-    fn test_insert_inserts_element_at_correct_index() {
-        let mut sut: ArrayList<i64, 4> = ArrayList::new();
+    fn test_list_remains_functional_after_multiple_operations() {
+        let mut sut: ArrayList<i32, 4> = ArrayList::new();
 
-        // Insert into an empty list
-        sut.insert(0, 10); // List: [10]
-        assert_eq!(sut.len(), 1);
-        assert_eq!(sut, [10]);
-        assert_eq!(sut.get(0), Some(&10));
+        // Initial insertions
+        sut.push_back(10);
+        sut.push_back(20);
+        sut.push_back(30);
+        sut.push_back(40);
+        sut.push_back(50);
 
-        // Insert at the beginning
-        sut.insert(0, 5); // List: [5, 10]
-        assert_eq!(sut.len(), 2);
-        assert_eq!(sut, [5, 10]);
-        assert_eq!(sut.get(0), Some(&5));
-        assert_eq!(sut.get(1), Some(&10));
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut.front(), Some(&10));
+        assert_eq!(sut.back(), Some(&50));
 
-        // Insert at the end
-        sut.insert(2, 20); // List: [5, 10, 20]
+        // Remove elements from the front
+        assert_eq!(sut.pop_front(), Some(10));
+        assert_eq!(sut.pop_front(), Some(20));
         assert_eq!(sut.len(), 3);
-        assert_eq!(sut, [5, 10, 20]);
-        assert_eq!(sut.get(2), Some(&20));
-
-        // Insert in the middle
-        sut.insert(1, 7); // List: [5, 7, 10, 20]
-        assert_eq!(sut.len(), 4);
-        assert_eq!(sut, [5, 7, 10, 20]);
-        assert_eq!(sut.get(1), Some(&7));
-        assert_eq!(sut.get(2), Some(&10));
+        assert_eq!(sut.front(), Some(&30));
+        assert_eq!(sut.back(), Some(&50));
 
-        // Fill the chunk
-        sut.insert(4, 25); // List: [5, 7, 10, 20, 25]
+        // Insert elements at the front
+        sut.push_front(5);
+        sut.push_front(0);
         assert_eq!(sut.len(), 5);
-        assert_eq!(sut, [5, 7, 10, 20, 25]);
-        assert_eq!(sut.get(4), Some(&25));
-
-        sut.insert(5, 30); // List: [5, 7, 10, 20, 25, 30]
-        assert_eq!(sut.len(), 6);
-        assert_eq!(sut, [5, 7, 10, 20, 25, 30]);
-        assert_eq!(sut.get(5), Some(&30));
-
-        // Force a chunk split
-        sut.insert(3, 15); // List: [5, 7, 10, 15, 20, 25, 30]
-        assert_eq!(sut.len(), 7);
-        assert_eq!(sut, [5, 7, 10, 15, 20, 25, 30]);
-        assert_eq!(sut.get(3), Some(&15));
-        assert_eq!(sut.get(4), Some(&20));
-
-        // Insert at the new chunk boundary
-        sut.insert(6, 27); // List: [5, 7, 10, 15, 20, 25, 27, 30]
-        assert_eq!(sut.len(), 8);
-        assert_eq!(sut, [5, 7, 10, 15, 20, 25, 27, 30]);
-        assert_eq!(sut.get(6), Some(&27));
-        assert_eq!(sut.get(7), Some(&30));
+        assert_eq!(sut.front(), Some(&0));
+        assert_eq!(sut.back(), Some(&50));
 
-        // Check all elements
-        assert_eq!(sut.get(0), Some(&5));
-        assert_eq!(sut.get(1), Some(&7));
-        assert_eq!(sut.get(2), Some(&10));
-        assert_eq!(sut.get(3), Some(&15));
-        assert_eq!(sut.get(4), Some(&20));
-        assert_eq!(sut.get(5), Some(&25));
-        assert_eq!(sut.get(6), Some(&27));
-        assert_eq!(sut.get(7), Some(&30));
+        // Remove elements from the back
+        assert_eq!(sut.pop_back(), Some(50));
+        assert_eq!(sut.pop_back(), Some(40));
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.front(), Some(&0));
+        assert_eq!(sut.back(), Some(&30));
 
-        // Insert out-of-bounds (should panic)
-        let result = std::panic::catch_unwind(move || sut.insert(10, 100));
-        assert!(result.is_err());
-    }
+        // Insert in the middle
+        sut.insert(1, 15);
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&15));
+        assert_eq!(sut.get(2), Some(&5));
+        assert_eq!(sut.get(3), Some(&30));
 
-    #[test]
-    fn test_pop_front_removes_and_returns_the_first_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        sut.push_front(30);
-        sut.push_front(20);
-        sut.push_front(10);
+        // Remove an element from the middle
+        assert_eq!(sut.remove(2).unwrap(), 5);
         assert_eq!(sut.len(), 3);
+        assert_eq!(sut.get(0), Some(&0));
+        assert_eq!(sut.get(1), Some(&15));
+        assert_eq!(sut.get(2), Some(&30));
 
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.len(), 2);
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.len(), 1);
-        assert_eq!(sut.pop_front(), Some(30));
+        // Clear and verify reusability
+        sut.clear();
+        assert!(sut.is_empty());
         assert_eq!(sut.len(), 0);
 
-        assert!(sut.is_empty());
-        assert_eq!(sut.pop_front(), None);
+        sut.push_back(100);
+        sut.push_back(200);
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.front(), Some(&100));
+        assert_eq!(sut.back(), Some(&200));
     }
 
     #[test]
-    fn test_pop_back_removes_and_returns_the_last_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
+    fn test_append_combines_two_lists() {
+        // Create and populate the first list
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
         sut.push_back(10);
         sut.push_back(20);
         sut.push_back(30);
-        assert_eq!(sut.len(), 3);
 
-        assert_eq!(sut.pop_back(), Some(30));
-        assert_eq!(sut.len(), 2);
-        assert_eq!(sut.pop_back(), Some(20));
-        assert_eq!(sut.len(), 1);
-        assert_eq!(sut.pop_back(), Some(10));
-        assert_eq!(sut.len(), 0);
+        // Create and populate the second list
+        let mut other: ArrayList<i32, 2> = ArrayList::new();
+        other.push_back(40);
+        other.push_back(50);
+        other.push_back(60);
 
-        assert!(sut.is_empty());
-        assert_eq!(sut.pop_back(), None);
+        // Append the second list into the first
+        sut.append(&mut other);
+
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
+
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
+        assert_eq!(sut.get(3), Some(&40));
+        assert_eq!(sut.get(4), Some(&50));
+        assert_eq!(sut.get(5), Some(&60));
+
+        // ensure appending an empty list does nothing
+        sut.append(&mut other);
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
+        assert_eq!(sut.get(3), Some(&40));
+        assert_eq!(sut.get(4), Some(&50));
+        assert_eq!(sut.get(5), Some(&60));
+
+        // ensure other remains functional
+        other.push_back(100);
+        other.push_back(200);
+        assert_eq!(other.len(), 2);
+        assert_eq!(other.front(), Some(&100));
+        assert_eq!(other.back(), Some(&200));
+
+        // ensure sut remains functional
+        sut.push_back(70);
+        assert_eq!(sut.len(), 7);
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
+        assert_eq!(sut.get(3), Some(&40));
+        assert_eq!(sut.get(4), Some(&50));
+        assert_eq!(sut.get(5), Some(&60));
+        assert_eq!(sut.get(6), Some(&70));
     }
 
     #[test]
-    fn test_remove_removes_element_at_index() {
-        let mut sut: ArrayList<i64, 3> = ArrayList::new();
-
-        // Fill the list with elements
+    fn test_append_an_empty_list_do_nothing() {
+        // Create and populate the first list
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
         sut.push_back(10);
         sut.push_back(20);
         sut.push_back(30);
-        sut.push_back(40);
-        sut.push_back(50);
-        sut.push_back(60);
-        assert_eq!(sut.len(), 6);
 
-        // Test removal of elements at various indices
-        assert_eq!(sut.remove(0).unwrap(), 10); // Removes 10, shifts 20 to index 0
-        assert_eq!(sut.get(0), Some(&20));
-        assert_eq!(sut.len(), 5);
+        // Create and populate the second list
+        let mut other: ArrayList<i32, 2> = ArrayList::new();
 
-        assert_eq!(sut.remove(2).unwrap(), 40); // Removes 40, shifts 50 to index 2
-        assert_eq!(sut.get(2), Some(&50));
-        assert_eq!(sut.len(), 4);
+        // Append the second list into the first
+        sut.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
 
-        assert_eq!(sut.remove(3).unwrap(), 60); // Removes 60, second chunk becomes empty
-        assert_eq!(sut.get(3), None); // No more elements at index 3
+        other.push_back(100);
+        other.push_back(200);
+        assert_eq!(other.len(), 2);
+        assert_eq!(other.front(), Some(&100));
+        assert_eq!(other.back(), Some(&200));
+
+        // Verify the combined list
         assert_eq!(sut.len(), 3);
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
+
+        // Verify the combined list is still functional
+        sut.push_back(40);
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.get(3), Some(&40));
+    }
+
+    #[test]
+    fn test_append_on_an_empty_list_adds_all_elements() {
+        // Create and populate the first list
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+
+        // Create and populate the second list
+        let mut other: ArrayList<i32, 2> = ArrayList::new();
+        other.push_back(10);
+        other.push_back(20);
+        other.push_back(30);
 
-        // Test removal of remaining elements
-        assert_eq!(sut.remove(1).unwrap(), 30); // Removes 30
-        assert_eq!(sut.get(1), Some(&50));
-        assert_eq!(sut.len(), 2);
+        // Append the second list into the first
+        sut.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
 
-        assert_eq!(sut.remove(1).unwrap(), 50); // Removes 50
-        assert_eq!(sut.get(1), None);
-        assert_eq!(sut.len(), 1);
+        other.push_back(100);
+        other.push_back(200);
+        assert_eq!(other.len(), 2);
+        assert_eq!(other.front(), Some(&100));
+        assert_eq!(other.back(), Some(&200));
 
-        assert_eq!(sut.remove(0).unwrap(), 20); // Removes 20, list becomes empty
-        assert_eq!(sut.get(0), None);
-        assert_eq!(sut.len(), 0);
+        // Verify the combined list
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.get(0), Some(&10));
+        assert_eq!(sut.get(1), Some(&20));
+        assert_eq!(sut.get(2), Some(&30));
 
-        assert_eq!(sut.remove(0), None);
+        // Verify the combined list is still functional
+        sut.push_back(40);
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.get(3), Some(&40));
     }
 
     #[test]
-    fn test_remove_element_in_middle_chunk() {
-        let mut sut: ArrayList<i64, 3> = ArrayList::new();
-        assert!(sut.is_empty());
-        assert_eq!(sut.len(), 0);
-
-        sut.push_back(0);
-        sut.push_back(1);
-        sut.push_back(2);
-        sut.push_back(3);
-        sut.push_back(4);
-        sut.push_back(5);
-        sut.push_back(6);
-        sut.push_back(7);
-        sut.push_back(8);
+    fn test_from_iter_works_correctly() {
+        let sut: ArrayList<i32, 2> = ArrayList::from_iter(0..5);
         assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 9);
+        assert_eq!(sut.len(), 5);
         assert_eq!(sut.get(0), Some(&0));
         assert_eq!(sut.get(1), Some(&1));
         assert_eq!(sut.get(2), Some(&2));
         assert_eq!(sut.get(3), Some(&3));
         assert_eq!(sut.get(4), Some(&4));
-        assert_eq!(sut.get(5), Some(&5));
-        assert_eq!(sut.get(6), Some(&6));
-        assert_eq!(sut.get(7), Some(&7));
-        assert_eq!(sut.get(8), Some(&8));
 
-        assert_eq!(sut.remove(5).unwrap(), 5);
+        assert_eq!(sut.front(), Some(&0));
+        assert_eq!(sut.back(), Some(&4));
+    }
+
+    #[test]
+    fn test_extend_works_correctly() {
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+        sut.extend(0..5);
         assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 8);
+        assert_eq!(sut.len(), 5);
         assert_eq!(sut.get(0), Some(&0));
         assert_eq!(sut.get(1), Some(&1));
         assert_eq!(sut.get(2), Some(&2));
         assert_eq!(sut.get(3), Some(&3));
         assert_eq!(sut.get(4), Some(&4));
-        assert_eq!(sut.get(5), Some(&6));
-        assert_eq!(sut.get(6), Some(&7));
-        assert_eq!(sut.get(7), Some(&8));
 
-        assert_eq!(sut.remove(4).unwrap(), 4);
+        assert_eq!(sut.front(), Some(&0));
+        assert_eq!(sut.back(), Some(&4));
+    }
+
+    #[test]
+    fn test_extend_with_refs_works_correctly() {
+        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+        sut.extend([0, 1, 2, 3, 4].iter());
         assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 7);
+        assert_eq!(sut.len(), 5);
         assert_eq!(sut.get(0), Some(&0));
         assert_eq!(sut.get(1), Some(&1));
         assert_eq!(sut.get(2), Some(&2));
         assert_eq!(sut.get(3), Some(&3));
-        assert_eq!(sut.get(4), Some(&6));
-        assert_eq!(sut.get(5), Some(&7));
-        assert_eq!(sut.get(6), Some(&8));
+        assert_eq!(sut.get(4), Some(&4));
 
-        assert_eq!(sut.remove(3).unwrap(), 3);
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 6);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&1));
-        assert_eq!(sut.get(2), Some(&2));
-        assert_eq!(sut.get(3), Some(&6));
-        assert_eq!(sut.get(4), Some(&7));
-        assert_eq!(sut.get(5), Some(&8));
+        assert_eq!(sut.front(), Some(&0));
+        assert_eq!(sut.back(), Some(&4));
     }
 
     #[test]
-    fn test_clear_resets_the_list() {
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+    #[cfg(feature = "extend_one")]
+    fn test_extend_one_and_extend_reserve() {
+        let mut sut: ArrayList<i32, 2> = ArrayList::from([0]);
 
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 3);
+        Extend::<i32>::extend_reserve(&mut sut, 5);
+        assert!(sut.chunks.capacity() >= 4);
 
-        sut.clear();
+        sut.extend_one(1);
+        sut.extend_one(&2);
+        assert_eq!(sut, [0, 1, 2]);
+    }
 
-        assert!(sut.is_empty());
-        assert_eq!(sut.len(), 0);
+    #[quickcheck]
+    fn test_extend_matches_vec_deque(seed: Vec<i32>, batches: Vec<(bool, Vec<i32>)>) {
+        fn _test<const N: usize>(seed: &[i32], batches: &[(bool, Vec<i32>)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = VecDeque::from_iter(seed.iter().copied());
 
-        assert_eq!(sut.front(), None);
-        assert_eq!(sut.back(), None);
+            for (pop, batch) in batches {
+                if *pop {
+                    assert_eq!(actual.pop_back(), expected.pop_back());
+                }
 
-        // Verify the list is still functional after clearing
-        sut.push_back(40);
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 1);
-        assert_eq!(sut.front(), Some(&40));
-        assert_eq!(sut.back(), Some(&40));
+                // hide the exact size of the batch half of the time
+                if batch.len() % 2 == 0 {
+                    actual.extend(batch.iter().copied());
+                } else {
+                    actual.extend(batch.iter().copied().filter(|_| true));
+                }
+                expected.extend(batch.iter().copied());
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(&seed, &batches);
+        _test::<2>(&seed, &batches);
+        _test::<3>(&seed, &batches);
+        _test::<4>(&seed, &batches);
+        _test::<5>(&seed, &batches);
+        _test::<8>(&seed, &batches);
+        _test::<16>(&seed, &batches);
+        _test::<32>(&seed, &batches);
     }
 
     #[test]
-    fn test_front_returns_the_first_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.front(), None);
+    fn test_extend_keeps_len_consistent_when_the_iterator_panics() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
 
-        sut.push_back(10);
-        assert_eq!(sut.front(), Some(&10));
+        let mut sut: ArrayList<i32, 4> = ArrayList::from([0, 1]);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            sut.extend((2..).map(|value| if value < 7 { value } else { panic!() }));
+        }));
 
-        sut.push_back(20);
-        assert_eq!(sut.front(), Some(&10));
+        assert!(result.is_err());
+        assert_eq!(sut.len(), 7);
+        assert!(sut.iter().copied().eq(0..7));
+        assert!(
+            sut.chunks
+                .iter()
+                .all(|chunk| (1..=4).contains(&chunk.len()))
+        );
+    }
 
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.front(), Some(&20));
+    #[quickcheck]
+    fn test_unchecked_swap_and_remove_match_vec(
+        seed: Vec<i32>,
+        operations: Vec<(bool, usize, usize)>,
+    ) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(bool, usize, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.to_vec();
 
-        sut.push_front(10);
-        assert_eq!(sut.front(), Some(&10));
+            for &(remove, a, b) in operations {
+                if expected.is_empty() {
+                    break;
+                }
 
-        assert_eq!(sut.pop_back(), Some(20));
-        assert_eq!(sut.front(), Some(&10));
+                let (a, b) = (a % expected.len(), b % expected.len());
+                if remove {
+                    // SAFETY: `a` is in bounds
+                    assert_eq!(unsafe { actual.remove_unchecked(a) }, expected.remove(a));
+                } else {
+                    expected.swap(a, b);
+                    // SAFETY: `a` and `b` are in bounds
+                    unsafe { actual.swap_unchecked(a, b) };
+                    actual.swap(b, a);
+                    actual.swap(a, b);
+                }
 
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.front(), None);
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()))
+                );
+            }
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_extend_front_matches_vec_deque(seed: Vec<i32>, batches: Vec<(bool, Vec<i32>)>) {
+        fn _test<const N: usize>(seed: &[i32], batches: &[(bool, Vec<i32>)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = VecDeque::from_iter(seed.iter().copied());
+
+            for (pop, batch) in batches {
+                // popping leaves room in the first chunk
+                if *pop {
+                    assert_eq!(actual.pop_front(), expected.pop_front());
+                }
+
+                actual.extend_front(batch.iter().copied());
+                batch
+                    .iter()
+                    .rev()
+                    .for_each(|&value| expected.push_front(value));
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(&seed, &batches);
+        _test::<2>(&seed, &batches);
+        _test::<3>(&seed, &batches);
+        _test::<4>(&seed, &batches);
+        _test::<5>(&seed, &batches);
+        _test::<8>(&seed, &batches);
+        _test::<16>(&seed, &batches);
+        _test::<32>(&seed, &batches);
+    }
+
+    #[quickcheck]
+    fn test_pop_n_matches_vec_deque(seed: Vec<i32>, operations: Vec<(bool, u8)>) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(bool, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = VecDeque::from_iter(seed.iter().copied());
+
+            for &(front, n) in operations {
+                let n = usize::from(n % 64);
+                let (popped, expected_popped) = if front {
+                    let rest = expected.split_off(n.min(expected.len()));
+                    (
+                        actual.pop_front_n(n),
+                        core::mem::replace(&mut expected, rest),
+                    )
+                } else {
+                    (
+                        actual.pop_back_n(n),
+                        expected.split_off(expected.len().saturating_sub(n)),
+                    )
+                };
+
+                for (list, deque) in [(&actual, &expected), (&popped, &expected_popped)] {
+                    assert_eq!(list.len(), deque.len());
+                    assert!(list.iter().eq(deque.iter()));
+                    assert!(
+                        list.chunks
+                            .iter()
+                            .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                    );
+                }
+            }
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_split_into_matches_vec(seed: Vec<i32>, n: u8) {
+        fn _test<const N: usize>(seed: &[i32], n: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let parts = ArrayList::<_, N>::from_iter(seed.iter().copied()).split_into(n);
+            assert_eq!(parts.len(), n);
+
+            let mut rest = seed;
+            for (i, part) in parts.iter().enumerate() {
+                let len = seed.len() / n + usize::from(i < seed.len() % n);
+                let (expected, tail) = rest.split_at(len);
+                rest = tail;
+
+                assert_eq!(part.len(), expected.len());
+                assert!(part.iter().eq(expected.iter()));
+                assert!(
+                    part.chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        let n = usize::from(n % 16) + 1;
+        _test::<1>(&seed, n);
+        _test::<2>(&seed, n);
+        _test::<3>(&seed, n);
+        _test::<4>(&seed, n);
+        _test::<5>(&seed, n);
+        _test::<8>(&seed, n);
+        _test::<16>(&seed, n);
+        _test::<32>(&seed, n);
+    }
+
+    #[quickcheck]
+    fn test_pop_chunk_matches_vec_deque(seed: Vec<i32>, front: u8, sides: Vec<bool>) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, sides: &[bool])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut actual = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| actual.push_front(value));
+            let mut expected = VecDeque::from_iter(seed.iter().copied());
+
+            for &side in sides {
+                let (popped, expected_popped) = if side {
+                    let len = actual.chunks.front().map_or(0, VecDeque::len);
+                    let rest = expected.split_off(len);
+                    (
+                        actual.pop_front_chunk(),
+                        core::mem::replace(&mut expected, rest),
+                    )
+                } else {
+                    let len = actual.chunks.back().map_or(0, VecDeque::len);
+                    (
+                        actual.pop_back_chunk(),
+                        expected.split_off(expected.len() - len),
+                    )
+                };
+
+                match popped {
+                    Some(popped) => {
+                        assert!((1..=N).contains(&popped.len()));
+                        assert!(popped.iter().eq(expected_popped.iter()));
+                    }
+                    None => assert!(expected.is_empty() && expected_popped.is_empty()),
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+            }
+        }
+
+        _test::<1>(&seed, front, &sides);
+        _test::<2>(&seed, front, &sides);
+        _test::<3>(&seed, front, &sides);
+        _test::<4>(&seed, front, &sides);
+        _test::<5>(&seed, front, &sides);
+        _test::<8>(&seed, front, &sides);
+        _test::<16>(&seed, front, &sides);
+        _test::<32>(&seed, front, &sides);
     }
 
     #[test]
-    fn test_front_mut_returns_the_first_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.front_mut(), None);
+    fn test_push_chunk_adopts_the_buffer() {
+        let mut sut = ArrayList::<i32, 4>::from([0]);
+
+        let chunk = vec![1, 2, 3, 4];
+        let ptr = chunk.as_ptr();
+        sut.push_chunk(chunk);
+        assert_eq!(sut.chunks.back().unwrap().as_slices().0.as_ptr(), ptr);
+
+        let mut chunk = Vec::with_capacity(16);
+        chunk.extend(5..15);
+        sut.push_chunk(chunk);
+        sut.push_chunk([15]);
+        sut.push_chunk([]);
+        sut.extend([16, 17]);
+
+        assert_eq!(sut, Vec::from_iter(0..18).as_slice());
+        assert_eq!(
+            sut.chunks.iter().map(VecDeque::len).collect::<Vec<_>>(),
+            [1, 4, 4, 4, 2, 3]
+        );
+        assert!(sut.chunks.iter().all(|chunk| chunk.capacity() <= 4));
+    }
 
-        sut.push_back(10);
-        assert_eq!(sut.front_mut(), Some(&mut 10));
+    #[quickcheck]
+    fn test_apply_permutation_matches_argsort(keys: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(keys: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut permutation: Vec<usize> = (0..keys.len()).collect();
+            permutation.sort_by_key(|&i| keys[i].0);
+
+            let mut sut = ArrayList::<_, N>::from_iter(keys.iter().copied());
+            sut.apply_permutation(&permutation);
+            assert!(sut.iter().eq(permutation.iter().map(|&i| &keys[i])));
+            assert!(sut.iter().map(|(key, _)| key).is_sorted());
+
+            sut.apply_inverse_permutation(&permutation);
+            assert_eq!(sut, keys);
+        }
+
+        _test::<1>(&keys);
+        _test::<2>(&keys);
+        _test::<3>(&keys);
+        _test::<4>(&keys);
+        _test::<5>(&keys);
+        _test::<8>(&keys);
+        _test::<16>(&keys);
+        _test::<32>(&keys);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn test_apply_permutation_rejects_repeated_indices() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.apply_permutation(&[0, 2, 2]);
+    }
+
+    #[quickcheck]
+    fn test_remove_batch_matches_vec(seed: Vec<i32>, selection: Vec<bool>) {
+        fn _test<const N: usize>(seed: &[i32], selection: &[bool])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let indices: Vec<usize> = (0..seed.len())
+                .filter(|&i| selection.get(i).copied().unwrap_or_default())
+                .collect();
+
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let removed = sut.remove_batch(&indices);
+
+            let (expected_removed, expected): (Vec<_>, Vec<_>) = seed
+                .iter()
+                .enumerate()
+                .partition(|(i, _)| indices.contains(i));
+
+            assert!(removed.iter().eq(expected_removed.iter().map(|(_, v)| *v)));
+            assert!(sut.iter().eq(expected.iter().map(|(_, v)| *v)));
+            assert_eq!(sut.len(), expected.len());
+            for list in [&sut, &removed] {
+                assert!(
+                    list.chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(&seed, &selection);
+        _test::<2>(&seed, &selection);
+        _test::<3>(&seed, &selection);
+        _test::<4>(&seed, &selection);
+        _test::<5>(&seed, &selection);
+        _test::<8>(&seed, &selection);
+        _test::<16>(&seed, &selection);
+        _test::<32>(&seed, &selection);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices are not sorted")]
+    fn test_remove_batch_rejects_unsorted_indices() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.remove_batch(&[1, 0]);
+    }
+
+    #[quickcheck]
+    fn test_remove_by_mask_matches_vec(seed: Vec<i32>, selection: Vec<bool>, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], selection: &[bool], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mask: Vec<bool> = (0..seed.len())
+                .map(|i| selection.get(i).copied().unwrap_or_default())
+                .collect();
+
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut sut = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| sut.push_front(value));
+
+            let removed = sut.remove_by_mask(&mask);
+            let expected: Vec<_> = seed.iter().zip(&mask).filter(|(_, m)| !**m).collect();
+
+            assert_eq!(removed, seed.len() - expected.len());
+            assert_eq!(sut.len(), expected.len());
+            assert!(sut.iter().eq(expected.iter().map(|(v, _)| *v)));
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed, &selection, front);
+        _test::<2>(&seed, &selection, front);
+        _test::<3>(&seed, &selection, front);
+        _test::<4>(&seed, &selection, front);
+        _test::<5>(&seed, &selection, front);
+        _test::<8>(&seed, &selection, front);
+        _test::<16>(&seed, &selection, front);
+        _test::<32>(&seed, &selection, front);
+    }
+
+    #[test]
+    #[should_panic(expected = "mask length (is 2) should be == len (is 3)")]
+    fn test_remove_by_mask_rejects_short_mask() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]);
+        sut.remove_by_mask(&[true, false]);
+    }
+
+    #[quickcheck]
+    fn test_insert_batch_matches_vec(seed: Vec<i32>, values: Vec<(usize, i32)>) {
+        fn _test<const N: usize>(seed: &[i32], values: &[(usize, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut values: Vec<_> = values
+                .iter()
+                .map(|&(index, value)| (index % (seed.len() + 1), value))
+                .collect();
+            values.sort_by_key(|(index, _)| *index);
+
+            let mut expected = Vec::new();
+            for index in 0..=seed.len() {
+                let inserted = values.iter().filter(|(i, _)| *i == index);
+                expected.extend(inserted.map(|(_, value)| *value));
+                expected.extend(seed.get(index));
+            }
+
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            sut.insert_batch(values);
+
+            assert_eq!(sut.len(), expected.len());
+            assert!(sut.iter().eq(expected.iter()));
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
+
+        _test::<1>(&seed, &values);
+        _test::<2>(&seed, &values);
+        _test::<3>(&seed, &values);
+        _test::<4>(&seed, &values);
+        _test::<5>(&seed, &values);
+        _test::<8>(&seed, &values);
+        _test::<16>(&seed, &values);
+        _test::<32>(&seed, &values);
+    }
+
+    #[quickcheck]
+    fn test_copy_within_matches_slice(seed: Vec<i32>, start: usize, end: usize, dest: usize) {
+        fn _test<const N: usize>(seed: &[i32], start: usize, end: usize, dest: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            // pushing at the front leaves the chunks partially filled and wrapped
+            if let Some(value) = sut.pop_front() {
+                sut.push_front(value);
+            }
 
-        sut.push_back(20);
-        assert_eq!(sut.front_mut(), Some(&mut 10));
+            let (start, end) = (start % (seed.len() + 1), end % (seed.len() + 1));
+            let (start, end) = (start.min(end), start.max(end));
+            let dest = dest % (seed.len() - (end - start) + 1);
 
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.front_mut(), Some(&mut 20));
+            let mut expected = seed.to_vec();
+            expected.copy_within(start..end, dest);
 
-        sut.push_front(10);
-        assert_eq!(sut.front_mut(), Some(&mut 10));
+            sut.copy_within(start..end, dest);
+            assert!(sut.iter().eq(expected.iter()));
 
-        assert_eq!(sut.pop_back(), Some(20));
-        assert_eq!(sut.front_mut(), Some(&mut 10));
+            // copy the destination back over the source, through the other method and bounds
+            if start < end {
+                expected.copy_within(dest..dest + end - start, start);
+                sut.clone_within(dest..=dest + end - start - 1, start);
+                assert!(sut.iter().eq(expected.iter()));
+            }
+        }
 
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.front_mut(), None);
+        _test::<1>(&seed, start, end, dest);
+        _test::<2>(&seed, start, end, dest);
+        _test::<3>(&seed, start, end, dest);
+        _test::<4>(&seed, start, end, dest);
+        _test::<5>(&seed, start, end, dest);
+        _test::<8>(&seed, start, end, dest);
+        _test::<16>(&seed, start, end, dest);
+        _test::<32>(&seed, start, end, dest);
     }
 
-    #[test]
-    fn test_back_returns_the_last_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.back(), None);
-
-        sut.push_front(10);
-        assert_eq!(sut.back(), Some(&10));
+    #[quickcheck]
+    fn test_range_operations_match_slice(seed: Vec<(u8, u16)>, ranges: Vec<(u8, usize, usize)>) {
+        fn _test<const N: usize>(seed: &[(u8, u16)], ranges: &[(u8, usize, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut =
+                ArrayList::<_, N>::from_iter(seed.iter().map(|&(key, tag)| Key(key, tag)));
+            // pushing at the front leaves the chunks partially filled and wrapped
+            if let Some(value) = sut.pop_front() {
+                sut.push_front(value);
+            }
+            let layout: Vec<usize> = sut.chunks.iter().map(VecDeque::len).collect();
+            let mut expected = seed.to_vec();
 
-        sut.push_front(20);
-        assert_eq!(sut.back(), Some(&10));
+            for &(operation, start, end) in ranges {
+                let (start, end) = (start % (seed.len() + 1), end % (seed.len() + 1));
+                let range = start.min(end)..start.max(end);
 
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.back(), Some(&10));
+                match operation % 3 {
+                    0 => {
+                        sut.fill_range(range.clone(), Key(operation, 0));
+                        expected[range].fill((operation, 0));
+                    }
+                    1 => {
+                        sut.reverse_range(range.clone());
+                        expected[range].reverse();
+                    }
+                    _ => {
+                        // keys are compared by their first field only, so the tags check stability
+                        sut.sort_range(range.clone());
+                        expected[range].sort_by_key(|&(key, _)| key);
+                    }
+                }
 
-        sut.push_front(20);
-        assert_eq!(sut.back(), Some(&10));
+                assert!(
+                    sut.iter()
+                        .map(|&Key(key, tag)| (key, tag))
+                        .eq(expected.iter().copied())
+                );
+            }
 
-        assert_eq!(sut.pop_back(), Some(10));
-        assert_eq!(sut.back(), Some(&20));
+            assert!(sut.chunks.iter().map(VecDeque::len).eq(layout));
+        }
 
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.back(), None);
-    }
+        /// Ordered by its first field only.
+        #[derive(Clone, Copy, Debug)]
+        struct Key(u8, u16);
 
-    #[test]
-    fn test_back_mut_returns_the_last_element() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.back_mut(), None);
+        impl PartialEq for Key {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
 
-        sut.push_front(10);
-        assert_eq!(sut.back_mut(), Some(&mut 10));
+        impl Eq for Key {}
 
-        sut.push_front(20);
-        assert_eq!(sut.back_mut(), Some(&mut 10));
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
 
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.back_mut(), Some(&mut 10));
+        impl Ord for Key {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
 
-        sut.push_front(20);
-        assert_eq!(sut.back_mut(), Some(&mut 10));
+        _test::<1>(&seed, &ranges);
+        _test::<2>(&seed, &ranges);
+        _test::<3>(&seed, &ranges);
+        _test::<4>(&seed, &ranges);
+        _test::<5>(&seed, &ranges);
+        _test::<8>(&seed, &ranges);
+        _test::<16>(&seed, &ranges);
+        _test::<32>(&seed, &ranges);
+    }
 
-        assert_eq!(sut.pop_back(), Some(10));
-        assert_eq!(sut.back_mut(), Some(&mut 20));
+    #[quickcheck]
+    fn test_rechunk_packs_chunks(seed: Vec<i32>, front: u8) {
+        fn _test<const N: usize, const M: usize>(seed: &[i32], front: usize)
+        where
+            Usize<N>: ChunkCapacity,
+            Usize<M>: ChunkCapacity,
+        {
+            // pushing at the front leaves the chunks partially filled
+            let mut sut = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| sut.push_front(value));
+
+            let sut = sut.rechunk::<M>();
+            assert_eq!(sut.len(), seed.len());
+            assert!(sut.iter().eq(seed.iter()));
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=M).contains(&chunk.len()) && chunk.capacity() <= M)
+            );
+            if M != N {
+                assert_eq!(sut.chunks.len(), seed.len().div_ceil(M));
+            }
+        }
 
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.back_mut(), None);
+        let front = usize::from(front) % (seed.len() + 1);
+        _test::<1, 4>(&seed, front);
+        _test::<2, 2>(&seed, front);
+        _test::<3, 8>(&seed, front);
+        _test::<4, 1>(&seed, front);
+        _test::<5, 3>(&seed, front);
+        _test::<8, 32>(&seed, front);
+        _test::<16, 5>(&seed, front);
+        _test::<32, 16>(&seed, front);
     }
 
-    #[test]
-    fn test_get_retrieves_correct_element() {
-        let mut sut: ArrayList<i64, 3> = ArrayList::new();
-        assert!(sut.is_empty());
+    #[quickcheck]
+    fn test_merge_matches_sort(left: Vec<i16>, right: Vec<i16>) {
+        fn _test<const N: usize>(left: &[i16], right: &[i16])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // coarse values make for long runs of equal elements on both sides
+            let mut left: Vec<_> = left.iter().map(|&value| (value / 64, 0)).collect();
+            let mut right: Vec<_> = right.iter().map(|&value| (value / 64, 1)).collect();
+            left.sort();
+            right.sort();
+
+            let mut expected = [left.as_slice(), right.as_slice()].concat();
+            expected.sort();
+
+            let mut sut = ArrayList::<_, N>::from_iter(left);
+            let mut other = ArrayList::<_, N>::from_iter(right);
+            sut.merge(&mut other);
+
+            assert!(other.is_empty());
+            assert_eq!(sut.len(), expected.len());
+            assert!(sut.iter().eq(expected.iter()));
+            assert!(
+                sut.chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
 
-        assert_eq!(sut.get(0), None);
-        assert_eq!(sut.get(5), None);
+        _test::<1>(&left, &right);
+        _test::<2>(&left, &right);
+        _test::<3>(&left, &right);
+        _test::<4>(&left, &right);
+        _test::<5>(&left, &right);
+        _test::<8>(&left, &right);
+        _test::<16>(&left, &right);
+        _test::<32>(&left, &right);
+    }
 
-        // Ensure to allocate at leat 2 chunks
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-        sut.push_back(40);
-        sut.push_back(50);
-        sut.push_back(60);
+    #[quickcheck]
+    fn test_into_std_containers(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            assert!(LinkedList::from(list.clone()).iter().eq(seed));
 
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
-        assert_eq!(sut.get(3), Some(&40));
-        assert_eq!(sut.get(4), Some(&50));
-        assert_eq!(sut.get(5), Some(&60));
+            let deque = VecDeque::from(list);
+            assert!(deque.iter().eq(seed));
+            assert!(deque.capacity() >= seed.len());
+        }
 
-        // Out-of-bounds indices, should return None
-        assert_eq!(sut.get(6), None);
-        assert_eq!(sut.get(10), None);
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
     }
 
-    #[test]
-    fn test_get_mut_retrieves_correct_element() {
-        let mut sut: ArrayList<i64, 3> = ArrayList::new();
-        assert!(sut.is_empty());
-
-        assert_eq!(sut.get_mut(0), None);
-        assert_eq!(sut.get_mut(5), None);
+    #[quickcheck]
+    fn test_to_array_matches_slice(seed: Vec<i32>) {
+        fn check<const N: usize, const M: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let expected = <[i32; M]>::try_from(seed).ok();
 
-        // Ensure to allocate at leat 2 chunks
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-        sut.push_back(40);
-        sut.push_back(50);
-        sut.push_back(60);
+            assert_eq!(list.to_array::<M>(), expected);
+            match <[i32; M]>::try_from(list.clone()) {
+                Ok(array) => assert_eq!(Some(array), expected),
+                Err(rest) => assert!(expected.is_none() && rest == list),
+            }
+        }
 
-        assert_eq!(sut.get_mut(0), Some(&mut 10));
-        assert_eq!(sut.get_mut(1), Some(&mut 20));
-        assert_eq!(sut.get_mut(2), Some(&mut 30));
-        assert_eq!(sut.get_mut(3), Some(&mut 40));
-        assert_eq!(sut.get_mut(4), Some(&mut 50));
-        assert_eq!(sut.get_mut(5), Some(&mut 60));
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            check::<N, 0>(seed);
+            check::<N, 1>(seed);
+            check::<N, 3>(seed);
+            check::<N, 8>(seed);
+            check::<N, 33>(seed);
+        }
 
-        // Out-of-bounds indices, should return None
-        assert_eq!(sut.get_mut(6), None);
-        assert_eq!(sut.get_mut(10), None);
+        // truncating the seed makes the lengths worth checking more likely
+        let seed = &seed[..seed.len() % 10];
+        _test::<1>(seed);
+        _test::<2>(seed);
+        _test::<3>(seed);
+        _test::<4>(seed);
+        _test::<5>(seed);
+        _test::<8>(seed);
+        _test::<16>(seed);
+        _test::<32>(seed);
     }
 
-    #[test]
-    fn test_len_returns_correct_length() {
-        let mut sut: ArrayList<i64, 2> = ArrayList::new();
-        assert_eq!(sut.len(), 0);
-        assert!(sut.is_empty());
-
-        sut.push_back(10);
-        assert_eq!(sut.len(), 1);
-        assert!(!sut.is_empty());
+    #[quickcheck]
+    fn test_swap_ranges_matches_vec(seed: Vec<i32>, ranges: Vec<(usize, usize, usize)>) {
+        fn _test<const N: usize>(seed: &[i32], ranges: &[(usize, usize, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut expected = seed.to_vec();
+
+            for &(a, b, len) in ranges {
+                // picks two disjoint ranges of the same length
+                let len = len % (seed.len() / 2 + 1);
+                let a = a % (seed.len() - 2 * len + 1);
+                let b = a + len + b % (seed.len() - a - 2 * len + 1);
+
+                let (head, tail) = expected.split_at_mut(b);
+                head[a..a + len].swap_with_slice(&mut tail[..len]);
+                if len % 2 == 0 {
+                    actual.swap_ranges(a..a + len, b..b + len);
+                } else {
+                    actual.swap_ranges(b..b + len, a..a + len);
+                }
 
-        sut.push_back(20);
-        assert_eq!(sut.len(), 2);
-        assert!(!sut.is_empty());
+                assert!(actual.iter().eq(expected.iter()));
+            }
+        }
 
-        sut.push_back(30);
-        assert_eq!(sut.len(), 3);
-        assert!(!sut.is_empty());
+        _test::<1>(&seed, &ranges);
+        _test::<2>(&seed, &ranges);
+        _test::<3>(&seed, &ranges);
+        _test::<4>(&seed, &ranges);
+        _test::<5>(&seed, &ranges);
+        _test::<8>(&seed, &ranges);
+        _test::<16>(&seed, &ranges);
+        _test::<32>(&seed, &ranges);
     }
 
     #[test]
-    fn test_list_remains_functional_after_multiple_operations() {
-        let mut sut: ArrayList<i32, 4> = ArrayList::new();
+    #[should_panic(expected = "ranges overlap: 1..3 and 2..4")]
+    fn test_swap_ranges_rejects_overlapping_ranges() {
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2, 3, 4]);
+        sut.swap_ranges(2..4, 1..3);
+    }
 
-        // Initial insertions
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-        sut.push_back(40);
-        sut.push_back(50);
+    #[quickcheck]
+    fn test_cursor_find_matches_position(seed: Vec<u8>, target: u8) {
+        fn _test<const N: usize>(seed: &[u8], target: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let expected = seed.iter().position(|&v| v % 8 == target % 8);
 
-        assert_eq!(sut.len(), 5);
-        assert_eq!(sut.front(), Some(&10));
-        assert_eq!(sut.back(), Some(&50));
+            let cursor = sut.cursor_find(|&v| v % 8 == target % 8);
+            assert_eq!(cursor.index(), expected);
+            assert_eq!(cursor.current(), expected.map(|i| &seed[i]));
 
-        // Remove elements from the front
-        assert_eq!(sut.pop_front(), Some(10));
-        assert_eq!(sut.pop_front(), Some(20));
-        assert_eq!(sut.len(), 3);
-        assert_eq!(sut.front(), Some(&30));
-        assert_eq!(sut.back(), Some(&50));
+            let mut cursor = sut.cursor_find_mut(|&v| v % 8 == target % 8);
+            assert_eq!(cursor.index(), expected);
+            assert_eq!(cursor.current().copied(), expected.map(|i| seed[i]));
+        }
 
-        // Insert elements at the front
-        sut.push_front(5);
-        sut.push_front(0);
-        assert_eq!(sut.len(), 5);
-        assert_eq!(sut.front(), Some(&0));
-        assert_eq!(sut.back(), Some(&50));
+        _test::<1>(&seed, target);
+        _test::<2>(&seed, target);
+        _test::<3>(&seed, target);
+        _test::<4>(&seed, target);
+        _test::<5>(&seed, target);
+        _test::<8>(&seed, target);
+        _test::<16>(&seed, target);
+        _test::<32>(&seed, target);
+    }
 
-        // Remove elements from the back
-        assert_eq!(sut.pop_back(), Some(50));
-        assert_eq!(sut.pop_back(), Some(40));
-        assert_eq!(sut.len(), 3);
-        assert_eq!(sut.front(), Some(&0));
-        assert_eq!(sut.back(), Some(&30));
+    #[quickcheck]
+    fn test_map_in_place_matches_vec(seed: Vec<u8>, front: u8, limit: u8) {
+        fn _test<const N: usize>(seed: &[u8], front: u8, limit: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut sut = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| sut.push_front(value));
+            let mut expected = seed.to_vec();
 
-        // Insert in the middle
-        sut.insert(1, 15);
-        assert_eq!(sut.len(), 4);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&15));
-        assert_eq!(sut.get(2), Some(&5));
-        assert_eq!(sut.get(3), Some(&30));
+            sut.map_in_place(|v| v.wrapping_mul(3));
+            expected.iter_mut().for_each(|v| *v = v.wrapping_mul(3));
+            assert!(sut.iter().eq(expected.iter()));
 
-        // Remove an element from the middle
-        assert_eq!(sut.remove(2).unwrap(), 5);
-        assert_eq!(sut.len(), 3);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&15));
-        assert_eq!(sut.get(2), Some(&30));
+            let result = sut.try_map_in_place(|&v| if v < limit { Ok(v / 2) } else { Err(v) });
+            let failing = expected.iter().position(|&v| v >= limit);
+            expected
+                .iter_mut()
+                .take(failing.unwrap_or(seed.len()))
+                .for_each(|v| *v /= 2);
 
-        // Clear and verify reusability
-        sut.clear();
-        assert!(sut.is_empty());
-        assert_eq!(sut.len(), 0);
+            assert_eq!(
+                result,
+                failing.map_or(Ok(()), |i| Err(seed[i].wrapping_mul(3)))
+            );
+            assert!(sut.iter().eq(expected.iter()));
+        }
 
-        sut.push_back(100);
-        sut.push_back(200);
-        assert_eq!(sut.len(), 2);
-        assert_eq!(sut.front(), Some(&100));
-        assert_eq!(sut.back(), Some(&200));
+        _test::<1>(&seed, front, limit);
+        _test::<2>(&seed, front, limit);
+        _test::<3>(&seed, front, limit);
+        _test::<4>(&seed, front, limit);
+        _test::<5>(&seed, front, limit);
+        _test::<8>(&seed, front, limit);
+        _test::<16>(&seed, front, limit);
+        _test::<32>(&seed, front, limit);
     }
 
-    #[test]
-    fn test_append_combines_two_lists() {
-        // Create and populate the first list
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-
-        // Create and populate the second list
-        let mut other: ArrayList<i32, 2> = ArrayList::new();
-        other.push_back(40);
-        other.push_back(50);
-        other.push_back(60);
-
-        // Append the second list into the first
-        sut.append(&mut other);
+    #[quickcheck]
+    fn test_map_keeps_chunks_layout(seed: Vec<i32>, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut sut = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| sut.push_front(value));
 
-        assert!(other.is_empty());
-        assert_eq!(other.len(), 0);
+            let layout: Vec<usize> = sut.chunks.iter().map(VecDeque::len).collect();
+            let mapped = sut.map(|v| i64::from(v) * 2);
 
-        assert_eq!(sut.len(), 6);
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
-        assert_eq!(sut.get(3), Some(&40));
-        assert_eq!(sut.get(4), Some(&50));
-        assert_eq!(sut.get(5), Some(&60));
+            assert_eq!(mapped.len(), seed.len());
+            let expected = seed.iter().map(|&v| i64::from(v) * 2);
+            assert!(mapped.iter().copied().eq(expected));
+            assert!(mapped.chunks.iter().map(VecDeque::len).eq(layout));
+            assert!(mapped.chunks.iter().all(|chunk| chunk.capacity() <= N));
+        }
 
-        // ensure appending an empty list does nothing
-        sut.append(&mut other);
-        assert_eq!(sut.len(), 6);
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
-        assert_eq!(sut.get(3), Some(&40));
-        assert_eq!(sut.get(4), Some(&50));
-        assert_eq!(sut.get(5), Some(&60));
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
 
-        // ensure other remains functional
-        other.push_back(100);
-        other.push_back(200);
-        assert_eq!(other.len(), 2);
-        assert_eq!(other.front(), Some(&100));
-        assert_eq!(other.back(), Some(&200));
+    #[quickcheck]
+    fn test_find_subsequence_matches_windows(seed: Vec<u8>, pattern: Vec<u8>, front: u8) {
+        fn _test<const N: usize>(seed: &[u8], pattern: &[u8], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let (expected, expected_back) = if pattern.is_empty() {
+                (Some(0), Some(seed.len()))
+            } else {
+                (
+                    seed.windows(pattern.len()).position(|w| w == pattern),
+                    seed.windows(pattern.len()).rposition(|w| w == pattern),
+                )
+            };
+
+            assert_eq!(list.find_subsequence(pattern), expected);
+            assert_eq!(list.rfind_subsequence(pattern), expected_back);
+        }
 
-        // ensure sut remains functional
-        sut.push_back(70);
-        assert_eq!(sut.len(), 7);
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
-        assert_eq!(sut.get(3), Some(&40));
-        assert_eq!(sut.get(4), Some(&50));
-        assert_eq!(sut.get(5), Some(&60));
-        assert_eq!(sut.get(6), Some(&70));
+        // a small alphabet and short patterns make for frequent and overlapping matches
+        let seed: Vec<_> = seed.iter().map(|v| v % 2).collect();
+        let pattern: Vec<_> = pattern.iter().take(6).map(|v| v % 2).collect();
+        _test::<1>(&seed, &pattern, front);
+        _test::<2>(&seed, &pattern, front);
+        _test::<3>(&seed, &pattern, front);
+        _test::<4>(&seed, &pattern, front);
+        _test::<5>(&seed, &pattern, front);
+        _test::<8>(&seed, &pattern, front);
+        _test::<16>(&seed, &pattern, front);
+        _test::<32>(&seed, &pattern, front);
     }
 
-    #[test]
-    fn test_append_an_empty_list_do_nothing() {
-        // Create and populate the first list
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
-        sut.push_back(10);
-        sut.push_back(20);
-        sut.push_back(30);
-
-        // Create and populate the second list
-        let mut other: ArrayList<i32, 2> = ArrayList::new();
+    #[quickcheck]
+    fn test_swap_retain_keeps_matching_elements(seed: Vec<u8>, front: u8) {
+        fn _test<const N: usize>(seed: &[u8], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
 
-        // Append the second list into the first
-        sut.append(&mut other);
-        assert!(other.is_empty());
-        assert_eq!(other.len(), 0);
+            let chunks: Vec<Vec<u8>> = list
+                .chunks
+                .iter()
+                .map(|chunk| chunk.iter().copied().filter(|v| v % 3 != 0).collect())
+                .collect();
+
+            list.swap_retain(|v| v % 3 != 0);
+            assert_eq!(list.len(), chunks.iter().map(Vec::len).sum::<usize>());
+
+            // elements stay in their chunk, in any order
+            let mut expected = chunks.into_iter().filter(|chunk| !chunk.is_empty());
+            for chunk in &list.chunks {
+                let mut actual: Vec<_> = chunk.iter().copied().collect();
+                let mut expected = expected.next().unwrap();
+                actual.sort_unstable();
+                expected.sort_unstable();
+                assert_eq!(actual, expected);
+            }
+            assert!(expected.next().is_none());
+        }
 
-        other.push_back(100);
-        other.push_back(200);
-        assert_eq!(other.len(), 2);
-        assert_eq!(other.front(), Some(&100));
-        assert_eq!(other.back(), Some(&200));
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
 
-        // Verify the combined list
-        assert_eq!(sut.len(), 3);
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
+    #[quickcheck]
+    fn test_from_sorted_iters_matches_stable_sort(shards: Vec<Vec<u8>>) {
+        fn _test<const N: usize>(shards: &[Vec<u8>])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a small range of keys makes ties across inputs likely
+            let shards: Vec<Vec<(u8, usize)>> = shards
+                .iter()
+                .enumerate()
+                .map(|(source, shard)| {
+                    let mut shard: Vec<_> = shard.iter().map(|&key| (key % 4, source)).collect();
+                    shard.sort_unstable();
+                    shard
+                })
+                .collect();
+
+            let mut expected: Vec<_> = shards.concat();
+            expected.sort_by_key(|&(key, _)| key);
+
+            let actual =
+                ArrayList::<_, N>::from_sorted_iters(shards, |a: &(u8, usize), b| a.0.cmp(&b.0));
+            assert!(actual.iter().eq(expected.iter()));
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
 
-        // Verify the combined list is still functional
-        sut.push_back(40);
-        assert_eq!(sut.len(), 4);
-        assert_eq!(sut.get(3), Some(&40));
+        _test::<1>(&shards);
+        _test::<2>(&shards);
+        _test::<3>(&shards);
+        _test::<4>(&shards);
+        _test::<5>(&shards);
+        _test::<8>(&shards);
+        _test::<16>(&shards);
+        _test::<32>(&shards);
     }
 
-    #[test]
-    fn test_append_on_an_empty_list_adds_all_elements() {
-        // Create and populate the first list
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
+    #[quickcheck]
+    fn test_eq_by_and_cmp_by_match_iterators(left: Vec<i32>, right: Vec<u8>, front: u8) {
+        fn _test<const N: usize, const M: usize>(left: &[i32], right: &[u8], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+            Usize<M>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (left.len() + 1);
+            let mut a = ArrayList::<_, N>::from_iter(left[front..].iter().copied());
+            left[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| a.push_front(value));
+            let b = ArrayList::<_, M>::from_iter(right.iter().copied());
 
-        // Create and populate the second list
-        let mut other: ArrayList<i32, 2> = ArrayList::new();
-        other.push_back(10);
-        other.push_back(20);
-        other.push_back(30);
+            // small values make equal prefixes likely
+            let relate = |a: &i32, b: &u8| (a % 4).cmp(&i32::from(b % 4));
+            let expected = left
+                .iter()
+                .map(|a| a % 4)
+                .cmp(right.iter().map(|b| i32::from(b % 4)));
+            assert_eq!(a.cmp_by(&b, relate), expected);
+            assert_eq!(a.eq_by(&b, |a, b| relate(a, b).is_eq()), expected.is_eq());
+        }
 
-        // Append the second list into the first
-        sut.append(&mut other);
-        assert!(other.is_empty());
-        assert_eq!(other.len(), 0);
+        _test::<1, 3>(&left, &right, front);
+        _test::<2, 2>(&left, &right, front);
+        _test::<3, 1>(&left, &right, front);
+        _test::<4, 8>(&left, &right, front);
+        _test::<5, 4>(&left, &right, front);
+        _test::<8, 5>(&left, &right, front);
+        _test::<16, 32>(&left, &right, front);
+        _test::<32, 16>(&left, &right, front);
+    }
 
-        other.push_back(100);
-        other.push_back(200);
-        assert_eq!(other.len(), 2);
-        assert_eq!(other.front(), Some(&100));
-        assert_eq!(other.back(), Some(&200));
+    #[quickcheck]
+    fn test_copy_to_slice_fills_prefix(seed: Vec<i32>, front: u8, capacity: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, capacity: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
 
-        // Verify the combined list
-        assert_eq!(sut.len(), 3);
-        assert_eq!(sut.get(0), Some(&10));
-        assert_eq!(sut.get(1), Some(&20));
-        assert_eq!(sut.get(2), Some(&30));
+            let written = capacity.min(seed.len());
+            let mut expected = vec![-1; capacity];
+            expected[..written].copy_from_slice(&seed[..written]);
 
-        // Verify the combined list is still functional
-        sut.push_back(40);
-        assert_eq!(sut.len(), 4);
-        assert_eq!(sut.get(3), Some(&40));
-    }
+            let mut copied = vec![-1; capacity];
+            assert_eq!(list.copy_to_slice(&mut copied), written);
+            assert_eq!(copied, expected);
 
-    #[test]
-    fn test_from_iter_works_correctly() {
-        let sut: ArrayList<i32, 2> = ArrayList::from_iter(0..5);
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 5);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&1));
-        assert_eq!(sut.get(2), Some(&2));
-        assert_eq!(sut.get(3), Some(&3));
-        assert_eq!(sut.get(4), Some(&4));
+            let mut cloned = vec![-1; capacity];
+            assert_eq!(list.clone_into_slice(&mut cloned), written);
+            assert_eq!(cloned, expected);
+        }
 
-        assert_eq!(sut.front(), Some(&0));
-        assert_eq!(sut.back(), Some(&4));
+        let capacity = usize::from(capacity);
+        _test::<1>(&seed, front, capacity);
+        _test::<2>(&seed, front, capacity);
+        _test::<3>(&seed, front, capacity);
+        _test::<4>(&seed, front, capacity);
+        _test::<5>(&seed, front, capacity);
+        _test::<8>(&seed, front, capacity);
+        _test::<16>(&seed, front, capacity);
+        _test::<32>(&seed, front, capacity);
     }
 
-    #[test]
-    fn test_extend_works_correctly() {
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
-        sut.extend(0..5);
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 5);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&1));
-        assert_eq!(sut.get(2), Some(&2));
-        assert_eq!(sut.get(3), Some(&3));
-        assert_eq!(sut.get(4), Some(&4));
+    #[quickcheck]
+    fn test_repeat_matches_slices(seed: Vec<i32>, front: u8, n: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, n: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let actual = list.repeat(n);
+            assert_eq!(actual, seed.repeat(n).as_slice());
+            assert_eq!(actual.chunks.len(), actual.len().div_ceil(N));
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+            );
+        }
 
-        assert_eq!(sut.front(), Some(&0));
-        assert_eq!(sut.back(), Some(&4));
+        let n = usize::from(n % 8);
+        _test::<1>(&seed, front, n);
+        _test::<2>(&seed, front, n);
+        _test::<3>(&seed, front, n);
+        _test::<4>(&seed, front, n);
+        _test::<5>(&seed, front, n);
+        _test::<8>(&seed, front, n);
+        _test::<16>(&seed, front, n);
+        _test::<32>(&seed, front, n);
     }
 
-    #[test]
-    fn test_extend_with_refs_works_correctly() {
-        let mut sut: ArrayList<i32, 2> = ArrayList::new();
-        sut.extend([0, 1, 2, 3, 4].iter());
-        assert!(!sut.is_empty());
-        assert_eq!(sut.len(), 5);
-        assert_eq!(sut.get(0), Some(&0));
-        assert_eq!(sut.get(1), Some(&1));
-        assert_eq!(sut.get(2), Some(&2));
-        assert_eq!(sut.get(3), Some(&3));
-        assert_eq!(sut.get(4), Some(&4));
+    #[quickcheck]
+    fn test_from_iter_packs_chunks(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let exact = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let hidden = ArrayList::<_, N>::from_iter(seed.iter().copied().filter(|_| true));
+            let sliced = ArrayList::<_, N>::from(seed);
+            let sliced_mut = ArrayList::<_, N>::from(&mut seed.to_vec()[..]);
+
+            for sut in [&exact, &hidden, &sliced, &sliced_mut] {
+                assert_eq!(sut.len(), seed.len());
+                assert!(sut.iter().eq(seed.iter()));
+                assert_eq!(sut.chunks.len(), seed.len().div_ceil(N));
+                assert!(
+                    sut.chunks
+                        .iter()
+                        .all(|chunk| !chunk.is_empty() && chunk.capacity() <= N)
+                );
+                assert!(
+                    sut.chunks
+                        .iter()
+                        .rev()
+                        .skip(1)
+                        .all(|chunk| chunk.len() == N)
+                );
+            }
 
-        assert_eq!(sut.front(), Some(&0));
-        assert_eq!(sut.back(), Some(&4));
+            assert_eq!(exact.chunks.capacity(), seed.len().div_ceil(N));
+            assert_eq!(sliced.chunks.capacity(), seed.len().div_ceil(N));
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
     }
 
     #[test]
@@ -1753,11 +6186,40 @@ mod tests {
         assert_eq!(l, r);
     }
 
+    #[quickcheck]
+    fn test_eq_across_chunk_capacities(left: Vec<u8>, right: Vec<u8>) {
+        fn _test<const N: usize>(left: &[u8], right: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a small alphabet makes equal lists likely
+            let l = ArrayList::<_, N>::from_iter(left.iter().map(|v| v % 2));
+            let r = ArrayList::<_, 3>::from_iter(right.iter().map(|v| v % 2));
+            let expected = l.iter().eq(r.iter());
+
+            assert_eq!(l == r, expected);
+            assert_eq!(r == l, expected);
+            assert_eq!(l, ArrayList::<_, 7>::from_iter(l.iter().copied()));
+        }
+
+        _test::<1>(&left, &right);
+        _test::<2>(&left, &right);
+        _test::<3>(&left, &right);
+        _test::<4>(&left, &right);
+        _test::<5>(&left, &right);
+        _test::<8>(&left, &right);
+        _test::<16>(&left, &right);
+        _test::<32>(&left, &right);
+    }
+
     #[test]
     fn test_debug_works_correctly() {
         let array = [0, 1, 2, 3, 4];
         let list = ArrayList::<usize, 2>::from(array);
-        assert_eq!(format!("{list:?}"), "[[0, 1], [2, 3], [4]]");
+        assert_eq!(format!("{list:?}"), "[0, 1, 2, 3, 4]");
+
+        let list = ArrayList::<usize, 3>::from(array);
+        assert_eq!(format!("{list:?}"), "[0, 1, 2, 3, 4]");
     }
 
     #[test]
@@ -1778,6 +6240,43 @@ mod tests {
         assert_eq!(b.partial_cmp(&a), Some(Ordering::Greater));
     }
 
+    #[quickcheck]
+    fn test_cmp_matches_slices(left: Vec<u8>, right: Vec<u8>, front: u8) {
+        fn _test<const N: usize>(left: &[u8], right: &[u8], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a small alphabet makes long common prefixes likely
+            let left: Vec<_> = left.iter().map(|v| v % 2).collect();
+            let right: Vec<_> = right.iter().map(|v| v % 2).collect();
+
+            // pushing some elements at the front misaligns the runs of the two lists
+            let front = usize::from(front) % (left.len() + 1);
+            let mut l = ArrayList::<_, N>::from_iter(left[front..].iter().copied());
+            left[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| l.push_front(value));
+            let r = ArrayList::<_, N>::from_iter(right.iter().copied());
+
+            assert_eq!(l.cmp(&r), left.cmp(&right));
+            assert_eq!(r.cmp(&l), right.cmp(&left));
+            assert_eq!(l.partial_cmp(&r), left.partial_cmp(&right));
+            assert_eq!(l == r, left == right);
+            assert_eq!(l == *right.as_slice(), left == right);
+            assert!(l == *left.as_slice());
+        }
+
+        _test::<1>(&left, &right, front);
+        _test::<2>(&left, &right, front);
+        _test::<3>(&left, &right, front);
+        _test::<4>(&left, &right, front);
+        _test::<5>(&left, &right, front);
+        _test::<8>(&left, &right, front);
+        _test::<16>(&left, &right, front);
+        _test::<32>(&left, &right, front);
+    }
+
     #[test]
     fn test_hash_works_correctly() {
         let bh = BuildHasherDefault::<DefaultHasher>::default();
@@ -1785,7 +6284,50 @@ mod tests {
         let b = ArrayList::<usize, 2>::from([4, 5, 6]);
         assert_ne!(bh.hash_one(&a), bh.hash_one(&b));
         assert_eq!(bh.hash_one(&a), bh.hash_one(&a));
-        assert_eq!(bh.hash_one(&a), bh.hash_one(&(a.clone())));
+        assert_eq!(bh.hash_one(&a), bh.hash_one(a.clone()));
+    }
+
+    #[quickcheck]
+    fn test_hash_matches_slices(seed: Vec<u8>, pops: u8) {
+        fn _test<const N: usize>(seed: &[u8], pops: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let bh = BuildHasherDefault::<DefaultHasher>::default();
+
+            let mut bytes = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let mut words = ArrayList::<_, N>::from_iter(seed.iter().map(|&v| u32::from(v)));
+            let mut strings = ArrayList::<_, N>::from_iter(seed.iter().map(u8::to_string));
+
+            // rotate the front chunks so that they wrap around
+            for _ in 0..pops % 8 {
+                let value = bytes.pop_back().unwrap_or_default();
+                bytes.push_front(value);
+                let value = words.pop_back().unwrap_or_default();
+                words.push_front(value);
+                let value = strings.pop_back().unwrap_or_default();
+                strings.push_front(value);
+            }
+
+            let expected = Vec::from_iter(bytes.iter().copied());
+            assert_eq!(bh.hash_one(&bytes), bh.hash_one(&expected));
+            assert_eq!(bh.hash_one(&bytes), bh.hash_one(expected.as_slice()));
+
+            let expected = Vec::from_iter(words.iter().copied());
+            assert_eq!(bh.hash_one(&words), bh.hash_one(&expected));
+
+            let expected = Vec::from_iter(strings.iter().cloned());
+            assert_eq!(bh.hash_one(&strings), bh.hash_one(&expected));
+        }
+
+        _test::<1>(&seed, pops);
+        _test::<2>(&seed, pops);
+        _test::<3>(&seed, pops);
+        _test::<4>(&seed, pops);
+        _test::<5>(&seed, pops);
+        _test::<8>(&seed, pops);
+        _test::<16>(&seed, pops);
+        _test::<32>(&seed, pops);
     }
 
     #[test]
@@ -2075,6 +6617,137 @@ mod tests {
         _test::<64>();
     }
 
+    #[cfg(feature = "rayon")]
+    #[quickcheck]
+    fn test_from_par_iter_and_par_extend(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            use rayon::iter::{IntoParallelRefIterator, ParallelExtend, ParallelIterator};
+
+            let actual: ArrayList<_, N> = seed.par_iter().map(|v| v.wrapping_add(1)).collect();
+            let expected = Vec::from_iter(seed.iter().map(|v| v.wrapping_add(1)));
+            assert_eq!(actual, expected.as_slice());
+            assert_eq!(actual.iter().count(), actual.len());
+            assert!(
+                actual
+                    .chunks
+                    .iter()
+                    .all(|chunk| (1..=N).contains(&chunk.len()))
+            );
+
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            actual.par_extend(seed.par_iter());
+            let expected = [seed, seed].concat();
+            assert_eq!(actual, expected.as_slice());
+            assert_eq!(actual.iter().count(), actual.len());
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[quickcheck]
+    fn test_par_chunks_cover_contiguous_runs(seed: Vec<i32>, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            use rayon::iter::ParallelIterator;
+
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut actual = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| actual.push_front(value));
+
+            let runs: Vec<&[i32]> = actual.par_chunks().collect();
+            assert_eq!(runs, actual.iter().as_chunks().collect::<Vec<_>>());
+            assert!(runs.iter().all(|run| (1..=N).contains(&run.len())));
+
+            actual
+                .par_chunks_mut()
+                .for_each(|run| run.iter_mut().for_each(|v| *v = v.wrapping_mul(3)));
+            assert!(
+                actual
+                    .iter()
+                    .eq(&Vec::from_iter(seed.iter().map(|v| v.wrapping_mul(3))))
+            );
+        }
+
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
+
+    #[cfg(feature = "rand")]
+    #[quickcheck]
+    fn test_shuffle_keeps_elements_and_layout(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let layout: Vec<_> = sut.chunks.iter().map(VecDeque::len).collect();
+
+            sut.shuffle(&mut rand::rng());
+            assert_eq!(sut.len(), seed.len());
+            assert_eq!(
+                sut.chunks.iter().map(VecDeque::len).collect::<Vec<_>>(),
+                layout
+            );
+
+            let mut actual: Vec<_> = sut.iter().copied().collect();
+            let mut expected = seed.to_vec();
+            actual.sort();
+            expected.sort();
+            assert_eq!(actual, expected);
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_choose_picks_every_element() {
+        let mut sut = ArrayList::<usize, 2>::from([0, 1, 2, 3, 4]);
+        let mut seen = [false; 5];
+
+        for _ in 0..1000 {
+            seen[*sut.choose(&mut rand::rng()).unwrap()] = true;
+        }
+        assert!(seen.iter().all(|&v| v));
+
+        *sut.choose_mut(&mut rand::rng()).unwrap() = 42;
+        assert!(sut.iter().any(|&v| v == 42));
+
+        sut.clear();
+        assert_eq!(sut.choose(&mut rand::rng()), None);
+        assert_eq!(sut.choose_mut(&mut rand::rng()), None);
+    }
+
     #[quickcheck]
     fn nightly_test_array_list_behavioural(seed: VecDeque<i32>) {
         fn _test<const N: usize>(mut expected: VecDeque<i32>)
@@ -2095,7 +6768,7 @@ mod tests {
                 assert_eq!(expected.back(), actual.back());
                 assert_eq!(expected.back_mut(), actual.back_mut());
 
-                assert_eq!(expected.get(0), actual.get(0));
+                assert_eq!(expected.front(), actual.get(0));
                 assert_eq!(expected.get_mut(0), actual.get_mut(0));
 
                 assert_eq!(expected.get(1), actual.get(1));
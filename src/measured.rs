@@ -0,0 +1,409 @@
+use std::collections::VecDeque;
+
+use crate::chunk_sums::ChunkSums;
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A user-defined weight carried by each element of a [`MeasuredArrayList`],
+/// e.g. the length in bytes of a line of text.
+pub trait Measure {
+    /// Returns the weight of the element.
+    fn measure(&self) -> usize;
+}
+
+impl Measure for str {
+    fn measure(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Measure for String {
+    fn measure(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Measure for [T] {
+    fn measure(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Measure for Vec<T> {
+    fn measure(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Measure for &T
+where
+    T: Measure + ?Sized,
+{
+    fn measure(&self) -> usize {
+        T::measure(self)
+    }
+}
+
+const LEN: usize = 0;
+const WEIGHT: usize = 1;
+
+fn measure_chunk<T: Measure>(chunk: &VecDeque<T>) -> [usize; 2] {
+    [chunk.len(), chunk.iter().map(Measure::measure).sum()]
+}
+
+/// An ArrayList whose chunks keep track of the total weight of their elements.
+///
+/// The totals of the chunks and their lengths are aggregated in Fenwick trees, so that
+/// [`prefix_weight`](Self::prefix_weight) and [`find_by_weight`](Self::find_by_weight) only walk
+/// O(log chunks) nodes plus the elements of a single chunk. Elements can't be borrowed mutably,
+/// use [`update`](Self::update) to modify one and have its weight measured again.
+///
+/// # Examples
+/// ```
+/// use array_list::MeasuredArrayList;
+///
+/// let lines: MeasuredArrayList<_, 2> = ["fn main() {\n", "}\n"].into_iter().collect();
+///
+/// assert_eq!(lines.total_weight(), 14);
+/// assert_eq!(lines.prefix_weight(1), 12);
+/// assert_eq!(lines.find_by_weight(12), Some((1, 0)));
+/// ```
+pub struct MeasuredArrayList<T, const N: usize>
+where
+    T: Measure,
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    sums: ChunkSums<2>,
+}
+
+impl<T, const N: usize> MeasuredArrayList<T, N>
+where
+    T: Measure,
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a new, empty `MeasuredArrayList`.
+    pub const fn new() -> Self {
+        Self {
+            list: ArrayList::new(),
+            sums: ChunkSums::new(),
+        }
+    }
+
+    /// Returns the underlying list.
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the sum of the weights of all the elements.
+    pub fn total_weight(&self) -> usize {
+        self.sums.total(WEIGHT)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        self.list.chunks.get(chunk_index)?.get(target_index)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.edit(0, 1, |list| list.push_front(value));
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let last = self.list.chunks.len().saturating_sub(1);
+        self.edit(last, 1, |list| list.push_back(value));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.edit(0, 1, ArrayList::pop_front)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self.list.chunks.len().saturating_sub(1);
+        self.edit(last, 1, ArrayList::pop_back)
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        let chunk_index = self.sums.search(LEN, index).0;
+        let chunk_index = chunk_index.min(self.list.chunks.len().saturating_sub(1));
+
+        // the element may spill over into the next chunk
+        self.edit(chunk_index, 2, |list| list.insert(index, value));
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let chunk_index = self.sums.search(LEN, index).0;
+        self.edit(chunk_index, 1, |list| list.remove(index))
+    }
+
+    /// Calls `f` on the element at `index`, then measures it again.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::MeasuredArrayList;
+    ///
+    /// let mut lines: MeasuredArrayList<String, 2> = MeasuredArrayList::new();
+    /// lines.push_back("hello\n".to_string());
+    ///
+    /// lines.update(0, |line| line.insert_str(5, ", world"));
+    /// assert_eq!(lines.total_weight(), 13);
+    /// ```
+    pub fn update<R>(&mut self, index: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(self.edit(chunk_index, 1, |list| {
+            f(&mut list.chunks[chunk_index][target_index])
+        }))
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the sum of the weights of the elements before `index`.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn prefix_weight(&self, index: usize) -> usize {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "index (is {index}) should be <= len (is {len})"
+        );
+
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        let in_chunk = self.list.chunks.get(chunk_index).map_or(0, |chunk| {
+            chunk.range(..target_index).map(Measure::measure).sum()
+        });
+
+        self.sums.prefix(WEIGHT, chunk_index) + in_chunk
+    }
+
+    /// Finds the element spanning the given `weight` offset, that is the first one for which
+    /// the weights up to and including it exceed `weight`.
+    ///
+    /// Returns the index of the element along with the offset of `weight` within it,
+    /// or `None` if `weight` is not less than the total weight.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::MeasuredArrayList;
+    ///
+    /// let lines: MeasuredArrayList<_, 4> = ["one\n", "", "two\n"].into_iter().collect();
+    ///
+    /// assert_eq!(lines.find_by_weight(2), Some((0, 2)));
+    /// assert_eq!(lines.find_by_weight(4), Some((2, 0)));
+    /// assert_eq!(lines.find_by_weight(8), None);
+    /// ```
+    pub fn find_by_weight(&self, weight: usize) -> Option<(usize, usize)> {
+        let (chunk_index, mut offset) = self.sums.search(WEIGHT, weight);
+        let chunk = self.list.chunks.get(chunk_index)?;
+
+        let target_index = chunk.iter().position(|value| {
+            let measure = value.measure();
+            if offset < measure {
+                return true;
+            }
+
+            offset -= measure;
+            false
+        })?;
+
+        Some((self.sums.prefix(LEN, chunk_index) + target_index, offset))
+    }
+
+    /// Applies `f` to the list, then measures again the chunks that were in
+    /// `start..start + count` before the edit, which must be the only ones `f` touches.
+    fn edit<R>(
+        &mut self,
+        start: usize,
+        count: usize,
+        f: impl FnOnce(&mut ArrayList<T, N>) -> R,
+    ) -> R {
+        let out = f(&mut self.list);
+        self.sums
+            .refresh(&self.list.chunks, start, count, measure_chunk);
+
+        out
+    }
+}
+
+impl<T, const N: usize> Default for MeasuredArrayList<T, N>
+where
+    T: Measure,
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for MeasuredArrayList<T, N>
+where
+    T: Clone + Measure,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            sums: self.sums.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> From<ArrayList<T, N>> for MeasuredArrayList<T, N>
+where
+    T: Measure,
+    Usize<N>: ChunkCapacity,
+{
+    fn from(list: ArrayList<T, N>) -> Self {
+        Self {
+            sums: ChunkSums::from_chunks(&list.chunks, measure_chunk),
+            list,
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for MeasuredArrayList<T, N>
+where
+    T: Measure,
+    Usize<N>: ChunkCapacity,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(ArrayList::from_iter(iter))
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for MeasuredArrayList<T, N>
+where
+    T: core::fmt::Debug + Measure,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ChunkCapacity, Usize};
+
+    use super::MeasuredArrayList;
+
+    #[quickcheck]
+    fn test_measured_matches_vec(seed: Vec<String>, operations: Vec<(u8, usize, String)>) {
+        fn _test<const N: usize>(seed: &[String], operations: &[(u8, usize, String)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.to_vec();
+            let mut actual = MeasuredArrayList::<_, N>::from_iter(seed.iter().cloned());
+
+            for (operation, index, value) in operations {
+                let index = index % (expected.len() + 1);
+
+                match operation % 7 {
+                    0 => {
+                        expected.insert(0, value.clone());
+                        actual.push_front(value.clone());
+                    }
+                    1 => {
+                        expected.push(value.clone());
+                        actual.push_back(value.clone());
+                    }
+                    2 => {
+                        let value = (!expected.is_empty()).then(|| expected.remove(0));
+                        assert_eq!(actual.pop_front(), value);
+                    }
+                    3 => assert_eq!(actual.pop_back(), expected.pop()),
+                    4 => {
+                        expected.insert(index, value.clone());
+                        actual.insert(index, value.clone());
+                    }
+                    5 => {
+                        let value = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), value);
+                    }
+                    _ => {
+                        let updated = actual.update(index, |v| v.push_str(value));
+                        if let Some(v) = expected.get_mut(index) {
+                            v.push_str(value);
+                            assert_eq!(updated, Some(()));
+                        } else {
+                            assert_eq!(updated, None);
+                        }
+                    }
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(*actual.as_list(), expected.as_slice());
+                assert_eq!(
+                    actual.total_weight(),
+                    expected.iter().map(String::len).sum::<usize>()
+                );
+
+                let mut prefix = 0;
+                for (index, value) in expected.iter().enumerate() {
+                    assert_eq!(actual.get(index), Some(value));
+                    assert_eq!(actual.prefix_weight(index), prefix);
+
+                    if let Some(last) = value.len().checked_sub(1) {
+                        assert_eq!(actual.find_by_weight(prefix), Some((index, 0)));
+                        assert_eq!(actual.find_by_weight(prefix + last), Some((index, last)));
+                    }
+
+                    prefix += value.len();
+                }
+
+                assert_eq!(actual.get(expected.len()), None);
+                assert_eq!(actual.prefix_weight(expected.len()), prefix);
+                assert_eq!(actual.find_by_weight(prefix), None);
+            }
+        }
+
+        _test::<1>(seed.as_slice(), &operations);
+        _test::<2>(seed.as_slice(), &operations);
+        _test::<3>(seed.as_slice(), &operations);
+        _test::<4>(seed.as_slice(), &operations);
+        _test::<5>(seed.as_slice(), &operations);
+        _test::<8>(seed.as_slice(), &operations);
+        _test::<16>(seed.as_slice(), &operations);
+        _test::<32>(seed.as_slice(), &operations);
+    }
+}
@@ -0,0 +1,451 @@
+use std::ops::{Deref, Range, RangeBounds};
+
+use crate::{ArrayList, ChunkCapacity, SearchTarget, Usize};
+
+/// Receives the changes made to an [`ObservedArrayList`], as ranges of indices.
+///
+/// Every method is called right after the change and has an empty default implementation,
+/// so an observer only overrides what it needs.
+///
+/// # Examples
+/// ```
+/// use std::ops::Range;
+/// use std::sync::mpsc;
+///
+/// use array_list::{ArrayList, ListObserver};
+///
+/// struct Rows(mpsc::Sender<Range<usize>>);
+///
+/// impl ListObserver for Rows {
+///     fn inserted(&mut self, range: Range<usize>) {
+///         self.0.send(range).unwrap();
+///     }
+/// }
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let mut list = ArrayList::<_, 4>::from([0, 1, 2]).observe(Rows(sender));
+///
+/// list.insert(1, 42);
+/// list.extend([3, 4]);
+///
+/// assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [1..2, 4..6]);
+/// ```
+pub trait ListObserver {
+    /// The elements now in `range` have been inserted, the ones that were there shifted after them.
+    fn inserted(&mut self, range: Range<usize>) {
+        let _ = range;
+    }
+
+    /// The elements that were in `range` have been removed, the ones after them shifted back.
+    fn removed(&mut self, range: Range<usize>) {
+        let _ = range;
+    }
+
+    /// The elements that were in `from` have been moved to start at `to`,
+    /// as if they were removed and then inserted back.
+    fn moved(&mut self, from: Range<usize>, to: usize) {
+        let _ = (from, to);
+    }
+
+    /// The elements in `range` may have been changed in place.
+    fn changed(&mut self, range: Range<usize>) {
+        let _ = range;
+    }
+}
+
+/// An ArrayList that reports every change made to it to a [`ListObserver`].
+///
+/// The list is read through `Deref`, while editing goes through the methods of this type,
+/// so that no change goes unnoticed.
+///
+/// This struct is created by ArrayList::observe().
+pub struct ObservedArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    observer: Option<Box<dyn ListObserver>>,
+}
+
+impl<T, const N: usize> ObservedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: ArrayList<T, N>) -> Self {
+        Self {
+            list,
+            observer: None,
+        }
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    /// Replaces the observer that the changes are reported to.
+    pub fn set_observer(&mut self, observer: impl ListObserver + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Takes the observer out, the changes that follow go unreported until a new one is set.
+    pub fn take_observer(&mut self) -> Option<Box<dyn ListObserver>> {
+        self.observer.take()
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    ///
+    /// The element is reported as changed, whether it's actually written or not.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let value = self.list.get_mut(index)?;
+        if let Some(observer) = self.observer.as_mut() {
+            observer.changed(index..index + 1);
+        }
+
+        Some(value)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(value);
+        self.notify(|observer| observer.inserted(0..1));
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(value);
+        let len = self.list.len();
+        self.notify(|observer| observer.inserted(len - 1..len));
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.list.insert(index, value);
+        self.notify(|observer| observer.inserted(index..index + 1));
+    }
+
+    /// Moves all elements from the `other` list to the end of this one.
+    pub fn append(&mut self, other: &mut ArrayList<T, N>) {
+        let start = self.list.len();
+        self.list.append(other);
+
+        let end = self.list.len();
+        if start < end {
+            self.notify(|observer| observer.inserted(start..end));
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.list.pop_front()?;
+        self.notify(|observer| observer.removed(0..1));
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let value = self.list.pop_back()?;
+        let len = self.list.len();
+        self.notify(|observer| observer.removed(len..len + 1));
+        Some(value)
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let value = self.list.remove(index)?;
+        self.notify(|observer| observer.removed(index..index + 1));
+        Some(value)
+    }
+
+    /// Moves the elements in `src` so that they start at `dest` once moved, keeping their order.
+    ///
+    /// Whole chunks are moved as they are, only the chunks at the ends of `src`
+    /// and the one at `dest` may need to be split.
+    ///
+    /// # Panics
+    /// - Panics if `src` is out of bounds, or if its start is greater than its end.
+    /// - Panics if `dest + src.len()` is greater than the list's current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<_, 2>::from(['a', 'b', 'c', 'd', 'e']).observe(());
+    ///
+    /// list.move_range(3..5, 1);
+    /// assert_eq!(*list, ['a', 'd', 'e', 'b', 'c']);
+    /// ```
+    #[track_caller]
+    pub fn move_range<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = self.list.resolve_range(src);
+        let len = self.list.len();
+        assert!(
+            dest <= len - (end - start),
+            "dest is out of bounds: {dest} + {} should be <= len (is {len})",
+            end - start
+        );
+
+        if start == end || start == dest {
+            return;
+        }
+
+        let SearchTarget {
+            chunk_index,
+            target_index,
+        } = self.list.search_target(start).unwrap();
+        let mut moved = self
+            .list
+            .split_range(chunk_index, target_index, end - start);
+
+        let SearchTarget {
+            chunk_index,
+            target_index,
+        } = self.list.search_target(dest).unwrap_or(SearchTarget {
+            chunk_index: self.list.chunks.len(),
+            target_index: 0,
+        });
+        self.list
+            .splice_chunks(chunk_index, target_index, &mut moved);
+
+        self.notify(|observer| observer.moved(start..end, dest));
+    }
+
+    pub fn clear(&mut self) {
+        let len = self.list.len();
+        self.list.clear();
+        if len > 0 {
+            self.notify(|observer| observer.removed(0..len));
+        }
+    }
+
+    fn notify(&mut self, f: impl FnOnce(&mut dyn ListObserver)) {
+        if let Some(observer) = self.observer.as_deref_mut() {
+            f(observer);
+        }
+    }
+}
+
+/// The unit observer ignores every change.
+impl ListObserver for () {}
+
+impl<T, const N: usize> Clone for ObservedArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    /// Clones the list, without any observer.
+    fn clone(&self) -> Self {
+        Self::from_list(self.list.clone())
+    }
+}
+
+impl<T, const N: usize> Deref for ObservedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ObservedArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Pushes every element at the back, reporting them as a single insertion.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let start = self.list.len();
+        self.list.extend(iter);
+
+        let end = self.list.len();
+        if start < end {
+            self.notify(|observer| observer.inserted(start..end));
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ObservedArrayList<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::ops::Range;
+    use std::rc::Rc;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::ListObserver;
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Inserted(Range<usize>),
+        Removed(Range<usize>),
+        Moved(Range<usize>, usize),
+        Changed(Range<usize>),
+    }
+
+    /// Mirrors the list it observes, as a UI would do with its rows.
+    #[derive(Clone, Default)]
+    struct Mirror {
+        rows: Rc<RefCell<Vec<Option<i32>>>>,
+        events: Rc<RefCell<Vec<Event>>>,
+    }
+
+    impl ListObserver for Mirror {
+        fn inserted(&mut self, range: Range<usize>) {
+            let start = range.start;
+            self.rows
+                .borrow_mut()
+                .splice(start..start, range.clone().map(|_| None));
+            self.events.borrow_mut().push(Event::Inserted(range));
+        }
+
+        fn removed(&mut self, range: Range<usize>) {
+            self.rows.borrow_mut().drain(range.clone());
+            self.events.borrow_mut().push(Event::Removed(range));
+        }
+
+        fn moved(&mut self, from: Range<usize>, to: usize) {
+            let mut rows = self.rows.borrow_mut();
+            let moved: Vec<_> = rows.drain(from.clone()).collect();
+            rows.splice(to..to, moved);
+            self.events.borrow_mut().push(Event::Moved(from, to));
+        }
+
+        fn changed(&mut self, range: Range<usize>) {
+            self.events.borrow_mut().push(Event::Changed(range));
+        }
+    }
+
+    #[test]
+    fn test_observer_receives_changes() {
+        let mirror = Mirror::default();
+        *mirror.rows.borrow_mut() = vec![None; 3];
+        let mut sut = ArrayList::<_, 2>::from([0, 1, 2]).observe(mirror.clone());
+
+        sut.push_front(-1);
+        sut.remove(2);
+        *sut.get_mut(0).unwrap() = -2;
+        sut.move_range(1..3, 1);
+        sut.move_range(0..2, 1);
+        sut.clear();
+
+        assert!(sut.is_empty());
+        assert_eq!(
+            *mirror.events.borrow(),
+            [
+                Event::Inserted(0..1),
+                Event::Removed(2..3),
+                Event::Changed(0..1),
+                Event::Moved(0..2, 1),
+                Event::Removed(0..3),
+            ]
+        );
+
+        let observer = sut.take_observer();
+        sut.push_back(0);
+        assert!(observer.is_some());
+        assert_eq!(mirror.events.borrow().len(), 5);
+    }
+
+    #[quickcheck]
+    fn test_observer_mirrors_list(seed: Vec<i32>, operations: Vec<(u8, usize, usize)>) {
+        fn _test<const N: usize>(seed: &[i32], operations: &[(u8, usize, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mirror = Mirror::default();
+            *mirror.rows.borrow_mut() = seed.iter().map(|&value| Some(value)).collect();
+
+            let mut sut = ArrayList::<_, N>::from_iter(seed.iter().copied()).observe(());
+            sut.set_observer(mirror.clone());
+            let mut expected = seed.to_vec();
+
+            for &(operation, a, b) in operations {
+                let index = a % (expected.len() + 1);
+                match operation % 6 {
+                    0 => {
+                        sut.insert(index, a as i32);
+                        expected.insert(index, a as i32);
+                    }
+                    1 => {
+                        sut.extend([a as i32, b as i32]);
+                        expected.extend([a as i32, b as i32]);
+                    }
+                    2 => {
+                        let removed = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(sut.remove(index), removed);
+                    }
+                    3 => {
+                        assert_eq!(sut.pop_back(), expected.pop());
+                        assert_eq!(
+                            sut.pop_front(),
+                            (!expected.is_empty()).then(|| expected.remove(0))
+                        );
+                    }
+                    4 => {
+                        let end = index + b % (expected.len() - index + 1);
+                        let dest = b % (expected.len() - (end - index) + 1);
+                        sut.move_range(index..end, dest);
+
+                        let moved: Vec<_> = expected.drain(index..end).collect();
+                        expected.splice(dest..dest, moved);
+                    }
+                    _ => {
+                        sut.push_front(a as i32);
+                        expected.insert(0, a as i32);
+                    }
+                }
+
+                // the rows inserted into the mirror are filled the first time they're seen
+                let mut rows = mirror.rows.borrow_mut();
+                assert_eq!(rows.len(), expected.len());
+                for (row, &value) in rows.iter_mut().zip(&expected) {
+                    assert_eq!(*row.get_or_insert(value), value);
+                }
+
+                assert!(sut.iter().eq(expected.iter()));
+                assert!(
+                    sut.chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+
+            let rows = mirror.rows.borrow();
+            assert!(
+                rows.iter()
+                    .map(|row| row.unwrap())
+                    .eq(expected.iter().copied())
+            );
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::VecDeque;
+
+use rayon::collections::vec_deque;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::{FlattenIter, IntoParallelIterator, ParallelIterator};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A parallel iterator over the elements of an ArrayList.
+///
+/// Work is split on chunk boundaries, each chunk is then iterated sequentially.
+///
+/// This struct is created by ArrayList::par_iter().
+pub struct ParIter<'a, T, const N: usize>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    delegate: FlattenIter<vec_deque::Iter<'a, VecDeque<T>>>,
+}
+
+impl<T, const N: usize> Clone for ParIter<'_, T, N>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            delegate: self.delegate.clone(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ParIter<'a, T, N>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: &'a ArrayList<T, N>) -> Self {
+        Self {
+            delegate: (&list.chunks).into_par_iter().flatten_iter(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ParallelIterator for ParIter<'a, T, N>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.delegate.drive_unindexed(consumer)
+    }
+}
+
+impl<'a, T, const N: usize> IntoParallelIterator for &'a ArrayList<T, N>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
+    type Iter = ParIter<'a, T, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter::from_list(self)
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ParIter<'_, T, N>
+where
+    T: Sync,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParIter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_par_iter_behavioural(seed: Vec<i32>) {
+        fn _test<const N: usize>(expected: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let actual = ArrayList::<_, N>::from_iter(expected.iter().copied());
+
+            assert_eq!(actual.par_iter().count(), expected.len());
+            assert_eq!(actual.par_iter().max(), expected.iter().max());
+            assert_eq!(
+                actual.par_iter().map(|&v| i64::from(v)).sum::<i64>(),
+                expected.iter().map(|&v| i64::from(v)).sum::<i64>()
+            );
+            assert_eq!(
+                actual.par_iter().collect::<Vec<_>>(),
+                Vec::from_iter(expected)
+            );
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+use rayon::collections::vec_deque;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::{FlattenIter, IntoParallelIterator, ParallelIterator};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// A parallel iterator over the elements of an ArrayList.
+///
+/// Work is split on chunk boundaries, each chunk is then iterated sequentially.
+///
+/// This struct is created by ArrayList::par_iter_mut().
+pub struct ParIterMut<'a, T, const N: usize>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    delegate: FlattenIter<vec_deque::IterMut<'a, VecDeque<T>>>,
+}
+
+impl<'a, T, const N: usize> ParIterMut<'a, T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: &'a mut ArrayList<T, N>) -> Self {
+        Self {
+            delegate: (&mut list.chunks).into_par_iter().flatten_iter(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ParallelIterator for ParIterMut<'a, T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.delegate.drive_unindexed(consumer)
+    }
+}
+
+impl<'a, T, const N: usize> IntoParallelIterator for &'a mut ArrayList<T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a mut T;
+    type Iter = ParIterMut<'a, T, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut::from_list(self)
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ParIterMut<'_, T, N>
+where
+    T: Send,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParIterMut").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+    use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_par_iter_mut_behavioural(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied());
+            let expected = Vec::from_iter(seed.iter().map(|v| v.wrapping_mul(3)));
+
+            actual.par_iter_mut().for_each(|v| *v = v.wrapping_mul(3));
+            assert_eq!(actual, expected.as_slice());
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+}
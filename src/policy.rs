@@ -0,0 +1,343 @@
+use core::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// Decides how a [`PolicyArrayList`] lays out its elements in chunks as it's edited.
+///
+/// Every method is given the capacity of the chunks, and has a default implementation
+/// matching the behavior of a plain ArrayList, so a policy only overrides what it needs.
+///
+/// # Examples
+/// ```
+/// use array_list::{ArrayList, ChunkPolicy};
+///
+/// /// Keeps some room in the chunks for later insertions, splits full chunks in half,
+/// /// and merges the chunks that get too small.
+/// struct Churn;
+///
+/// impl ChunkPolicy for Churn {
+///     fn push_limit(capacity: usize) -> usize {
+///         capacity - capacity / 4
+///     }
+///
+///     fn split_len(capacity: usize) -> usize {
+///         capacity / 2
+///     }
+///
+///     fn should_merge(len: usize, _neighbor_len: usize, capacity: usize) -> bool {
+///         len < capacity / 4
+///     }
+/// }
+///
+/// let mut list = ArrayList::<_, 8>::with_policy::<Churn>();
+/// list.extend(0..12);
+/// list.insert(3, 42);
+///
+/// assert_eq!(list.remove(3), Some(42));
+/// assert!(list.iter().eq(&Vec::from_iter(0..12)));
+/// ```
+pub trait ChunkPolicy {
+    /// Returns how many elements `push_front` and `push_back` put in the chunk at the end of
+    /// the list before starting a new one, leaving the rest of it free for later insertions.
+    ///
+    /// The value is clamped between 1 and `capacity`, which is also the default.
+    fn push_limit(capacity: usize) -> usize {
+        capacity
+    }
+
+    /// Returns how many elements are moved from the back of a full chunk to make room
+    /// for an insertion, either to the front of the next chunk if they fit, or to a new chunk.
+    ///
+    /// The value is clamped between 1 and `capacity - 1`, the default is 1.
+    fn split_len(capacity: usize) -> usize {
+        let _ = capacity;
+        1
+    }
+
+    /// Returns whether a chunk left with `len` elements after a removal should be merged
+    /// with a neighbor holding `neighbor_len` elements. It's only asked when they fit in one chunk.
+    ///
+    /// By default chunks are never merged, they are only dropped once empty.
+    fn should_merge(len: usize, neighbor_len: usize, capacity: usize) -> bool {
+        let _ = (len, neighbor_len, capacity);
+        false
+    }
+}
+
+/// The policy followed by a plain ArrayList: chunks are filled up, a full chunk spills
+/// a single element on insertion, and chunks are never merged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DefaultPolicy;
+
+impl ChunkPolicy for DefaultPolicy {}
+
+/// An ArrayList whose insertions and removals follow the chunk policy `P`.
+///
+/// The list is read through `Deref`, while editing goes through the methods of this type.
+///
+/// This struct is created by ArrayList::with_policy().
+pub struct PolicyArrayList<T, const N: usize, P>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<T, const N: usize, P> PolicyArrayList<T, N, P>
+where
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) const fn from_list(list: ArrayList<T, N>) -> Self {
+        Self {
+            list,
+            policy: PhantomData,
+        }
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front_with::<P>(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back_with::<P>(value);
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.list.insert_with::<P>(index, value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.remove_with::<P>(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let index = self.list.len().checked_sub(1)?;
+        self.list.remove_with::<P>(index)
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.list.remove_with::<P>(index)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl<T, const N: usize, P> Default for PolicyArrayList<T, N, P>
+where
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::from_list(ArrayList::new())
+    }
+}
+
+impl<T, const N: usize, P> Clone for PolicyArrayList<T, N, P>
+where
+    T: Clone,
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self::from_list(self.list.clone())
+    }
+}
+
+impl<T, const N: usize, P> Deref for PolicyArrayList<T, N, P>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize, P> From<ArrayList<T, N>> for PolicyArrayList<T, N, P>
+where
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    /// Takes over the list as it is, the policy only applies to the edits that follow.
+    fn from(list: ArrayList<T, N>) -> Self {
+        Self::from_list(list)
+    }
+}
+
+impl<T, const N: usize, P> Extend<T> for PolicyArrayList<T, N, P>
+where
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T, const N: usize, P> FromIterator<T> for PolicyArrayList<T, N, P>
+where
+    P: ChunkPolicy,
+    Usize<N>: ChunkCapacity,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize, P> core::fmt::Debug for PolicyArrayList<T, N, P>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::{ChunkPolicy, DefaultPolicy, PolicyArrayList};
+
+    struct Churn;
+
+    impl ChunkPolicy for Churn {
+        fn push_limit(capacity: usize) -> usize {
+            capacity / 2
+        }
+
+        fn split_len(capacity: usize) -> usize {
+            capacity / 2
+        }
+
+        fn should_merge(len: usize, _neighbor_len: usize, capacity: usize) -> bool {
+            len < capacity / 4
+        }
+    }
+
+    fn chunk_lens<const N: usize>(list: &ArrayList<i32, N>) -> Vec<usize>
+    where
+        Usize<N>: ChunkCapacity,
+    {
+        list.chunks.iter().map(|chunk| chunk.len()).collect()
+    }
+
+    #[test]
+    fn test_policy_shapes_chunks() {
+        let mut sut = PolicyArrayList::<_, 8, Churn>::from_iter(0..12);
+        assert_eq!(chunk_lens(&sut), [4, 4, 4]);
+
+        (0..4).for_each(|_| sut.insert(5, -1));
+        assert_eq!(chunk_lens(&sut), [4, 8, 4]);
+
+        sut.insert(6, -2);
+        assert_eq!(chunk_lens(&sut), [4, 5, 8]);
+
+        sut.insert(13, -3);
+        assert_eq!(chunk_lens(&sut), [4, 5, 5, 4]);
+
+        for _ in 0..4 {
+            sut.pop_front();
+        }
+        assert_eq!(chunk_lens(&sut), [5, 5, 4]);
+
+        for _ in 0..3 {
+            sut.pop_back();
+        }
+        assert_eq!(chunk_lens(&sut), [5, 6]);
+        assert_eq!(sut.into_list(), [4, -1, -2, -1, -1, -1, 5, 6, 7, -3, 8]);
+
+        let mut plain = PolicyArrayList::<_, 8, DefaultPolicy>::from_iter(0..12);
+        plain.insert(1, -1);
+        assert_eq!(chunk_lens(&plain), [8, 5]);
+    }
+
+    #[quickcheck]
+    fn test_policy_matches_vec(operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut actual = PolicyArrayList::<_, N, Churn>::default();
+            let mut expected = Vec::new();
+
+            for &(operation, value) in operations {
+                let index = value.unsigned_abs() as usize % (expected.len() + 1);
+                match operation % 6 {
+                    0 => {
+                        actual.push_front(value);
+                        expected.insert(0, value);
+                    }
+                    1 => {
+                        actual.push_back(value);
+                        expected.push(value);
+                    }
+                    2 | 3 => {
+                        actual.insert(index, value);
+                        expected.insert(index, value);
+                    }
+                    4 => {
+                        let removed = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), removed);
+                    }
+                    _ => {
+                        assert_eq!(
+                            actual.pop_front(),
+                            (!expected.is_empty()).then(|| expected.remove(0))
+                        );
+                        assert_eq!(actual.pop_back(), expected.pop());
+                    }
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(&operations);
+        _test::<2>(&operations);
+        _test::<3>(&operations);
+        _test::<4>(&operations);
+        _test::<5>(&operations);
+        _test::<8>(&operations);
+        _test::<16>(&operations);
+        _test::<32>(&operations);
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::VecDeque;
+use std::ops::Deref;
+
+use crate::{ArrayList, ChunkCapacity, SearchTarget, Usize};
+
+/// An ArrayList whose chunks all come from a pool allocated up front,
+/// so that editing it never calls the global allocator.
+///
+/// The pool holds a fixed number of chunks: pushing and inserting take one out of it when
+/// the list needs a new chunk, failing once it's exhausted, while removing hands the chunks
+/// that get empty back to it. The list of chunks itself is allocated with room for all of them.
+/// Elements that own heap memory still allocate and free it on their own.
+///
+/// The list is read through `Deref`, while editing goes through the methods of this type.
+///
+/// This struct is created by ArrayList::with_pool().
+pub struct PooledArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    /// The empty chunks not in use by the list, with room for every chunk of the pool.
+    pool: Vec<VecDeque<T>>,
+}
+
+impl<T, const N: usize> PooledArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn new(chunks: usize) -> Self {
+        let mut list = ArrayList::new();
+        list.chunks.reserve_exact(chunks);
+
+        let mut pool = Vec::with_capacity(chunks);
+        pool.extend((0..chunks).map(|_| {
+            list.counters.allocation();
+            VecDeque::with_capacity(N)
+        }));
+
+        Self { list, pool }
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list and dropping the unused chunks.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    /// Returns the number of chunks left in the pool.
+    pub fn spare_chunks(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Adds an element to the front of the list, taking a chunk from the pool if the front one
+    /// is full. Hands `value` back if the pool is exhausted.
+    pub fn try_push_front(&mut self, value: T) -> Result<(), T> {
+        match self.list.chunks.front_mut() {
+            Some(chunk) if chunk.len() < N => chunk.push_front(value),
+            _ => {
+                let Some(mut chunk) = self.pool.pop() else {
+                    return Err(value);
+                };
+
+                chunk.push_front(value);
+                self.list.chunks.push_front(chunk);
+            }
+        }
+
+        self.list.len += 1;
+        Ok(())
+    }
+
+    /// Adds an element to the back of the list, taking a chunk from the pool if the back one
+    /// is full. Hands `value` back if the pool is exhausted.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list = ArrayList::<_, 2>::with_pool(2);
+    /// assert_eq!(list.try_push_back(0), Ok(()));
+    /// assert_eq!(list.try_push_back(1), Ok(()));
+    /// assert_eq!(list.try_push_back(2), Ok(()));
+    /// assert_eq!(list.try_push_back(3), Ok(()));
+    /// assert_eq!(list.try_push_back(4), Err(4));
+    ///
+    /// // the emptied chunk goes back to the pool
+    /// assert_eq!(list.pop_front(), Some(0));
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.try_push_back(4), Ok(()));
+    /// assert_eq!(*list, [2, 3, 4]);
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Result<(), T> {
+        match self.list.chunks.back_mut() {
+            Some(chunk) if chunk.len() < N => chunk.push_back(value),
+            _ => {
+                let Some(mut chunk) = self.pool.pop() else {
+                    return Err(value);
+                };
+
+                chunk.push_back(value);
+                self.list.chunks.push_back(chunk);
+            }
+        }
+
+        self.list.len += 1;
+        Ok(())
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// A full chunk makes room by moving its last element to the next chunk, or to a chunk
+    /// taken from the pool if the next one is full too. Hands `value` back if the pool is exhausted.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), T> {
+        let len = self.list.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        let Some(SearchTarget {
+            chunk_index,
+            target_index,
+        }) = self.list.search_target(index)
+        else {
+            return self.try_push_back(value);
+        };
+
+        if self.list.chunks[chunk_index].len() >= N {
+            match self.list.chunks.get(chunk_index + 1) {
+                Some(next) if next.len() < N => {}
+                _ => {
+                    let Some(chunk) = self.pool.pop() else {
+                        return Err(value);
+                    };
+                    self.list.chunks.insert(chunk_index + 1, chunk);
+                }
+            }
+
+            let mut chunks = self.list.chunks.range_mut(chunk_index..=chunk_index + 1);
+            let (Some(chunk), Some(next)) = (chunks.next(), chunks.next()) else {
+                unreachable!()
+            };
+            next.push_front(chunk.pop_back().unwrap());
+        }
+
+        self.list.chunks[chunk_index].insert(target_index, value);
+        self.list.len += 1;
+        Ok(())
+    }
+
+    /// Removes the first element, handing its chunk back to the pool if it gets empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let chunk = self.list.chunks.front_mut()?;
+        let value = chunk.pop_front();
+        if chunk.is_empty() {
+            self.recycle(0);
+        }
+
+        self.list.len -= 1;
+        value
+    }
+
+    /// Removes the last element, handing its chunk back to the pool if it gets empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let chunk = self.list.chunks.back_mut()?;
+        let value = chunk.pop_back();
+        if chunk.is_empty() {
+            self.recycle(self.list.chunks.len() - 1);
+        }
+
+        self.list.len -= 1;
+        value
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let SearchTarget {
+            chunk_index,
+            target_index,
+        } = self.list.search_target(index)?;
+
+        let chunk = &mut self.list.chunks[chunk_index];
+        let value = chunk.remove(target_index);
+        if chunk.is_empty() {
+            self.recycle(chunk_index);
+        }
+
+        self.list.len -= 1;
+        value
+    }
+
+    /// Removes all the elements, handing every chunk back to the pool.
+    pub fn clear(&mut self) {
+        while let Some(mut chunk) = self.list.chunks.pop_back() {
+            chunk.clear();
+            self.pool.push(chunk);
+        }
+
+        self.list.len = 0;
+    }
+
+    /// Hands the empty chunk at `chunk_index` back to the pool.
+    fn recycle(&mut self, chunk_index: usize) {
+        let chunk = self.list.chunks.remove(chunk_index).unwrap();
+        debug_assert!(chunk.is_empty());
+        self.pool.push(chunk);
+    }
+}
+
+impl<T, const N: usize> Deref for PooledArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for PooledArrayList<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_pool_reuses_its_chunks() {
+        let mut sut = ArrayList::<usize, 4>::with_pool(3);
+        let chunks = sut.list.chunks.capacity();
+
+        (0..12).for_each(|value| sut.try_push_back(value).unwrap());
+        assert_eq!(sut.try_push_front(12), Err(12));
+        assert_eq!(sut.try_insert(5, 12), Err(12));
+        assert_eq!(sut.spare_chunks(), 0);
+
+        sut.remove(5);
+        sut.try_insert(1, 42).unwrap();
+        assert_eq!(*sut, [0, 42, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
+
+        sut.clear();
+        (0..6).for_each(|value| sut.try_push_front(value).unwrap());
+        assert_eq!(sut.spare_chunks(), 1);
+        assert_eq!(*sut, [5, 4, 3, 2, 1, 0]);
+
+        // neither the list of chunks nor the pool had to grow
+        assert_eq!(sut.list.chunks.capacity(), chunks);
+        assert_eq!(sut.pool.capacity(), 3);
+    }
+
+    #[quickcheck]
+    fn test_pool_matches_vec(chunks: u8, operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(chunks: u8, operations: &[(u8, i32)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let chunks = usize::from(chunks % 16);
+            let mut actual = ArrayList::<_, N>::with_pool(chunks);
+            let mut expected = Vec::new();
+
+            for &(operation, value) in operations {
+                let index = value.unsigned_abs() as usize % (expected.len() + 1);
+                let pushed = match operation % 6 {
+                    0 => actual
+                        .try_push_front(value)
+                        .map(|()| expected.insert(0, value)),
+                    1 => actual.try_push_back(value).map(|()| expected.push(value)),
+                    2 | 3 => actual
+                        .try_insert(index, value)
+                        .map(|()| expected.insert(index, value)),
+                    4 => {
+                        let removed = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), removed);
+                        Ok(())
+                    }
+                    _ => {
+                        assert_eq!(
+                            actual.pop_front(),
+                            (!expected.is_empty()).then(|| expected.remove(0))
+                        );
+                        assert_eq!(actual.pop_back(), expected.pop());
+                        Ok(())
+                    }
+                };
+
+                if pushed.is_err() {
+                    assert_eq!(actual.spare_chunks(), 0);
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert_eq!(actual.list.chunks.len() + actual.spare_chunks(), chunks);
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(chunks, &operations);
+        _test::<2>(chunks, &operations);
+        _test::<3>(chunks, &operations);
+        _test::<4>(chunks, &operations);
+        _test::<5>(chunks, &operations);
+        _test::<8>(chunks, &operations);
+        _test::<16>(chunks, &operations);
+        _test::<32>(chunks, &operations);
+    }
+}
@@ -0,0 +1,54 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+impl<T, const N: usize> Arbitrary for ArrayList<T, N>
+where
+    T: Arbitrary,
+    Usize<N>: ChunkCapacity,
+{
+    /// Generates a list whose elements are inserted at arbitrary positions,
+    /// so that chunks are not always filled up to `N`.
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut list = Self::new();
+
+        for value in Vec::<T>::arbitrary(g) {
+            let index = usize::arbitrary(g) % (list.len() + 1);
+            list.insert(index, value);
+        }
+
+        list
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let values: Vec<T> = self.iter().cloned().collect();
+        Box::new(values.shrink().map(Self::from_iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::Arbitrary;
+    use quickcheck_macros::quickcheck;
+
+    use crate::ArrayList;
+
+    #[quickcheck]
+    fn test_arbitrary_list_is_consistent(list: ArrayList<i32, 3>) {
+        assert_eq!(list.iter().count(), list.len());
+        assert_eq!(list.is_empty(), list.front().is_none());
+        assert!(
+            list.chunks
+                .iter()
+                .all(|chunk| (1..=3).contains(&chunk.len()))
+        );
+    }
+
+    #[test]
+    fn test_shrink_yields_smaller_lists() {
+        let list = ArrayList::<i32, 2>::from([1, 2, 3, 4, 5]);
+        assert!(list.shrink().all(|shrunk| shrunk.len() <= list.len()));
+        assert!(list.shrink().any(|shrunk| shrunk.is_empty()));
+        assert!(ArrayList::<i32, 2>::new().shrink().next().is_none());
+    }
+}
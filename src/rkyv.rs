@@ -0,0 +1,109 @@
+use rkyv::rancor::Fallible;
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+impl<T, const N: usize> Archive for ArrayList<T, N>
+where
+    T: Archive,
+    Usize<N>: ChunkCapacity,
+{
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(self.len(), resolver, out);
+    }
+}
+
+impl<T, S, const N: usize> Serialize<S> for ArrayList<T, N>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+    Usize<N>: ChunkCapacity,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        if let (Some(chunk), 1) = (self.chunks.front(), self.chunks.len()) {
+            if let (slice, []) = chunk.as_slices() {
+                return ArchivedVec::<T::Archived>::serialize_from_slice(slice, serializer);
+            }
+        }
+
+        ArchivedVec::<T::Archived>::serialize_from_iter::<T, _, _>(self.iter(), serializer)
+    }
+}
+
+impl<T, D, const N: usize> Deserialize<ArrayList<T, N>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    Usize<N>: ChunkCapacity,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<ArrayList<T, N>, D::Error> {
+        self.iter()
+            .map(|value| value.deserialize(deserializer))
+            .collect()
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<ArrayList<U, N>> for ArchivedVec<T>
+where
+    T: PartialEq<U>,
+    Usize<N>: ChunkCapacity,
+{
+    fn eq(&self, other: &ArrayList<U, N>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_archive_flattens_chunks() {
+        let list = ArrayList::<i32, 2>::from([0, 1, 2, 3, 4]);
+
+        let bytes = rkyv::to_bytes::<Error>(&list).unwrap();
+        let archived = rkyv::access::<ArchivedVec<rkyv::Archived<i32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.as_slice(), [0, 1, 2, 3, 4]);
+        assert_eq!(*archived, list);
+
+        let actual = rkyv::deserialize::<ArrayList<i32, 3>, Error>(archived).unwrap();
+        assert_eq!(actual, [0, 1, 2, 3, 4]);
+        assert_eq!(
+            format!("{:?}", actual.debug_chunks()),
+            "[[0, 1, 2], [3, 4]]"
+        );
+    }
+
+    #[quickcheck]
+    fn test_archive_round_trip(seed: Vec<i32>) {
+        fn _test<const N: usize>(seed: &[i32])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let list = ArrayList::<_, N>::from_iter(seed.iter().copied());
+
+            let bytes = rkyv::to_bytes::<Error>(&list).unwrap();
+            let actual = rkyv::from_bytes::<ArrayList<i32, N>, Error>(&bytes).unwrap();
+            assert_eq!(actual, seed);
+        }
+
+        _test::<1>(&seed);
+        _test::<2>(&seed);
+        _test::<3>(&seed);
+        _test::<4>(&seed);
+        _test::<5>(&seed);
+        _test::<8>(&seed);
+        _test::<16>(&seed);
+        _test::<32>(&seed);
+    }
+}
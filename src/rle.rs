@@ -0,0 +1,345 @@
+use std::iter;
+
+use crate::{ChunkCapacity, Measure, MeasuredArrayList, Usize};
+
+/// A value repeated `count` times in a row, never zero.
+#[derive(Clone, Debug)]
+struct Run<T> {
+    value: T,
+    count: usize,
+}
+
+impl<T> Measure for Run<T> {
+    fn measure(&self) -> usize {
+        self.count
+    }
+}
+
+/// A list of elements stored as runs of equal values, each run taking a single slot
+/// of a chunk no matter how long it is.
+///
+/// It's meant for sequences dominated by long runs of repeated values, such as tile maps,
+/// where storing every element wastes most of the memory. The runs are kept in a
+/// [`MeasuredArrayList`] weighted by their lengths, so that indexing an element only walks
+/// O(log chunks) nodes plus the runs of a single chunk. Adjacent runs never hold equal values:
+/// edits merge them back together.
+///
+/// # Examples
+/// ```
+/// use array_list::RleArrayList;
+///
+/// let mut tiles: RleArrayList<_, 4> = "wwwwwwggggwwww".chars().collect();
+/// assert_eq!(tiles.len(), 14);
+/// assert_eq!(tiles.runs_len(), 3);
+///
+/// tiles.replace(5, 'g');
+/// assert_eq!(tiles.get(5), Some(&'g'));
+/// assert!(tiles.runs().eq([(&'w', 5), (&'g', 5), (&'w', 4)]));
+/// ```
+pub struct RleArrayList<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    runs: MeasuredArrayList<Run<T>, N>,
+}
+
+impl<T, const N: usize> RleArrayList<T, N>
+where
+    T: Clone + PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a new, empty `RleArrayList`.
+    pub const fn new() -> Self {
+        Self {
+            runs: MeasuredArrayList::new(),
+        }
+    }
+
+    /// Returns the number of elements, counting every repetition.
+    pub fn len(&self) -> usize {
+        self.runs.total_weight()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// Returns the number of runs of equal values.
+    pub const fn runs_len(&self) -> usize {
+        self.runs.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (run_index, _) = self.runs.find_by_weight(index)?;
+        self.runs.get(run_index).map(|run| &run.value)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.insert(0, value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.insert(self.len(), value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.len().checked_sub(1)?)
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// The element joins the run it lands in or next to if it holds an equal value,
+    /// otherwise it starts a new run, splitting the one it lands in if any.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        let (run_index, offset) = self
+            .runs
+            .find_by_weight(index)
+            .unwrap_or((self.runs.len(), 0));
+
+        // joins the run the element lands in, or the one ending right before it
+        let joined = [
+            Some(run_index),
+            run_index.checked_sub(1).filter(|_| offset == 0),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|&run_index| {
+            self.runs
+                .get(run_index)
+                .is_some_and(|run| run.value == value)
+        });
+
+        if let Some(run_index) = joined {
+            self.runs.update(run_index, |run| run.count += 1);
+            return;
+        }
+
+        if offset > 0 {
+            let rest = self.runs.update(run_index, |run| {
+                let rest = run.count - offset;
+                run.count = offset;
+                Run {
+                    value: run.value.clone(),
+                    count: rest,
+                }
+            });
+
+            self.runs.insert(run_index + 1, rest.unwrap());
+        }
+
+        let run_index = run_index + usize::from(offset > 0);
+        self.runs.insert(run_index, Run { value, count: 1 });
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (run_index, _) = self.runs.find_by_weight(index)?;
+
+        let value = self.runs.update(run_index, |run| {
+            run.count -= 1;
+            (run.count > 0).then(|| run.value.clone())
+        })?;
+
+        if let Some(value) = value {
+            return Some(value);
+        }
+
+        let run = self.runs.remove(run_index)?;
+        self.merge(run_index);
+        Some(run.value)
+    }
+
+    /// Replaces the element at `index`, returning the previous one.
+    ///
+    /// Returns `None`, leaving the list untouched, if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, value: T) -> Option<T> {
+        let previous = self.remove(index)?;
+        self.insert(index, value);
+        Some(previous)
+    }
+
+    pub fn clear(&mut self) {
+        self.runs.clear();
+    }
+
+    /// Returns an iterator over the elements, repeating the value of every run.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.runs
+            .as_list()
+            .iter()
+            .flat_map(|run| iter::repeat_n(&run.value, run.count))
+    }
+
+    /// Returns an iterator over the runs, as their values along with how many times they repeat.
+    pub fn runs(&self) -> impl DoubleEndedIterator<Item = (&T, usize)> {
+        self.runs
+            .as_list()
+            .iter()
+            .map(|run| (&run.value, run.count))
+    }
+
+    /// Merges the run at `run_index` into the one before it, if they hold equal values.
+    fn merge(&mut self, run_index: usize) {
+        let Some(before) = run_index.checked_sub(1) else {
+            return;
+        };
+
+        let (Some(a), Some(b)) = (self.runs.get(before), self.runs.get(run_index)) else {
+            return;
+        };
+
+        if a.value == b.value {
+            let run = self.runs.remove(run_index).unwrap();
+            self.runs.update(before, |a| a.count += run.count);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for RleArrayList<T, N>
+where
+    T: Clone + PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for RleArrayList<T, N>
+where
+    T: Clone,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for RleArrayList<T, N>
+where
+    T: Clone + PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for RleArrayList<T, N>
+where
+    T: Clone + PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for RleArrayList<T, N>
+where
+    T: Clone + core::fmt::Debug + PartialEq,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ChunkCapacity, Usize};
+
+    use super::RleArrayList;
+
+    #[quickcheck]
+    fn test_rle_matches_vec(seed: Vec<bool>, operations: Vec<(u8, usize, bool)>) {
+        fn _test<const N: usize>(seed: &[bool], operations: &[(u8, usize, bool)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.to_vec();
+            let mut actual = RleArrayList::<_, N>::from_iter(seed.iter().copied());
+
+            for &(operation, index, value) in operations {
+                let index = index % (expected.len() + 1);
+
+                match operation % 7 {
+                    0 => {
+                        expected.insert(0, value);
+                        actual.push_front(value);
+                    }
+                    1 => {
+                        expected.push(value);
+                        actual.push_back(value);
+                    }
+                    2 => {
+                        let value = (!expected.is_empty()).then(|| expected.remove(0));
+                        assert_eq!(actual.pop_front(), value);
+                    }
+                    3 => assert_eq!(actual.pop_back(), expected.pop()),
+                    4 => {
+                        expected.insert(index, value);
+                        actual.insert(index, value);
+                    }
+                    5 => {
+                        let value = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), value);
+                    }
+                    _ => {
+                        let previous = expected
+                            .get_mut(index)
+                            .map(|previous| core::mem::replace(previous, value));
+                        assert_eq!(actual.replace(index, value), previous);
+                    }
+                }
+
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(actual.get(index), expected.get(index));
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(actual.iter().rev().eq(expected.iter().rev()));
+
+                // adjacent runs are always merged
+                assert_eq!(actual.runs_len(), expected.chunk_by(|a, b| a == b).count());
+            }
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+}
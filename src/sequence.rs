@@ -0,0 +1,332 @@
+use std::collections::VecDeque;
+
+use crate::{ArrayList, ChunkCapacity, Iter, IterMut, Usize};
+
+/// The operations shared by the sequential containers, so that code can be written once
+/// and run over a `Vec`, a `VecDeque` or an ArrayList by changing a type parameter.
+///
+/// Indices behave the same for every container: `insert` panics past the length of the
+/// sequence, while `get` and `remove` return `None` for out of bounds indices.
+///
+/// # Examples
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use array_list::{ArrayList, Sequence};
+///
+/// fn interleave<S: Sequence<i32> + Default>(values: &[i32]) -> S {
+///     let mut sequence = S::default();
+///     for &value in values {
+///         match value % 2 {
+///             0 => sequence.push_back(value),
+///             _ => sequence.push_front(value),
+///         }
+///     }
+///     sequence
+/// }
+///
+/// let list: ArrayList<_, 4> = interleave(&[1, 2, 3, 4]);
+/// let deque: VecDeque<_> = interleave(&[1, 2, 3, 4]);
+/// assert!(list.iter().eq(deque.iter()));
+/// ```
+pub trait Sequence<T> {
+    type Iter<'a>: DoubleEndedIterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    type IterMut<'a>: DoubleEndedIterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, index: usize) -> Option<&T>;
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    fn push_front(&mut self, value: T);
+
+    fn push_back(&mut self, value: T);
+
+    fn pop_front(&mut self) -> Option<T>;
+
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Inserts an element at `index`, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the sequence's current length).
+    fn insert(&mut self, index: usize, value: T);
+
+    /// Removes and returns the element at `index`, shifting subsequent elements to the left.
+    fn remove(&mut self, index: usize) -> Option<T>;
+
+    fn clear(&mut self);
+
+    fn iter(&self) -> Self::Iter<'_>;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+impl<T, const N: usize> Sequence<T> for ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Iter<'a>
+        = Iter<'a, T, N>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = IterMut<'a, T, N>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        ArrayList::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        ArrayList::get(self, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        ArrayList::get_mut(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        ArrayList::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        ArrayList::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        ArrayList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        ArrayList::pop_back(self)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) {
+        ArrayList::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        ArrayList::remove(self, index)
+    }
+
+    fn clear(&mut self) {
+        ArrayList::clear(self);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayList::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        ArrayList::iter_mut(self)
+    }
+}
+
+/// Pushing and popping at the front shift all the elements, in O(n).
+impl<T> Sequence<T> for Vec<T> {
+    type Iter<'a>
+        = core::slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = core::slice::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        Vec::insert(self, 0, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        Vec::push(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        Sequence::remove(self, 0)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) {
+        Vec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| Vec::remove(self, index))
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T> Sequence<T> for VecDeque<T> {
+    type Iter<'a>
+        = std::collections::vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = std::collections::vec_deque::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        VecDeque::get_mut(self, index)
+    }
+
+    fn push_front(&mut self, value: T) {
+        VecDeque::push_front(self, value);
+    }
+
+    fn push_back(&mut self, value: T) {
+        VecDeque::push_back(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) {
+        VecDeque::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        VecDeque::remove(self, index)
+    }
+
+    fn clear(&mut self) {
+        VecDeque::clear(self);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        VecDeque::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        VecDeque::iter_mut(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::Sequence;
+
+    /// Runs the operations on a sequence, returning everything they observed.
+    fn run<S: Sequence<i32> + Default>(operations: &[(u8, i32)]) -> Vec<Option<i32>> {
+        let mut sequence = S::default();
+        let mut observed = Vec::new();
+
+        for &(operation, value) in operations {
+            let index = value.unsigned_abs() as usize % (sequence.len() + 1);
+            match operation % 9 {
+                0 => sequence.push_front(value),
+                1 => sequence.push_back(value),
+                2 => observed.push(sequence.pop_front()),
+                3 => observed.push(sequence.pop_back()),
+                4 => sequence.insert(index, value),
+                5 => observed.push(sequence.remove(index)),
+                6 => observed.push(sequence.get(index).copied()),
+                7 => {
+                    if let Some(slot) = sequence.get_mut(index) {
+                        *slot = value;
+                    }
+                    sequence
+                        .iter_mut()
+                        .rev()
+                        .take(2)
+                        .for_each(|v| *v = v.wrapping_add(1));
+                }
+                _ => observed.extend(sequence.iter().rev().map(|&v| Some(v))),
+            }
+
+            observed.push(Some(sequence.len() as i32));
+            assert_eq!(sequence.is_empty(), sequence.len() == 0);
+        }
+
+        sequence.clear();
+        assert!(sequence.is_empty());
+        observed
+    }
+
+    #[quickcheck]
+    fn test_sequences_behave_alike(operations: Vec<(u8, i32)>) {
+        fn _test<const N: usize>(operations: &[(u8, i32)], expected: &[Option<i32>])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            assert_eq!(run::<ArrayList<i32, N>>(operations), expected);
+        }
+
+        let expected = run::<Vec<i32>>(&operations);
+        assert_eq!(run::<VecDeque<i32>>(&operations), expected);
+
+        _test::<1>(&operations, &expected);
+        _test::<2>(&operations, &expected);
+        _test::<3>(&operations, &expected);
+        _test::<4>(&operations, &expected);
+        _test::<5>(&operations, &expected);
+        _test::<8>(&operations, &expected);
+        _test::<16>(&operations, &expected);
+        _test::<32>(&operations, &expected);
+    }
+}
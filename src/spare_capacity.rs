@@ -0,0 +1,216 @@
+use core::mem::MaybeUninit;
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// The uninitialized room left in the chunk at the back of an ArrayList, to be written in place.
+///
+/// It dereferences to `[MaybeUninit<T>]`, the values written at its front are then appended
+/// to the list with [`assume_init_push`](Self::assume_init_push). While it's alive the back chunk
+/// is held aside, and it goes back to the list once dropped, along with the values pushed so far.
+///
+/// This struct is created by ArrayList::back_spare_capacity_mut().
+pub struct BackSpareCapacity<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a mut ArrayList<T, N>,
+    chunk: Vec<T>,
+}
+
+impl<'a, T, const N: usize> BackSpareCapacity<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Takes the back chunk out of the list if it has room left, or starts a new one.
+    pub(crate) fn new(list: &'a mut ArrayList<T, N>) -> Self {
+        let chunk = match list.chunks.back() {
+            Some(chunk) if chunk.len() < N => {
+                let chunk = list.chunks.pop_back().unwrap();
+                list.len -= chunk.len();
+
+                // never moves the elements around if they already start the buffer
+                let mut chunk = Vec::from(chunk);
+                chunk.reserve_exact(N - chunk.len());
+                chunk
+            }
+            _ => {
+                list.counters.allocation();
+                Vec::with_capacity(N)
+            }
+        };
+
+        Self { list, chunk }
+    }
+
+    /// Appends the first `n` values of the spare capacity to the list.
+    ///
+    /// The spare capacity shrinks accordingly, so this can be called again
+    /// once more values have been written at its new front.
+    ///
+    /// # Safety
+    /// The first `n` values of the spare capacity must have been initialized,
+    /// and `n` must not exceed its length.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 8> = ArrayList::from(*b"hello");
+    ///
+    /// let mut spare = list.back_spare_capacity_mut();
+    /// assert_eq!(spare.len(), 3);
+    ///
+    /// spare[..2].copy_from_slice(&[MaybeUninit::new(b'!'), MaybeUninit::new(b'?')]);
+    /// // SAFETY: the first two values have just been written
+    /// unsafe { spare.assume_init_push(2) };
+    /// assert_eq!(spare.len(), 1);
+    /// drop(spare);
+    ///
+    /// assert_eq!(list, b"hello!?".as_slice());
+    /// ```
+    pub unsafe fn assume_init_push(&mut self, n: usize) {
+        debug_assert!(n <= self.len());
+
+        // SAFETY: the caller guarantees that the `n` values past the current length
+        // are initialized, and that they fit in the capacity of the chunk.
+        unsafe { self.chunk.set_len(self.chunk.len() + n) };
+    }
+}
+
+impl<T, const N: usize> Deref for BackSpareCapacity<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Target = [MaybeUninit<T>];
+
+    fn deref(&self) -> &Self::Target {
+        let len = N - self.chunk.len();
+        // SAFETY: the chunk has a capacity of at least `N`, so there are `len` values
+        // of spare capacity past its elements.
+        unsafe {
+            core::slice::from_raw_parts(self.chunk.as_ptr().add(self.chunk.len()).cast(), len)
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for BackSpareCapacity<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let len = N - self.chunk.len();
+        &mut self.chunk.spare_capacity_mut()[..len]
+    }
+}
+
+impl<T, const N: usize> Drop for BackSpareCapacity<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn drop(&mut self) {
+        if self.chunk.is_empty() {
+            return;
+        }
+
+        let chunk = core::mem::take(&mut self.chunk);
+        self.list.len += chunk.len();
+        self.list.chunks.push_back(VecDeque::from(chunk));
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for BackSpareCapacity<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BackSpareCapacity")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_back_spare_capacity_fills_the_back_chunk_in_place() {
+        let mut list = ArrayList::<u8, 4>::from(*b"abcde");
+        let back = list.chunks[1].as_slices().0.as_ptr();
+
+        let mut spare = list.back_spare_capacity_mut();
+        assert_eq!(spare.len(), 3);
+        spare[0].write(b'f');
+        // SAFETY: the first value has just been written
+        unsafe { spare.assume_init_push(1) };
+        drop(spare);
+
+        assert_eq!(list, b"abcdef".as_slice());
+        assert_eq!(list.chunks[1].as_slices().0.as_ptr(), back);
+
+        // dropping it untouched leaves the list as it was, even with a new chunk
+        drop(list.back_spare_capacity_mut());
+        list.push_back(b'g');
+        list.push_back(b'h');
+        drop(list.back_spare_capacity_mut());
+        assert_eq!(list.chunks.len(), 2);
+        assert_eq!(list, b"abcdefgh".as_slice());
+    }
+
+    #[quickcheck]
+    fn test_back_spare_capacity_matches_vec(seed: Vec<i32>, front: u8, writes: Vec<u8>) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, writes: &[u8])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves the back chunk wrapped around its buffer
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut actual = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| actual.push_front(value));
+
+            let mut expected = seed.to_vec();
+            for &n in writes {
+                let mut spare = actual.back_spare_capacity_mut();
+                assert!((1..=N).contains(&spare.len()));
+
+                let n = usize::from(n) % (spare.len() + 1);
+                for (i, slot) in spare[..n].iter_mut().enumerate() {
+                    *slot = MaybeUninit::new(i as i32);
+                    expected.push(i as i32);
+                }
+                // SAFETY: the first `n` values have just been written
+                unsafe { spare.assume_init_push(n) };
+                drop(spare);
+
+                assert_eq!(actual.len(), expected.len());
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+        }
+
+        _test::<1>(&seed, front, &writes);
+        _test::<2>(&seed, front, &writes);
+        _test::<3>(&seed, front, &writes);
+        _test::<4>(&seed, front, &writes);
+        _test::<5>(&seed, front, &writes);
+        _test::<8>(&seed, front, &writes);
+        _test::<16>(&seed, front, &writes);
+        _test::<32>(&seed, front, &writes);
+    }
+}
@@ -0,0 +1,158 @@
+/// What happened to the chunks of an ArrayList since it was created or since its stats were reset,
+/// along with how well its chunks are filled right now.
+///
+/// This struct is created by ArrayList::stats().
+#[cfg(feature = "stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// The number of chunks allocated by the list.
+    pub allocations: usize,
+    /// The number of times a chunk was split in two.
+    pub splits: usize,
+    /// The number of times two chunks were merged into one.
+    pub merges: usize,
+    /// The number of times elements were spilled out of a full chunk to make room for an insertion.
+    pub spills: usize,
+    /// The number of chunks currently held by the list.
+    pub chunks: usize,
+    /// The number of elements currently held by the list.
+    pub len: usize,
+    /// The number of elements the current chunks can hold.
+    pub capacity: usize,
+}
+
+#[cfg(feature = "stats")]
+impl Stats {
+    /// Returns the fraction of the current chunks that is filled, between 0 and 1,
+    /// or 1 if the list holds no chunks.
+    pub fn average_fill(&self) -> f64 {
+        match self.capacity {
+            0 => 1.0,
+            capacity => self.len as f64 / capacity as f64,
+        }
+    }
+}
+
+/// The counters kept by every ArrayList, which take no room at all
+/// unless the `stats` feature is enabled.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Counters {
+    #[cfg(feature = "stats")]
+    stats: Stats,
+}
+
+impl Counters {
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "stats")]
+            stats: Stats {
+                allocations: 0,
+                splits: 0,
+                merges: 0,
+                spills: 0,
+                chunks: 0,
+                len: 0,
+                capacity: 0,
+            },
+        }
+    }
+
+    #[inline]
+    pub(crate) fn allocation(&mut self) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.allocations += 1;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn split(&mut self) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.splits += 1;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn merge(&mut self) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.merges += 1;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn spill(&mut self) {
+        #[cfg(feature = "stats")]
+        {
+            self.stats.spills += 1;
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    pub(crate) fn snapshot(&self, chunks: usize, len: usize, capacity: usize) -> Stats {
+        Stats {
+            chunks,
+            len,
+            capacity,
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "stats")]
+mod tests {
+    use crate::ArrayList;
+
+    #[test]
+    fn test_stats_count_chunk_events() {
+        let mut list = ArrayList::<usize, 4>::from_iter(0..8);
+        let stats = list.stats();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.average_fill(), 1.0);
+
+        list.insert(1, 42);
+        let stats = list.stats();
+        assert_eq!((stats.allocations, stats.splits, stats.spills), (3, 1, 1));
+        assert_eq!((stats.chunks, stats.len, stats.capacity), (3, 9, 12));
+        assert_eq!(stats.average_fill(), 0.75);
+
+        // opening the editor splits a chunk, closing it merges the halves back
+        drop(list.edit_at(7));
+        let stats = list.stats();
+        assert_eq!((stats.splits, stats.merges), (2, 1));
+
+        list.reset_stats();
+        let stats = list.stats();
+        assert_eq!((stats.allocations, stats.splits, stats.merges), (0, 0, 0));
+        assert_eq!(stats.len, 9);
+
+        assert_eq!(ArrayList::<usize, 4>::new().stats().average_fill(), 1.0);
+    }
+
+    #[test]
+    fn test_stats_count_batch_insertions() {
+        let mut list = ArrayList::<i32, 2>::from_iter(0..4);
+        list.insert_batch([(0, 10), (1, 11), (2, 12), (3, 13)]);
+
+        // each chunk is woven into two new ones
+        let stats = list.stats();
+        assert_eq!((stats.allocations, stats.chunks), (6, 4));
+    }
+
+    #[test]
+    fn test_stats_count_appended_chunks() {
+        let mut list = ArrayList::<usize, 4>::from_iter(0..4);
+
+        let mut appender = list.appender();
+        appender.extend(4..8);
+        assert_eq!(appender.take_published().len(), 8);
+        appender.push(8);
+        drop(appender);
+
+        // taking the published elements leaves the counters behind
+        let stats = list.stats();
+        assert_eq!((stats.allocations, stats.chunks, stats.len), (3, 1, 1));
+    }
+}
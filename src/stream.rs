@@ -0,0 +1,215 @@
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::future;
+
+use futures_core::{FusedStream, Stream};
+
+use crate::{ArrayList, ChunkCapacity, IntoIter, Usize};
+
+impl<T, const N: usize> ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Collects the items of `stream` into a list, waiting for each of them in turn.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use array_list::ArrayList;
+    /// use futures::stream;
+    ///
+    /// let list = ArrayList::<_, 2>::from_stream(stream::iter(0..5)).await;
+    /// assert_eq!(list, [0, 1, 2, 3, 4]);
+    /// # });
+    /// ```
+    pub async fn from_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = T>,
+    {
+        let mut stream = pin!(stream);
+        let mut list = Self::new();
+
+        while let Some(value) = future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            list.push_back(value);
+        }
+
+        list
+    }
+
+    /// Turns the list into a stream of its elements, which never has to wait for them.
+    pub fn into_stream(self) -> IntoStream<T, N> {
+        IntoStream {
+            iter: self.into_iter(),
+        }
+    }
+
+    /// Turns the list into a stream of its chunks, front to back, each one as a `Vec`
+    /// of at most `N` elements, so that the consumer gets them in batches.
+    ///
+    /// # Examples
+    /// ```
+    /// # futures::executor::block_on(async {
+    /// use array_list::ArrayList;
+    /// use futures::StreamExt;
+    ///
+    /// let list: ArrayList<_, 2> = ArrayList::from_iter(0..5);
+    /// let batches: Vec<Vec<i32>> = list.into_chunk_stream().collect().await;
+    ///
+    /// assert_eq!(batches, [vec![0, 1], vec![2, 3], vec![4]]);
+    /// # });
+    /// ```
+    pub fn into_chunk_stream(self) -> IntoChunkStream<T, N> {
+        IntoChunkStream {
+            chunks: self.chunks,
+        }
+    }
+}
+
+/// A stream over the elements of an ArrayList, which are always ready.
+///
+/// This struct is created by ArrayList::into_stream().
+pub struct IntoStream<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    iter: IntoIter<T, N>,
+}
+
+// the elements are never pinned, only moved out
+impl<T, const N: usize> Unpin for IntoStream<T, N> where Usize<N>: ChunkCapacity {}
+
+impl<T, const N: usize> Stream for IntoStream<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const N: usize> FusedStream for IntoStream<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.len() == 0
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for IntoStream<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoStream").field(&self.iter).finish()
+    }
+}
+
+/// A stream over the chunks of an ArrayList, each one turned into a `Vec`.
+///
+/// This struct is created by ArrayList::into_chunk_stream().
+pub struct IntoChunkStream<T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    chunks: VecDeque<VecDeque<T>>,
+}
+
+// the elements are never pinned, only moved out
+impl<T, const N: usize> Unpin for IntoChunkStream<T, N> where Usize<N>: ChunkCapacity {}
+
+impl<T, const N: usize> Stream for IntoChunkStream<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = Vec<T>;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Vec<T>>> {
+        // turning a chunk into a Vec reuses its buffer, at most rotating it in place
+        Poll::Ready(self.get_mut().chunks.pop_front().map(Vec::from))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.chunks.len(), Some(self.chunks.len()))
+    }
+}
+
+impl<T, const N: usize> FusedStream for IntoChunkStream<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn is_terminated(&self) -> bool {
+        self.chunks.is_empty()
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for IntoChunkStream<T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.chunks).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::{Stream, StreamExt, stream};
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_stream_round_trip(seed: Vec<i32>, front: u8) {
+        fn _test<const N: usize>(seed: &[i32], front: u8)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let chunks: Vec<Vec<i32>> = list
+                .chunks
+                .iter()
+                .map(|chunk| chunk.iter().copied().collect())
+                .collect();
+
+            // cloning repacks the chunks, so the elements are streamed out of a copy
+            let stream = list.clone().into_stream();
+            assert_eq!(stream.size_hint(), (seed.len(), Some(seed.len())));
+            assert_eq!(block_on(stream.collect::<Vec<_>>()), seed);
+
+            let batches = list.into_chunk_stream().collect::<Vec<_>>();
+            assert_eq!(block_on(batches), chunks);
+
+            let actual = block_on(ArrayList::<_, N>::from_stream(stream::iter(
+                seed.iter().copied(),
+            )));
+            assert_eq!(actual, seed);
+        }
+
+        _test::<1>(&seed, front);
+        _test::<2>(&seed, front);
+        _test::<3>(&seed, front);
+        _test::<4>(&seed, front);
+        _test::<5>(&seed, front);
+        _test::<8>(&seed, front);
+        _test::<16>(&seed, front);
+        _test::<32>(&seed, front);
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::Range;
+
+use crate::{ArrayList, ChunkCapacity, Iter, SearchTarget, Usize};
+
+/// A view over a range of the elements of an ArrayList, indexed from the start of the range.
+///
+/// It's a cheap borrowed window, so APIs can take part of a list without copying it out.
+///
+/// This struct is created by ArrayList::view().
+pub struct SubListView<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    list: &'a ArrayList<T, N>,
+    range: Range<usize>,
+}
+
+impl<'a, T, const N: usize> SubListView<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn new(list: &'a ArrayList<T, N>, range: Range<usize>) -> Self {
+        Self { list, range }
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns a reference to the element at the specified index of the view, if any.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index < self.len() {
+            self.list.get(self.range.start + index)
+        } else {
+            None
+        }
+    }
+
+    pub fn front(&self) -> Option<&'a T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&'a T> {
+        self.get(self.len().wrapping_sub(1))
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> Iter<'a, T, N> {
+        iter(self.list, &self.range)
+    }
+}
+
+impl<T, const N: usize> Clone for SubListView<'_, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for SubListView<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for SubListView<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Returns the chunk index and the index within that chunk of the element at `index`,
+/// or the position past the last chunk if `index` is the list's length.
+fn position<T, const N: usize>(list: &ArrayList<T, N>, index: usize) -> (usize, usize)
+where
+    Usize<N>: ChunkCapacity,
+{
+    match list.search_target(index) {
+        Some(SearchTarget {
+            chunk_index,
+            target_index,
+        }) => (chunk_index, target_index),
+        None => (list.chunks.len(), 0),
+    }
+}
+
+fn iter<'a, T, const N: usize>(list: &'a ArrayList<T, N>, range: &Range<usize>) -> Iter<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    let start = position(list, range.start);
+    let end = position(list, range.end);
+    Iter::from_range(list, start, end, range.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_views_match_slices(seed: Vec<i32>, front: u8, bounds: (usize, usize)) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, (a, b): (usize, usize))
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let (a, b) = (a % (seed.len() + 1), b % (seed.len() + 1));
+            let range = a.min(b)..a.max(b);
+            let mut expected = seed.to_vec();
+            let slice = &mut expected[range.clone()];
+
+            let view = list.view(range.clone());
+            assert_eq!(view.len(), slice.len());
+            assert_eq!(view.front(), slice.first());
+            assert_eq!(view.back(), slice.last());
+            assert!(view.iter().eq(slice.iter()));
+            assert!(view.iter().rev().eq(slice.iter().rev()));
+            for index in 0..=slice.len() {
+                assert_eq!(view.get(index), slice.get(index));
+            }
+
+            let mut view = list.view_mut(range);
+            if let (Some(value), Some(expected)) = (view.front_mut(), slice.first_mut()) {
+                *value = value.wrapping_neg();
+                *expected = expected.wrapping_neg();
+            }
+            view.iter_mut()
+                .for_each(|value| *value = value.wrapping_add(1));
+            slice
+                .iter_mut()
+                .for_each(|value| *value = value.wrapping_add(1));
+            if let (Some(value), Some(expected)) = (view.back_mut(), slice.last_mut()) {
+                *value = value.wrapping_mul(3);
+                *expected = expected.wrapping_mul(3);
+            }
+
+            assert_eq!(view.len(), slice.len());
+            assert!(view.iter().eq(slice.iter()));
+            assert!(view.iter().rev().eq(slice.iter().rev()));
+            assert!(view.into_iter().eq(slice.iter_mut()));
+            assert!(list.iter().eq(expected.iter()));
+        }
+
+        _test::<1>(&seed, front, bounds);
+        _test::<2>(&seed, front, bounds);
+        _test::<3>(&seed, front, bounds);
+        _test::<4>(&seed, front, bounds);
+        _test::<5>(&seed, front, bounds);
+        _test::<8>(&seed, front, bounds);
+        _test::<16>(&seed, front, bounds);
+        _test::<32>(&seed, front, bounds);
+    }
+}
@@ -0,0 +1,517 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::chunk_sums::ChunkSums;
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+const LEN: usize = 0;
+const LINES: usize = 1;
+const CHARS: usize = 2;
+
+fn measure_chunk(chunk: &VecDeque<u8>) -> [usize; 3] {
+    let lines = chunk.iter().filter(|&&byte| byte == b'\n').count();
+    let chars = chunk.iter().filter(|&&byte| is_char_start(byte)).count();
+    [chunk.len(), lines, chars]
+}
+
+/// Returns `true` if `byte` is not a UTF-8 continuation byte.
+fn is_char_start(byte: u8) -> bool {
+    (byte as i8) >= -0x40
+}
+
+/// A UTF-8 text buffer stored in an `ArrayList<u8, N>`, which keeps an index of the line starts
+/// and char boundaries up to date as it's edited.
+///
+/// Lines are separated by `'\n'` and columns are counted in chars. The index stores per-chunk
+/// counts in Fenwick trees, so that lookups only walk O(log chunks) nodes plus a single chunk.
+/// Edits must fall on char boundaries, which keeps the content valid UTF-8.
+///
+/// # Examples
+/// ```
+/// use array_list::TextBuffer;
+///
+/// let mut text: TextBuffer<8> = TextBuffer::from("fn main() {\n}\n");
+/// text.insert_str(12, "    println!(\"héllo\");\n");
+///
+/// assert_eq!(text.len_lines(), 4);
+/// assert_eq!(text.line_to_index(2), Some(36));
+/// assert_eq!(text.index_to_line_col(30), (1, 17));
+/// assert_eq!(text.to_string(), "fn main() {\n    println!(\"héllo\");\n}\n");
+/// ```
+pub struct TextBuffer<const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    bytes: ArrayList<u8, N>,
+    sums: ChunkSums<3>,
+}
+
+impl<const N: usize> TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Creates a new, empty `TextBuffer`.
+    pub const fn new() -> Self {
+        Self {
+            bytes: ArrayList::new(),
+            sums: ChunkSums::new(),
+        }
+    }
+
+    /// Returns the underlying bytes.
+    pub const fn as_bytes(&self) -> &ArrayList<u8, N> {
+        &self.bytes
+    }
+
+    /// Consumes `self`, returning the underlying bytes.
+    pub fn into_bytes(self) -> ArrayList<u8, N> {
+        self.bytes
+    }
+
+    /// Returns the length of the text in bytes.
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.sums.total(CHARS)
+    }
+
+    /// Returns the number of lines, that is one more than the number of `'\n'`.
+    pub fn len_lines(&self) -> usize {
+        self.sums.total(LINES) + 1
+    }
+
+    /// Returns `true` if `index` is the start or the end of the text, or the start of a char.
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        if index == 0 || index == self.len() {
+            return true;
+        }
+
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        self.bytes
+            .chunks
+            .get(chunk_index)
+            .and_then(|chunk| chunk.get(target_index))
+            .is_some_and(|&byte| is_char_start(byte))
+    }
+
+    /// Returns the byte index at which `line` starts, or `None` if there are not that many lines.
+    pub fn line_to_index(&self, line: usize) -> Option<usize> {
+        let Some(newlines) = line.checked_sub(1) else {
+            return Some(0);
+        };
+
+        self.nth_in_chunks(LINES, newlines, |byte| byte == b'\n')
+            .map(|index| index + 1)
+    }
+
+    /// Returns the byte index of the char at `char_index`, or the length of the text if
+    /// `char_index` equals the number of chars, or `None` if it's greater.
+    pub fn char_to_index(&self, char_index: usize) -> Option<usize> {
+        if char_index == self.len_chars() {
+            return Some(self.len());
+        }
+
+        self.nth_in_chunks(CHARS, char_index, is_char_start)
+    }
+
+    /// Returns the number of chars before `index`.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the text length).
+    #[track_caller]
+    pub fn index_to_char(&self, index: usize) -> usize {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "byte index (is {index}) should be <= len (is {len})"
+        );
+        self.count_before(CHARS, index, is_char_start)
+    }
+
+    /// Returns the line holding the byte at `index` and its column, counted in chars.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the text length),
+    ///   or if it does not lie on a char boundary.
+    #[track_caller]
+    pub fn index_to_line_col(&self, index: usize) -> (usize, usize) {
+        self.check_char_boundary(index);
+
+        let line = self.count_before(LINES, index, |byte| byte == b'\n');
+        let line_start = self.line_to_index(line).unwrap();
+        let col = self.index_to_char(index) - self.index_to_char(line_start);
+        (line, col)
+    }
+
+    /// Inserts `text` at the byte `index`.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the text length),
+    ///   or if it does not lie on a char boundary.
+    #[track_caller]
+    pub fn insert_str(&mut self, index: usize, text: &str) {
+        self.check_char_boundary(index);
+
+        // short insertions, like typing, go into the existing chunks to avoid splitting them
+        if text.len() <= N {
+            for (offset, byte) in text.bytes().enumerate() {
+                let chunk_index = self.insertion_chunk(index + offset);
+                self.bytes.insert(index + offset, byte);
+                self.refresh(chunk_index, 2);
+            }
+            return;
+        }
+
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        let mut other = ArrayList::from_iter(text.bytes());
+        self.bytes
+            .splice_chunks(chunk_index, target_index, &mut other);
+        self.refresh(chunk_index, 1);
+    }
+
+    /// Appends `text` at the end.
+    pub fn push_str(&mut self, text: &str) {
+        self.insert_str(self.len(), text);
+    }
+
+    /// Removes the bytes in `range`, returning them as a `String`.
+    ///
+    /// # Panics
+    /// - Panics if the start of the range is greater than its end or the end is greater than
+    ///   the text length, or if either of them does not lie on a char boundary.
+    #[track_caller]
+    pub fn remove(&mut self, range: Range<usize>) -> String {
+        let Range { start, end } = range;
+        assert!(start <= end, "range starts at {start} but ends at {end}");
+        self.check_char_boundary(start);
+        self.check_char_boundary(end);
+
+        if range.is_empty() {
+            return String::new();
+        }
+
+        let (first, target_index) = self.sums.search(LEN, range.start);
+        let (last, _) = self.sums.search(LEN, range.end - 1);
+
+        let removed = self.bytes.split_range(first, target_index, range.len());
+        self.refresh(first, last - first + 1);
+
+        String::from_utf8(removed.into_iter().collect()).expect("removed bytes must be valid UTF-8")
+    }
+
+    #[track_caller]
+    fn check_char_boundary(&self, index: usize) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "byte index (is {index}) should be <= len (is {len})"
+        );
+        assert!(
+            self.is_char_boundary(index),
+            "byte index {index} is not a char boundary"
+        );
+    }
+
+    /// Returns the chunk that an insertion at `index` may touch first.
+    fn insertion_chunk(&self, index: usize) -> usize {
+        let (chunk_index, _) = self.sums.search(LEN, index);
+        chunk_index.min(self.bytes.chunks.len().saturating_sub(1))
+    }
+
+    fn refresh(&mut self, start: usize, count: usize) {
+        self.sums
+            .refresh(&self.bytes.chunks, start, count, measure_chunk);
+    }
+
+    /// Counts the bytes before `index` that satisfy `predicate`, which must match what
+    /// the `d`-th quantity of the index counts.
+    fn count_before(&self, d: usize, index: usize, predicate: impl Fn(u8) -> bool) -> usize {
+        let (chunk_index, target_index) = self.sums.search(LEN, index);
+        let in_chunk = self.bytes.chunks.get(chunk_index).map_or(0, |chunk| {
+            chunk
+                .range(..target_index)
+                .filter(|&&byte| predicate(byte))
+                .count()
+        });
+
+        self.sums.prefix(d, chunk_index) + in_chunk
+    }
+
+    /// Returns the index of the `n`-th byte satisfying `predicate`, which must match what
+    /// the `d`-th quantity of the index counts.
+    fn nth_in_chunks(&self, d: usize, n: usize, predicate: impl Fn(u8) -> bool) -> Option<usize> {
+        let (chunk_index, n) = self.sums.search(d, n);
+        let target_index = self
+            .bytes
+            .chunks
+            .get(chunk_index)?
+            .iter()
+            .enumerate()
+            .filter(|&(_, &byte)| predicate(byte))
+            .nth(n)?
+            .0;
+
+        Some(self.sums.prefix(LEN, chunk_index) + target_index)
+    }
+}
+
+impl<const N: usize> Default for TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Clone for TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            sums: self.sums.clone(),
+        }
+    }
+}
+
+impl<const N: usize> From<&str> for TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn from(text: &str) -> Self {
+        let bytes = ArrayList::from_iter(text.bytes());
+        Self {
+            sums: ChunkSums::from_chunks(&bytes.chunks, measure_chunk),
+            bytes,
+        }
+    }
+}
+
+impl<const N: usize> core::fmt::Display for TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // chars may straddle chunks, so the text is gathered before being decoded
+        let bytes: Vec<u8> = self.bytes.iter().copied().collect();
+        f.write_str(core::str::from_utf8(&bytes).map_err(|_| core::fmt::Error)?)
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for TextBuffer<N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+/// String building on plain byte lists, which grow a chunk at a time
+/// instead of reallocating one contiguous buffer.
+impl<const N: usize> ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Appends the bytes of `text` to the back of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// use array_list::ArrayList;
+    ///
+    /// let mut list: ArrayList<u8, 4> = ArrayList::new();
+    /// list.extend_from_str("héllo");
+    /// list.push_str(" world");
+    ///
+    /// assert_eq!(list.len(), 12);
+    /// assert_eq!(list.to_string().unwrap(), "héllo world");
+    /// ```
+    pub fn extend_from_str(&mut self, text: &str) {
+        self.append_bytes(text.as_bytes());
+    }
+
+    /// Appends the bytes of `text` to the back of the list, same as [`Self::extend_from_str`].
+    pub fn push_str(&mut self, text: &str) {
+        self.extend_from_str(text);
+    }
+
+    /// Gathers the bytes of the list into a `String`.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not valid UTF-8, from which they can be recovered.
+    pub fn to_string(&self) -> Result<String, std::string::FromUtf8Error> {
+        let mut bytes = Vec::with_capacity(self.len());
+        for chunk in &self.chunks {
+            let (front, back) = chunk.as_slices();
+            bytes.extend_from_slice(front);
+            bytes.extend_from_slice(back);
+        }
+
+        String::from_utf8(bytes)
+    }
+}
+
+/// Writing appends the formatted text to the back of the list.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+///
+/// use array_list::ArrayList;
+///
+/// let mut list: ArrayList<u8, 4> = ArrayList::new();
+/// write!(list, "{}-{:03}", "id", 7).unwrap();
+///
+/// assert_eq!(list.to_string().unwrap(), "id-007");
+/// ```
+impl<const N: usize> core::fmt::Write for ArrayList<u8, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.extend_from_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    use super::TextBuffer;
+
+    /// Returns the char boundary closest to `index` from below.
+    fn floor_char_boundary(text: &str, index: usize) -> usize {
+        (0..=index.min(text.len()))
+            .rev()
+            .find(|&i| text.is_char_boundary(i))
+            .unwrap()
+    }
+
+    #[quickcheck]
+    fn test_text_buffer_matches_string(
+        seed: String,
+        operations: Vec<(bool, usize, usize, String)>,
+    ) {
+        fn _test<const N: usize>(seed: &str, operations: &[(bool, usize, usize, String)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.to_string();
+            let mut actual = TextBuffer::<N>::from(seed);
+
+            for (insert, start, end, text) in operations {
+                let start = floor_char_boundary(&expected, start % (expected.len() + 1));
+
+                if *insert {
+                    let text: String = text.chars().take(8).collect();
+                    let text = text.replace('a', "\n");
+                    expected.insert_str(start, &text);
+                    actual.insert_str(start, &text);
+                } else {
+                    let end = floor_char_boundary(&expected, start + end % 16);
+                    let removed: String = expected.drain(start..end).collect();
+                    assert_eq!(actual.remove(start..end), removed);
+                }
+
+                assert_eq!(actual.to_string(), expected);
+                assert_eq!(actual.len(), expected.len());
+                assert_eq!(actual.len_lines(), expected.split('\n').count());
+
+                let line = expected[..start].matches('\n').count();
+                let line_start = expected[..start].rfind('\n').map_or(0, |i| i + 1);
+                let col = expected[line_start..start].chars().count();
+                assert_eq!(actual.line_to_index(line), Some(line_start));
+                assert_eq!(actual.index_to_line_col(start), (line, col));
+            }
+
+            let mut line_start = 0;
+            for (line, text) in expected.split('\n').enumerate() {
+                assert_eq!(actual.line_to_index(line), Some(line_start));
+
+                for (col, (offset, _)) in text.char_indices().enumerate() {
+                    assert!(actual.is_char_boundary(line_start + offset));
+                    assert_eq!(actual.index_to_line_col(line_start + offset), (line, col));
+                }
+
+                line_start += text.len() + 1;
+            }
+            assert_eq!(actual.line_to_index(actual.len_lines()), None);
+
+            for (char_index, (index, _)) in expected.char_indices().enumerate() {
+                assert_eq!(actual.char_to_index(char_index), Some(index));
+                assert_eq!(actual.index_to_char(index), char_index);
+            }
+
+            let chars = expected.chars().count();
+            assert_eq!(actual.len_chars(), chars);
+            assert_eq!(actual.char_to_index(chars), Some(expected.len()));
+            assert_eq!(actual.char_to_index(chars + 1), None);
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[quickcheck]
+    fn test_string_building_matches_string(pieces: Vec<String>) {
+        fn _test<const N: usize>(pieces: &[String])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            use core::fmt::Write;
+
+            let mut actual = ArrayList::<u8, N>::new();
+            let mut expected = String::new();
+
+            for (i, piece) in pieces.iter().enumerate() {
+                match i % 3 {
+                    0 => actual.extend_from_str(piece),
+                    1 => actual.push_str(piece),
+                    _ => write!(actual, "{piece}").unwrap(),
+                }
+                expected.push_str(piece);
+
+                assert_eq!(actual.to_string().as_deref(), Ok(expected.as_str()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() <= N)
+                );
+            }
+
+            // a char cut in half is reported, with the bytes
+            if let Some(c) = expected.chars().find(|c| c.len_utf8() > 1) {
+                actual.push_back(c.to_string().as_bytes()[0]);
+                let error = actual.to_string().unwrap_err();
+                assert_eq!(error.as_bytes().len(), expected.len() + 1);
+            }
+        }
+
+        _test::<1>(&pieces);
+        _test::<2>(&pieces);
+        _test::<3>(&pieces);
+        _test::<4>(&pieces);
+        _test::<5>(&pieces);
+        _test::<8>(&pieces);
+        _test::<16>(&pieces);
+        _test::<32>(&pieces);
+    }
+}
@@ -0,0 +1,369 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::{Deref, Range};
+
+use crate::{ArrayList, ChunkCapacity, Usize};
+
+/// An ArrayList paired with a hash index from every value to the positions holding it,
+/// making [`contains`](Self::contains) and [`position`](Self::position) O(1), at the cost
+/// of a clone of every distinct value and a word per element.
+///
+/// The positions are stored shifted by a counter that every push at the front moves back,
+/// so that editing either end of the list updates the index in O(1). Inserting or removing
+/// anywhere else shifts the positions on the shorter side of the edit, one lookup per element,
+/// moving the counter instead of the other side when that's the front.
+///
+/// The list is read through `Deref`, while editing goes through the methods of this type,
+/// so that the index never goes stale.
+///
+/// This struct is created by ArrayList::indexed().
+pub struct IndexedArrayList<T, const N: usize>
+where
+    T: Clone + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+    /// The positions of each value in ascending order, shifted by `front`.
+    positions: HashMap<T, VecDeque<usize>>,
+    front: usize,
+}
+
+impl<T, const N: usize> IndexedArrayList<T, N>
+where
+    T: Clone + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(list: ArrayList<T, N>) -> Self {
+        let mut indexed = Self {
+            list,
+            positions: HashMap::new(),
+            front: 0,
+        };
+
+        indexed.rebuild();
+        indexed
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list.
+    pub fn into_list(self) -> ArrayList<T, N> {
+        self.list
+    }
+
+    /// Returns `true` if the list contains an element equal to `value`, in O(1).
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.positions.contains_key(value)
+    }
+
+    /// Returns the index of the first element equal to `value`, in O(1).
+    pub fn position<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let position = self.positions.get(value)?.front()?;
+        Some(position.wrapping_sub(self.front))
+    }
+
+    /// Returns the index of the last element equal to `value`, in O(1).
+    pub fn rposition<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let position = self.positions.get(value)?.back()?;
+        Some(position.wrapping_sub(self.front))
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.front = self.front.wrapping_sub(1);
+        let positions = self.positions.entry(value.clone()).or_default();
+        positions.push_front(self.front);
+        self.list.push_front(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let position = self.front.wrapping_add(self.list.len());
+        let positions = self.positions.entry(value.clone()).or_default();
+        positions.push_back(position);
+        self.list.push_back(value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.list.pop_front()?;
+        self.unindex(&value, VecDeque::pop_front);
+        self.front = self.front.wrapping_add(1);
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let value = self.list.pop_back()?;
+        self.unindex(&value, VecDeque::pop_back);
+        Some(value)
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// Updates the positions of the elements before `index` or of the ones after it,
+    /// whichever are fewer.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.list.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if index == 0 {
+            self.push_front(value);
+            return;
+        }
+
+        if index == len {
+            self.push_back(value);
+            return;
+        }
+
+        if index < len - index {
+            self.shift(0..index, false);
+            self.front = self.front.wrapping_sub(1);
+        } else {
+            self.shift(index..len, true);
+        }
+
+        let front = self.front;
+        let positions = self.positions.entry(value.clone()).or_default();
+        let at = positions.partition_point(|position| position.wrapping_sub(front) < index);
+        positions.insert(at, front.wrapping_add(index));
+        self.list.insert(index, value);
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    ///
+    /// Updates the positions of the elements before `index` or of the ones after it,
+    /// whichever are fewer.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let len = self.list.len();
+        if index == 0 {
+            return self.pop_front();
+        }
+
+        if index + 1 == len {
+            return self.pop_back();
+        }
+
+        let front = self.front;
+        let value = self.list.get(index)?;
+        if let Some(positions) = self.positions.get_mut(value) {
+            let at = positions.partition_point(|position| position.wrapping_sub(front) < index);
+            positions.remove(at);
+            if positions.is_empty() {
+                self.positions.remove(value);
+            }
+        }
+
+        if index < len - 1 - index {
+            self.shift(0..index, true);
+            self.front = self.front.wrapping_add(1);
+        } else {
+            self.shift(index + 1..len, false);
+        }
+
+        self.list.remove(index)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.positions.clear();
+        self.front = 0;
+    }
+
+    /// Drops the position at one end of those of `value`, forgetting the value if it was the last.
+    fn unindex(&mut self, value: &T, pop: fn(&mut VecDeque<usize>) -> Option<usize>) {
+        if let Some(positions) = self.positions.get_mut(value) {
+            pop(positions);
+            if positions.is_empty() {
+                self.positions.remove(value);
+            }
+        }
+    }
+
+    /// Moves the positions of the elements in `range` one step forward or backward.
+    ///
+    /// Elements are visited so that each position moves to one that no longer holds
+    /// the same value, keeping the positions of every value in ascending order.
+    fn shift(&mut self, range: Range<usize>, forward: bool) {
+        // counts from one before the front when moving backward, so that no position wraps around
+        let base = if forward {
+            self.front
+        } else {
+            self.front.wrapping_sub(1)
+        };
+
+        let positions = &mut self.positions;
+        let mut shift = |(index, value): (usize, &T)| {
+            let Some(positions) = positions.get_mut(value) else {
+                return;
+            };
+
+            let index = index + usize::from(!forward);
+            let at = positions.partition_point(|position| position.wrapping_sub(base) < index);
+            positions[at] = if forward {
+                positions[at].wrapping_add(1)
+            } else {
+                positions[at].wrapping_sub(1)
+            };
+        };
+
+        let elements = range.clone().zip(self.list.view(range).iter());
+        if forward {
+            elements.rev().for_each(&mut shift);
+        } else {
+            elements.for_each(&mut shift);
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.positions.clear();
+        self.front = 0;
+
+        for (index, value) in self.list.iter().enumerate() {
+            let positions = self.positions.entry(value.clone()).or_default();
+            positions.push_back(index);
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for IndexedArrayList<T, N>
+where
+    T: Clone + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize> Extend<T> for IndexedArrayList<T, N>
+where
+    T: Clone + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T, const N: usize> Clone for IndexedArrayList<T, N>
+where
+    T: Clone + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            positions: self.positions.clone(),
+            front: self.front,
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for IndexedArrayList<T, N>
+where
+    T: Clone + core::fmt::Debug + Eq + Hash,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_indexed_matches_vec(seed: Vec<u8>, operations: Vec<(u8, usize, u8)>) {
+        fn _test<const N: usize>(seed: &[u8], operations: &[(u8, usize, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // a few distinct values, so that most of them repeat
+            let mut expected: Vec<u8> = seed.iter().map(|value| value % 8).collect();
+            let mut actual = ArrayList::<_, N>::from_iter(expected.iter().copied()).indexed();
+
+            for &(operation, index, value) in operations {
+                let index = index % (expected.len() + 1);
+                let value = value % 8;
+
+                match operation % 6 {
+                    0 => {
+                        expected.insert(0, value);
+                        actual.push_front(value);
+                    }
+                    1 => {
+                        expected.push(value);
+                        actual.push_back(value);
+                    }
+                    2 => {
+                        let value = (!expected.is_empty()).then(|| expected.remove(0));
+                        assert_eq!(actual.pop_front(), value);
+                    }
+                    3 => assert_eq!(actual.pop_back(), expected.pop()),
+                    4 => {
+                        expected.insert(index, value);
+                        actual.insert(index, value);
+                    }
+                    _ => {
+                        let value = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), value);
+                    }
+                }
+
+                assert!(actual.iter().eq(expected.iter()));
+                for value in 0..8 {
+                    assert_eq!(actual.contains(&value), expected.contains(&value));
+                    assert_eq!(
+                        actual.position(&value),
+                        expected.iter().position(|v| *v == value)
+                    );
+                    assert_eq!(
+                        actual.rposition(&value),
+                        expected.iter().rposition(|v| *v == value)
+                    );
+
+                    let positions = actual.positions.get(&value).into_iter().flatten();
+                    assert!(
+                        positions
+                            .map(|position| position.wrapping_sub(actual.front))
+                            .eq((0..expected.len()).filter(|&i| expected[i] == value))
+                    );
+                }
+            }
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{ChunkCapacity, Usize, ViewMut};
+
+/// A cursor over a [`ViewMut`], which can change the elements of the view but not move them.
+///
+/// Like [`CursorMut`](crate::CursorMut), it rests on an element of the view, or on a “ghost”
+/// non-element between its back and its front, moving in a logically circular way.
+/// Since views over disjoint ranges are independent, two of these cursors can walk
+/// the two sides of a list at the same time.
+///
+/// This struct is created by ArrayList::cursor_pair_mut().
+pub struct ViewCursorMut<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    view: ViewMut<'a, T, N>,
+    index: usize,
+    /// The run of the view holding the current element, and the index of the element in that run.
+    position: (usize, usize),
+}
+
+impl<'a, T, const N: usize> ViewCursorMut<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_front(view: ViewMut<'a, T, N>) -> Self {
+        let mut cursor = Self {
+            view,
+            index: 0,
+            position: (0, 0),
+        };
+
+        cursor.position = cursor.forward((0, 0));
+        cursor
+    }
+
+    pub fn as_view(&self) -> &ViewMut<'a, T, N> {
+        &self.view
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.is_ghost() {
+            return None;
+        }
+
+        let (run, offset) = self.position;
+        Some(&mut self.view.run_mut(run)[offset])
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        if self.is_ghost() {
+            return None;
+        }
+
+        Some(self.index)
+    }
+
+    pub fn move_next(&mut self) {
+        if self.is_ghost() {
+            self.index = 0;
+            self.position = self.forward((0, 0));
+            return;
+        }
+
+        let (run, offset) = self.position;
+        self.index += 1;
+        self.position = self.forward((run, offset + 1));
+    }
+
+    pub fn move_prev(&mut self) {
+        if self.is_ghost() {
+            if let Some(position) = self.backward(self.ghost()) {
+                self.index = self.view.len() - 1;
+                self.position = position;
+            }
+            return;
+        }
+
+        match self.backward(self.position) {
+            Some(position) => {
+                self.index -= 1;
+                self.position = position;
+            }
+            None => {
+                self.index = self.view.len();
+                self.position = self.ghost();
+            }
+        }
+    }
+
+    /// Returns the element following the current one, or the front element of the view
+    /// if the cursor is pointing at the “ghost” non-element.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let position = if self.is_ghost() {
+            self.forward((0, 0))
+        } else {
+            self.forward((self.position.0, self.position.1 + 1))
+        };
+
+        self.get_at(position)
+    }
+
+    /// Returns the element preceding the current one, or the back element of the view
+    /// if the cursor is pointing at the “ghost” non-element.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let position = if self.is_ghost() {
+            self.backward(self.ghost())
+        } else {
+            self.backward(self.position)
+        };
+
+        position.and_then(|position| self.get_at(position))
+    }
+
+    /// Moves the cursor to the element at `index` of the view, or to the “ghost” non-element
+    /// if `index` is out of bounds.
+    pub fn seek_to(&mut self, index: usize) {
+        self.index = index.min(self.view.len());
+        self.position = self.forward((0, self.index));
+    }
+
+    fn is_ghost(&self) -> bool {
+        self.index >= self.view.len()
+    }
+
+    fn ghost(&self) -> (usize, usize) {
+        (self.view.runs_len(), 0)
+    }
+
+    fn get_at(&mut self, (run, offset): (usize, usize)) -> Option<&mut T> {
+        if run < self.view.runs_len() {
+            Some(&mut self.view.run_mut(run)[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Resolves the element `offset` positions after the start of `run`,
+    /// skipping over the runs it goes past, or returns the ghost position if it's out of the view.
+    fn forward(&self, (mut run, mut offset): (usize, usize)) -> (usize, usize) {
+        let runs_len = self.view.runs_len();
+        while run < runs_len {
+            let len = self.view.run(run).len();
+            if offset < len {
+                return (run, offset);
+            }
+
+            offset -= len;
+            run += 1;
+        }
+
+        self.ghost()
+    }
+
+    /// Returns the position of the element preceding `position`, if any.
+    fn backward(&self, (mut run, offset): (usize, usize)) -> Option<(usize, usize)> {
+        if offset > 0 {
+            return Some((run, offset - 1));
+        }
+
+        while run > 0 {
+            run -= 1;
+            if let Some(offset) = self.view.run(run).len().checked_sub(1) {
+                return Some((run, offset));
+            }
+        }
+
+        None
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ViewCursorMut<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ViewCursorMut")
+            .field(&self.view)
+            .field(&self.index())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_cursor_pair_mut_matches_slices(
+        seed: Vec<i32>,
+        front: u8,
+        split: usize,
+        moves: Vec<(bool, u8, usize)>,
+    ) {
+        fn _test<const N: usize>(seed: &[i32], front: u8, split: usize, moves: &[(bool, u8, usize)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves chunks wrapped around their buffers
+            let front = usize::from(front) % (seed.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(seed[front..].iter().copied());
+            seed[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let split = split % (seed.len() + 1);
+            let mut expected = seed.to_vec();
+            let (head, tail) = expected.split_at_mut(split);
+            let mut sides = [(head, 0), (tail, 0)];
+
+            let (left, right) = list.cursor_pair_mut(split);
+            let mut cursors = [left, right];
+
+            for &(side, operation, index) in moves {
+                let (values, position) = &mut sides[usize::from(side)];
+                let cursor = &mut cursors[usize::from(side)];
+                let len = values.len();
+                let positions = len + 1;
+
+                match operation % 4 {
+                    0 => {
+                        cursor.move_next();
+                        *position = (*position + 1) % positions;
+                    }
+                    1 => {
+                        cursor.move_prev();
+                        *position = (*position + len) % positions;
+                    }
+                    2 => {
+                        cursor.seek_to(index % (positions + 1));
+                        *position = (index % (positions + 1)).min(len);
+                    }
+                    _ => {
+                        if let Some(value) = cursor.current() {
+                            *value = value.wrapping_add(1);
+                        }
+                        if let Some(value) = values.get_mut(*position) {
+                            *value = value.wrapping_add(1);
+                        }
+                    }
+                }
+
+                assert_eq!(cursor.index(), (*position < len).then_some(*position));
+                assert_eq!(cursor.current(), values.get_mut(*position));
+                let next = (*position + 1) % positions;
+                assert_eq!(cursor.peek_next(), values.get_mut(next));
+                let prev = (*position + len) % positions;
+                assert_eq!(cursor.peek_prev(), values.get_mut(prev));
+            }
+
+            assert!(list.iter().eq(expected.iter()));
+        }
+
+        _test::<1>(&seed, front, split, &moves);
+        _test::<2>(&seed, front, split, &moves);
+        _test::<3>(&seed, front, split, &moves);
+        _test::<4>(&seed, front, split, &moves);
+        _test::<5>(&seed, front, split, &moves);
+        _test::<8>(&seed, front, split, &moves);
+        _test::<16>(&seed, front, split, &moves);
+        _test::<32>(&seed, front, split, &moves);
+    }
+}
@@ -0,0 +1,447 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::{ArrayList, ChunkCapacity, IterMut, SearchTarget, Usize};
+
+type Pair<'a, U> = (&'a mut [U], &'a mut [U]);
+
+/// A mutable view over a contiguous range of the elements of an ArrayList.
+///
+/// The view borrows the chunks in place, so it can change the elements in its range
+/// but never moves them around. Views over disjoint ranges can be used at the same time,
+/// e.g. from different scoped threads.
+///
+/// This struct is created by ArrayList::split_at_mut() and ArrayList::view_mut().
+pub struct ViewMut<'a, T, const N: usize>
+where
+    Usize<N>: ChunkCapacity,
+{
+    front: Pair<'a, T>,
+    chunks: Pair<'a, VecDeque<T>>,
+    back: Pair<'a, T>,
+    len: usize,
+}
+
+impl<'a, T, const N: usize> ViewMut<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    /// Splits the list into two views, before and after the element at `index`.
+    #[track_caller]
+    pub(crate) fn split(list: &'a mut ArrayList<T, N>, index: usize) -> (Self, Self) {
+        let len = list.len();
+        assert!(
+            index <= len,
+            "split index (is {index}) should be <= len (is {len})"
+        );
+
+        let Some(SearchTarget {
+            chunk_index,
+            target_index,
+        }) = list.search_target(index)
+        else {
+            let head = Self::new(
+                Default::default(),
+                list.chunks.as_mut_slices(),
+                Default::default(),
+                len,
+            );
+            let tail = Self::new(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+            );
+            return (head, tail);
+        };
+
+        let (before, after) = split_pair(list.chunks.as_mut_slices(), chunk_index);
+        let (chunk, after) = split_first_pair(after).unwrap();
+        let (chunk_head, chunk_tail) = split_pair(chunk.as_mut_slices(), target_index);
+
+        let head = Self::new(Default::default(), before, chunk_head, index);
+        let tail = Self::new(chunk_tail, after, Default::default(), len - index);
+        (head, tail)
+    }
+
+    /// Returns a view over the elements in `range`, which must be within the list's bounds.
+    pub(crate) fn range(list: &'a mut ArrayList<T, N>, range: Range<usize>) -> Self {
+        let (_, tail) = Self::split(list, range.start);
+        let (view, _) = tail.split_at(range.len());
+        view
+    }
+
+    /// Splits the view into two, before and after the element at `mid`, which is at most its length.
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        let Self {
+            front,
+            chunks,
+            back,
+            len,
+        } = self;
+
+        let front_len = front.0.len() + front.1.len();
+        if mid <= front_len {
+            let (head, tail) = split_pair(front, mid);
+            let head = Self::new(head, Default::default(), Default::default(), mid);
+            let tail = Self::new(tail, chunks, back, len - mid);
+            return (head, tail);
+        }
+
+        // finds the chunk holding the element at `mid`, and its index within it
+        let mut rest = mid - front_len;
+        let chunk_index = chunks.0.iter().chain(chunks.1.iter()).position(|chunk| {
+            match rest.checked_sub(chunk.len()) {
+                Some(next) => {
+                    rest = next;
+                    false
+                }
+                None => true,
+            }
+        });
+
+        let Some(chunk_index) = chunk_index else {
+            let (head, tail) = split_pair(back, rest);
+            let head = Self::new(front, chunks, head, mid);
+            let tail = Self::new(tail, Default::default(), Default::default(), len - mid);
+            return (head, tail);
+        };
+
+        let (before, after) = split_pair(chunks, chunk_index);
+        let (chunk, after) = split_first_pair(after).unwrap();
+        let (chunk_head, chunk_tail) = split_pair(chunk.as_mut_slices(), rest);
+
+        let head = Self::new(front, before, chunk_head, mid);
+        let tail = Self::new(chunk_tail, after, back, len - mid);
+        (head, tail)
+    }
+
+    fn new(
+        front: Pair<'a, T>,
+        chunks: Pair<'a, VecDeque<T>>,
+        back: Pair<'a, T>,
+        len: usize,
+    ) -> Self {
+        Self {
+            front,
+            chunks,
+            back,
+            len,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the specified index of the view, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut index = index;
+        if index <= self.len / 2 {
+            return self.runs().find_map(|run| match run.get(index) {
+                Some(value) => Some(value),
+                None => {
+                    index -= run.len();
+                    None
+                }
+            });
+        }
+
+        let mut index = self.len - 1 - index;
+        self.runs()
+            .rev()
+            .find_map(|run| match index.checked_sub(run.len()) {
+                Some(rest) => {
+                    index = rest;
+                    None
+                }
+                None => Some(&run[run.len() - 1 - index]),
+            })
+    }
+
+    /// Returns a mutable reference to the element at the specified index of the view, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut index = index;
+        if index <= self.len / 2 {
+            return self
+                .runs_mut()
+                .find_map(|run| match index.checked_sub(run.len()) {
+                    Some(rest) => {
+                        index = rest;
+                        None
+                    }
+                    None => Some(&mut run[index]),
+                });
+        }
+
+        let len = self.len;
+        let mut index = len - 1 - index;
+        self.runs_mut()
+            .rev()
+            .find_map(|run| match index.checked_sub(run.len()) {
+                Some(rest) => {
+                    index = rest;
+                    None
+                }
+                None => Some(&mut run[run.len() - 1 - index]),
+            })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.runs().flatten()
+    }
+
+    /// Returns an iterator that allows modifying the elements of the view.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.runs_mut().flatten()
+    }
+
+    /// Returns the number of runs of the view, counting the empty ones:
+    /// two per chunk, plus two at the front and two at the back.
+    pub(crate) fn runs_len(&self) -> usize {
+        4 + 2 * (self.chunks.0.len() + self.chunks.1.len())
+    }
+
+    /// Returns the run at `run`, in the order of [`Self::runs`], in constant time.
+    pub(crate) fn run(&self, run: usize) -> &[T] {
+        let runs_len = self.runs_len();
+        match run {
+            0 => self.front.0,
+            1 => self.front.1,
+            _ if run + 2 == runs_len => self.back.0,
+            _ if run + 1 == runs_len => self.back.1,
+            _ => {
+                let chunk_index = (run - 2) / 2;
+                let chunk = match self.chunks.0.get(chunk_index) {
+                    Some(chunk) => chunk,
+                    None => &self.chunks.1[chunk_index - self.chunks.0.len()],
+                };
+
+                let (head, tail) = chunk.as_slices();
+                if run % 2 == 0 { head } else { tail }
+            }
+        }
+    }
+
+    /// Returns the run at `run`, in the order of [`Self::runs_mut`], in constant time.
+    pub(crate) fn run_mut(&mut self, run: usize) -> &mut [T] {
+        let runs_len = self.runs_len();
+        match run {
+            0 => self.front.0,
+            1 => self.front.1,
+            _ if run + 2 == runs_len => self.back.0,
+            _ if run + 1 == runs_len => self.back.1,
+            _ => {
+                let chunk_index = (run - 2) / 2;
+                let front_len = self.chunks.0.len();
+                let chunk = if chunk_index < front_len {
+                    &mut self.chunks.0[chunk_index]
+                } else {
+                    &mut self.chunks.1[chunk_index - front_len]
+                };
+
+                let (head, tail) = chunk.as_mut_slices();
+                if run % 2 == 0 { head } else { tail }
+            }
+        }
+    }
+
+    /// Returns the non-empty contiguous runs of the elements in `range`, in order.
+    pub(crate) fn runs_in_mut(&mut self, range: Range<usize>) -> impl Iterator<Item = &mut [T]> {
+        let mut offset = 0;
+        self.runs_mut().filter_map(move |run| {
+            let start = offset;
+            offset += run.len();
+
+            let from = range.start.saturating_sub(start).min(run.len());
+            let to = range.end.saturating_sub(start).min(run.len());
+            (from < to).then(|| &mut run[from..to])
+        })
+    }
+
+    /// Returns the contiguous runs of elements of the view, in order, some of which may be empty.
+    fn runs(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        let chunks = self
+            .chunks
+            .0
+            .iter()
+            .chain(self.chunks.1.iter())
+            .flat_map(|chunk| {
+                let (head, tail) = chunk.as_slices();
+                [head, tail]
+            });
+
+        [&*self.front.0, &*self.front.1]
+            .into_iter()
+            .chain(chunks)
+            .chain([&*self.back.0, &*self.back.1])
+    }
+
+    fn runs_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> {
+        let chunks = self
+            .chunks
+            .0
+            .iter_mut()
+            .chain(self.chunks.1.iter_mut())
+            .flat_map(|chunk| {
+                let (head, tail) = chunk.as_mut_slices();
+                [head, tail]
+            });
+
+        [&mut *self.front.0, &mut *self.front.1]
+            .into_iter()
+            .chain(chunks)
+            .chain([&mut *self.back.0, &mut *self.back.1])
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for ViewMut<'a, T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::from_parts(self.front, self.chunks, self.back, self.len)
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for ViewMut<'_, T, N>
+where
+    T: core::fmt::Debug,
+    Usize<N>: ChunkCapacity,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Splits a pair of slices as if they were a single one, at the given position.
+pub(crate) fn split_pair<U>((head, tail): Pair<'_, U>, at: usize) -> (Pair<'_, U>, Pair<'_, U>) {
+    match at.checked_sub(head.len()) {
+        None | Some(0) => {
+            let (left, right) = head.split_at_mut(at);
+            ((left, Default::default()), (right, tail))
+        }
+        Some(at) => {
+            let (left, right) = tail.split_at_mut(at);
+            ((head, left), (right, Default::default()))
+        }
+    }
+}
+
+/// Takes the first element out of a pair of slices, if any.
+pub(crate) fn split_first_pair<U>((head, tail): Pair<'_, U>) -> Option<(&mut U, Pair<'_, U>)> {
+    match head.split_first_mut() {
+        Some((first, head)) => Some((first, (head, tail))),
+        None => tail
+            .split_first_mut()
+            .map(|(first, tail)| (first, (tail, Default::default()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use quickcheck_macros::quickcheck;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[test]
+    fn test_split_at_mut_from_scoped_threads() {
+        let mut list: ArrayList<usize, 4> = (0..100).collect();
+
+        let (mut head, mut tail) = list.split_at_mut(42);
+        assert_eq!(head.len(), 42);
+        assert_eq!(tail.len(), 58);
+
+        thread::scope(|scope| {
+            scope.spawn(|| head.iter_mut().for_each(|value| *value *= 2));
+            scope.spawn(|| tail.iter_mut().rev().for_each(|value| *value += 1000));
+        });
+
+        let expected: Vec<_> = (0..42)
+            .map(|v| v * 2)
+            .chain((42..100).map(|v| v + 1000))
+            .collect();
+        assert_eq!(list, expected.as_slice());
+    }
+
+    #[quickcheck]
+    fn test_split_at_mut_matches_slice(values: Vec<i32>, front: u8, index: usize) {
+        fn _test<const N: usize>(values: &[i32], front: u8, index: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            // pushing some elements at the front leaves the chunks partially filled and wrapped
+            let front = usize::from(front) % (values.len() + 1);
+            let mut list = ArrayList::<_, N>::from_iter(values[front..].iter().copied());
+            values[..front]
+                .iter()
+                .rev()
+                .for_each(|&value| list.push_front(value));
+
+            let mut expected = values.to_vec();
+            let index = index % (values.len() + 1);
+
+            let (mut head, mut tail) = list.split_at_mut(index);
+            let (expected_head, expected_tail) = expected.split_at_mut(index);
+
+            for (view, slice) in [(&mut head, expected_head), (&mut tail, expected_tail)] {
+                assert_eq!(view.len(), slice.len());
+                assert!(view.iter().eq(slice.iter()));
+                assert!(view.iter().rev().eq(slice.iter().rev()));
+
+                for i in 0..=slice.len() {
+                    assert_eq!(view.get(i), slice.get(i));
+                    if let Some(value) = view.get_mut(i) {
+                        *value = value.wrapping_add(i as i32);
+                        slice[i] = slice[i].wrapping_add(i as i32);
+                    }
+                }
+            }
+
+            assert_eq!(list, expected.as_slice());
+        }
+
+        _test::<1>(&values, front, index);
+        _test::<2>(&values, front, index);
+        _test::<3>(&values, front, index);
+        _test::<4>(&values, front, index);
+        _test::<5>(&values, front, index);
+        _test::<8>(&values, front, index);
+        _test::<16>(&values, front, index);
+        _test::<32>(&values, front, index);
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::VecDeque;
+use std::ops::Deref;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{ArrayList, ChunkCapacity, SearchTarget, Usize};
+
+/// Wipes every element, then the whole buffer of every chunk, spare capacity included,
+/// before freeing it, leaving the list empty.
+///
+/// Only this call wipes anything: a plain list is not [`ZeroizeOnDrop`], and the edits that
+/// free a chunk, such as pops and removals emptying it or the merges made by some chunk policies,
+/// give its memory back without wiping it. Use a [`SecretArrayList`] to have every chunk wiped
+/// before it's freed, the list itself included once dropped.
+///
+/// # Examples
+/// ```
+/// use array_list::ArrayList;
+/// use zeroize::Zeroize;
+///
+/// let mut key: ArrayList<u8, 16> = ArrayList::from_iter(0..32);
+/// key.zeroize();
+/// assert!(key.is_empty());
+/// ```
+impl<T: Zeroize, const N: usize> Zeroize for ArrayList<T, N>
+where
+    Usize<N>: ChunkCapacity,
+{
+    fn zeroize(&mut self) {
+        for chunk in self.chunks.drain(..) {
+            // turning a chunk into a Vec keeps its buffer, so the whole capacity gets wiped
+            Vec::from(chunk).zeroize();
+        }
+
+        self.len = 0;
+    }
+}
+
+/// An ArrayList that wipes every chunk with [`Zeroize`] before freeing it,
+/// and all of them once dropped.
+///
+/// Chunks are freed when they get empty, once the list is cleared, and when it's dropped,
+/// and every one of them is wiped first, spare capacity included. The elements are wiped
+/// along with them, except the ones handed back by pops and removals, which are moved out
+/// and become the caller's to wipe.
+///
+/// The list is read through `Deref`, while editing goes through the methods of this type,
+/// none of which merges or reallocates chunks.
+///
+/// This struct is created by ArrayList::secret().
+pub struct SecretArrayList<T, const N: usize>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    list: ArrayList<T, N>,
+}
+
+impl<T, const N: usize> SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    pub(crate) fn from_list(mut list: ArrayList<T, N>) -> Self {
+        // chunks short of room would reallocate while growing, freeing their buffer unwiped
+        for chunk in list.chunks.iter_mut().filter(|chunk| chunk.capacity() < N) {
+            let mut fresh = VecDeque::with_capacity(N);
+            fresh.extend(chunk.drain(..));
+            Vec::from(core::mem::replace(chunk, fresh)).zeroize();
+            list.counters.allocation();
+        }
+
+        Self { list }
+    }
+
+    pub const fn as_list(&self) -> &ArrayList<T, N> {
+        &self.list
+    }
+
+    /// Consumes `self`, returning the underlying list, which is no longer wiped.
+    pub fn into_list(mut self) -> ArrayList<T, N> {
+        core::mem::take(&mut self.list)
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(value);
+    }
+
+    /// Removes the first element, wiping its chunk before freeing it if it gets empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.list.chunks.front_mut()?.pop_front();
+        self.wipe_if_empty(0);
+        self.list.len -= 1;
+        value
+    }
+
+    /// Removes the last element, wiping its chunk before freeing it if it gets empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let value = self.list.chunks.back_mut()?.pop_back();
+        self.wipe_if_empty(self.list.chunks.len() - 1);
+        self.list.len -= 1;
+        value
+    }
+
+    /// Inserts an element at the specified index, shifting subsequent elements to the right.
+    ///
+    /// # Panics
+    /// - Panics if the `index` is out of bounds (greater than the list's current length).
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        // making room only ever moves elements to other chunks or to new ones
+        self.list.insert(index, value);
+    }
+
+    /// Removes and returns the element at the specified index, shifting subsequent elements left.
+    ///
+    /// The chunk it was in is wiped before it's freed if it gets empty.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let SearchTarget {
+            chunk_index,
+            target_index,
+        } = self.list.search_target(index)?;
+
+        let value = self.list.chunks[chunk_index].remove(target_index);
+        self.wipe_if_empty(chunk_index);
+        self.list.len -= 1;
+        value
+    }
+
+    /// Removes all the elements, wiping them along with every chunk.
+    pub fn clear(&mut self) {
+        self.list.zeroize();
+    }
+
+    /// Wipes the chunk at `chunk_index` and frees it, if it's empty.
+    fn wipe_if_empty(&mut self, chunk_index: usize) {
+        if self.list.chunks[chunk_index].is_empty() {
+            let chunk = self.list.chunks.remove(chunk_index).unwrap();
+            Vec::from(chunk).zeroize();
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    fn default() -> Self {
+        Self::from_list(ArrayList::new())
+    }
+}
+
+impl<T, const N: usize> Deref for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    type Target = ArrayList<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_list(ArrayList::from_iter(iter))
+    }
+}
+
+impl<T, const N: usize> Zeroize for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    fn zeroize(&mut self) {
+        self.list.zeroize();
+    }
+}
+
+impl<T, const N: usize> Drop for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    fn drop(&mut self) {
+        self.list.zeroize();
+    }
+}
+
+impl<T, const N: usize> ZeroizeOnDrop for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+}
+
+impl<T, const N: usize> core::fmt::Debug for SecretArrayList<T, N>
+where
+    T: Zeroize,
+    Usize<N>: ChunkCapacity,
+{
+    /// Never prints the elements, only how many there are.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecretArrayList")
+            .field("len", &self.list.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use quickcheck_macros::quickcheck;
+    use zeroize::Zeroize;
+
+    use crate::{ArrayList, ChunkCapacity, Usize};
+
+    #[quickcheck]
+    fn test_zeroize_wipes_every_element(len: u8, front: u8) {
+        struct Secret<'a>(&'a Cell<usize>);
+
+        impl Zeroize for Secret<'_> {
+            fn zeroize(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        fn _test<const N: usize>(len: usize, front: usize)
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let wiped = Cell::new(0);
+
+            // pushing some elements at the front leaves chunks partially filled and wrapped around
+            let front = front % (len + 1);
+            let mut list = ArrayList::<_, N>::from_iter((front..len).map(|_| Secret(&wiped)));
+            (0..front).for_each(|_| list.push_front(Secret(&wiped)));
+
+            list.zeroize();
+            assert_eq!(wiped.get(), len);
+            assert!(list.is_empty());
+            assert!(list.chunks.is_empty());
+        }
+
+        let (len, front) = (usize::from(len), usize::from(front));
+        _test::<1>(len, front);
+        _test::<2>(len, front);
+        _test::<3>(len, front);
+        _test::<4>(len, front);
+        _test::<5>(len, front);
+        _test::<8>(len, front);
+        _test::<16>(len, front);
+        _test::<32>(len, front);
+    }
+
+    #[quickcheck]
+    fn test_secret_matches_vec(seed: Vec<u8>, operations: Vec<(u8, usize, u8)>) {
+        fn _test<const N: usize>(seed: &[u8], operations: &[(u8, usize, u8)])
+        where
+            Usize<N>: ChunkCapacity,
+        {
+            let mut expected = seed.to_vec();
+            // a clone leaves chunks without room to grow
+            let mut actual = ArrayList::<_, N>::from_iter(seed.iter().copied())
+                .clone()
+                .secret();
+
+            for &(operation, index, value) in operations {
+                let index = index % (expected.len() + 1);
+
+                match operation % 6 {
+                    0 => {
+                        expected.insert(0, value);
+                        actual.push_front(value);
+                    }
+                    1 => {
+                        expected.push(value);
+                        actual.push_back(value);
+                    }
+                    2 => {
+                        let value = (!expected.is_empty()).then(|| expected.remove(0));
+                        assert_eq!(actual.pop_front(), value);
+                    }
+                    3 => assert_eq!(actual.pop_back(), expected.pop()),
+                    4 => {
+                        expected.insert(index, value);
+                        actual.insert(index, value);
+                    }
+                    _ => {
+                        let value = (index < expected.len()).then(|| expected.remove(index));
+                        assert_eq!(actual.remove(index), value);
+                    }
+                }
+
+                assert!(actual.iter().eq(expected.iter()));
+                assert!(
+                    actual
+                        .chunks
+                        .iter()
+                        .all(|chunk| (1..=N).contains(&chunk.len()) && chunk.capacity() == N)
+                );
+            }
+        }
+
+        _test::<1>(&seed, &operations);
+        _test::<2>(&seed, &operations);
+        _test::<3>(&seed, &operations);
+        _test::<4>(&seed, &operations);
+        _test::<5>(&seed, &operations);
+        _test::<8>(&seed, &operations);
+        _test::<16>(&seed, &operations);
+        _test::<32>(&seed, &operations);
+    }
+
+    #[test]
+    fn test_secret_wipes_on_drop() {
+        struct Secret<'a>(&'a Cell<usize>);
+
+        impl Zeroize for Secret<'_> {
+            fn zeroize(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let wiped = Cell::new(0);
+        let mut list = ArrayList::<_, 4>::from_iter((0..10).map(|_| Secret(&wiped))).secret();
+
+        // the popped elements are the caller's to wipe
+        list.pop_front();
+        list.remove(4);
+        list.pop_back();
+        assert_eq!(wiped.get(), 0);
+
+        drop(list);
+        assert_eq!(wiped.get(), 7);
+    }
+}